@@ -0,0 +1,44 @@
+#!/usr/bin/env bash
+
+# Generate the TPC-H tables with the tpch_* table functions, then run the 22 queries
+# and report the server time of each run.
+#
+# Usage: ./bench.sh <scale> [tries]
+
+set -e
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/shell_env.sh
+
+SCALE=${1:-1}
+TRIES=${2:-3}
+QUERIES_DIR="$CURDIR/../clickbench/tpch/queries"
+
+echo "CREATE DATABASE IF NOT EXISTS ${MYSQL_DATABASE}" | $BENDSQL_CLIENT_CONNECT_DEFAULT
+
+for t in customer lineitem nation orders partsupp part region supplier; do
+    echo "Generating $t at scale ${SCALE}..."
+    echo "CREATE OR REPLACE TABLE $t AS SELECT * FROM tpch_$t(${SCALE})" | $BENDSQL_CLIENT_CONNECT
+    echo "ANALYZE TABLE $t" | $BENDSQL_CLIENT_CONNECT
+done
+
+echo "query,$(seq -s, -f 'try%g' 1 "$TRIES")" >result.csv
+for query in "$QUERIES_DIR"/[0-9][0-9].sql; do
+    name=$(basename "$query" .sql)
+    # 00.sql is a warm-up query
+    if [[ $name == 00 ]]; then
+        continue
+    fi
+    times=()
+    for _ in $(seq 1 "$TRIES"); do
+        q_time=$($BENDSQL_CLIENT_CONNECT --time=server <"$query") || q_time=""
+        times+=("${q_time:-null}")
+    done
+    echo "Q${name}: ${times[*]}"
+    (
+        IFS=,
+        echo "Q${name},${times[*]}"
+    ) >>result.csv
+done
+
+echo "Timings written to result.csv"
@@ -23,15 +23,7 @@ mod utils;
 use std::env;
 use std::path::Path;
 
-use databend_common_config::Config;
-use databend_common_config::InnerConfig;
-use databend_common_license::license_manager::LicenseManager;
-use databend_common_license::license_manager::OssLicenseManager;
-use databend_common_meta_app::storage::StorageFsConfig;
-use databend_common_meta_app::storage::StorageParams;
-use databend_common_meta_embedded::MetaEmbedded;
-use databend_query::clusters::ClusterDiscovery;
-use databend_query::GlobalServices;
+use databend_query::local::init_local;
 use pyo3::prelude::*;
 use utils::RUNTIME;
 
@@ -41,27 +33,8 @@ fn databend(_py: Python, m: &PyModule) -> PyResult<()> {
     let data_path = env::var("DATABEND_DATA_PATH").unwrap_or(".databend/".to_string());
     let path = Path::new(&data_path);
 
-    env::set_var("META_EMBEDDED_DIR", path.join("_meta"));
-
-    let mut conf: InnerConfig = Config::load(false).unwrap().try_into().unwrap();
-    conf.storage.allow_insecure = true;
-    conf.storage.params = StorageParams::Fs(StorageFsConfig {
-        root: path.join("_data").to_str().unwrap().to_owned(),
-    });
-
     RUNTIME.block_on(async {
-        let meta_dir = path.join("_meta");
-        MetaEmbedded::init_global_meta_store(meta_dir.to_string_lossy().to_string())
-            .await
-            .unwrap();
-        GlobalServices::init(&conf).await.unwrap();
-
-        // init oss license manager
-        OssLicenseManager::init(conf.query.tenant_id.tenant_name().to_string()).unwrap();
-        ClusterDiscovery::instance()
-            .register_to_metastore(&conf)
-            .await
-            .unwrap();
+        init_local(path, false).await.unwrap();
     });
 
     m.add_class::<context::PySessionContext>()?;
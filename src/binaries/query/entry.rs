@@ -44,6 +44,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::sessions::SessionManager;
 use databend_query::GlobalServices;
 use log::info;
 
@@ -58,6 +59,14 @@ pub async fn run_cmd(conf: &InnerConfig) -> Result<bool, MainError> {
             println!("version: {}", *QUERY_SEMVER);
             println!("min-compatible-metasrv-version: {}", MIN_METASRV_SEMVER);
         }
+        Some(Commands::ShowConfig) => {
+            let config = conf.clone().into_config().with_mask();
+            println!(
+                "config:\n{}",
+                serde_json::to_string_pretty(&config)
+                    .unwrap_or_else(|e| format!("error format config: {}", e))
+            );
+        }
         Some(Commands::Local {
             query,
             output_format,
@@ -157,7 +166,8 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         let tls_config = MySQLTlsConfig::new(
             conf.query.mysql_tls_server_cert.clone(),
             conf.query.mysql_tls_server_key.clone(),
-        );
+        )
+        .with_root_ca(conf.query.mysql_tls_server_root_ca_cert.clone());
 
         let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, tls_config)
             .with_context(make_error)?;
@@ -275,6 +285,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
     if conf.log.structlog.on {
         println!("    structlog: {}", conf.log.structlog);
     }
+    if conf.log.audit.on {
+        println!("    audit: {}", conf.log.audit);
+    }
 
     println!();
     println!(
@@ -402,6 +415,7 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         println!("    {}={}", k, v);
     }
 
+    SessionManager::instance().set_ready(true);
     info!(
         "Ready for connections after {}s.",
         start_time.elapsed().as_secs_f32()
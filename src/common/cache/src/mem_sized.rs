@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
+
 pub trait MemSized {
     fn mem_bytes(&self) -> usize;
 }
@@ -28,6 +30,12 @@ impl MemSized for String {
     }
 }
 
+impl MemSized for IpAddr {
+    fn mem_bytes(&self) -> usize {
+        std::mem::size_of::<IpAddr>()
+    }
+}
+
 impl<T> MemSized for Option<T>
 where T: MemSized
 {
@@ -97,6 +97,7 @@ build_exceptions! {
     BadAddressFormat(1036),
     DnsParseError(1037),
     CannotConnectNode(1038),
+    TooManyRunningQueriesPerUser(1040),
     TooManyUserConnections(1041),
     AbortedSession(1042),
     AbortedQuery(1043),
@@ -104,6 +105,7 @@ build_exceptions! {
     CannotListenerPort(1045),
     BadBytes(1046),
     InitPrometheusFailure(1047),
+    QueryTimeout(1048),
     Overflow(1049),
     TLSConfigurationFailure(1052),
     UnknownSession(1053),
@@ -368,10 +370,11 @@ build_exceptions! {
     OnlySupportAsciiChars(2802),
     WrongValueForVariable(2803),
 
-    // Tenant quota error codes.
+    // Tenant and user quota error codes.
     IllegalTenantQuotaFormat(2901),
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
+    UserQuotaExceeded(2904),
 
     // Script error codes.
     ScriptSemanticError(3001),
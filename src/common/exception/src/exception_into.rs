@@ -383,28 +383,7 @@ impl From<tonic::Status> for ErrorCode {
                 }
                 match serde_json::from_slice::<SerializedError>(details) {
                     Err(error) => ErrorCode::from(error),
-                    Ok(serialized_error) => match serialized_error.backtrace.len() {
-                        0 => ErrorCode::create(
-                            serialized_error.code,
-                            serialized_error.name,
-                            serialized_error.message,
-                            String::new(),
-                            None,
-                            None,
-                        )
-                        .set_span(serialized_error.span),
-                        _ => ErrorCode::create(
-                            serialized_error.code,
-                            serialized_error.name,
-                            serialized_error.message,
-                            String::new(),
-                            None,
-                            Some(ErrorCodeBacktrace::Serialized(Arc::new(
-                                serialized_error.backtrace,
-                            ))),
-                        )
-                        .set_span(serialized_error.span),
-                    },
+                    Ok(serialized_error) => ErrorCode::from(&serialized_error),
                 }
             }
             _ => ErrorCode::Unimplemented(status.to_string()),
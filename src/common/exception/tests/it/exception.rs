@@ -136,3 +136,14 @@ fn test_from_and_to_status() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_status_keeps_error_stacks() {
+    let e = ErrorCode::IllegalDataType("foo").with_context("executing fragment 1 on node n1");
+    let status: Status = e.into();
+
+    let e2: ErrorCode = status.into();
+    assert_eq!(1007, e2.code());
+    assert_eq!(1, e2.stacks().len());
+    assert_eq!("executing fragment 1 on node n1", e2.stacks()[0].message);
+}
@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::mem_allocator::GlobalAllocator;
+use databend_common_base::runtime::metrics::dump_process_stat;
+use databend_common_base::runtime::GLOBAL_MEM_STAT;
+use poem::web::Json;
+use poem::IntoResponse;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+pub struct MemoryStats {
+    pub allocator: String,
+    pub allocator_conf: String,
+    // the memory tracked by the global memory tracker
+    pub memory_usage: i64,
+    pub peak_memory_usage: i64,
+    // the process memory from the os, None if not supported on the platform
+    pub resident_memory_bytes: Option<u64>,
+    pub virtual_memory_bytes: Option<u64>,
+}
+
+// dump the memory stats
+// example: /debug/mem
+#[poem::handler]
+pub async fn debug_mem_handler() -> poem::Result<impl IntoResponse> {
+    let process_stat = dump_process_stat();
+    Ok(Json(MemoryStats {
+        allocator: GlobalAllocator::name(),
+        allocator_conf: GlobalAllocator::conf(),
+        memory_usage: GLOBAL_MEM_STAT.get_memory_usage(),
+        peak_memory_usage: GLOBAL_MEM_STAT.get_peak_memory_usage(),
+        resident_memory_bytes: process_stat.as_ref().map(|stat| stat.rss),
+        virtual_memory_bytes: process_stat.as_ref().map(|stat| stat.vsize),
+    }))
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod home;
+pub mod mem;
 pub mod pprof;
 
 #[cfg(feature = "memory-profiling")]
@@ -25,6 +25,7 @@ pub type VecLabels = Vec<(&'static str, String)>;
 pub use crate::metrics::cache;
 pub use crate::metrics::cluster;
 pub use crate::metrics::external_server;
+pub use crate::metrics::flight;
 /// Metrics.
 pub use crate::metrics::http;
 pub use crate::metrics::interpreter;
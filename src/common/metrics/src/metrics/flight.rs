@@ -0,0 +1,36 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::LazyLock;
+
+use databend_common_base::runtime::metrics::register_counter_family;
+use databend_common_base::runtime::metrics::FamilyCounter;
+
+use crate::VecLabels;
+
+const METRIC_FLIGHT_RPC_ERRORS: &str = "flight_rpc_errors";
+
+const LABEL_METHOD: &str = "method";
+const LABEL_CODE: &str = "code";
+
+pub static FLIGHT_RPC_ERRORS: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family(METRIC_FLIGHT_RPC_ERRORS));
+
+pub fn metrics_inc_flight_rpc_errors(method: &str, code: &str) {
+    let labels = &vec![
+        (LABEL_METHOD, method.to_string()),
+        (LABEL_CODE, code.to_string()),
+    ];
+    FLIGHT_RPC_ERRORS.get_or_create(labels).inc();
+}
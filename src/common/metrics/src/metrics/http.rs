@@ -32,6 +32,8 @@ static QUERY_HTTP_SLOW_REQUESTS_COUNT: LazyLock<FamilyCounter<VecLabels>> =
     LazyLock::new(|| register_counter_family("query_http_slow_requests_count"));
 static QUERY_HTTP_RESPONSE_ERRORS_COUNT: LazyLock<FamilyCounter<VecLabels>> =
     LazyLock::new(|| register_counter_family("query_http_response_errors_count"));
+static QUERY_HTTP_RATE_LIMITED_REQUESTS_COUNT: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family("query_http_rate_limited_requests_count"));
 static QUERY_HTTP_RESPONSE_PANICS_COUNT: LazyLock<Counter> =
     LazyLock::new(|| register_counter("query_http_response_panics_count"));
 
@@ -45,6 +47,13 @@ pub fn metrics_incr_http_slow_request_count(method: String, api: String, status:
     QUERY_HTTP_SLOW_REQUESTS_COUNT.get_or_create(&labels).inc();
 }
 
+pub fn metrics_incr_http_rate_limited_request_count(api: String) {
+    let labels = vec![("api", api)];
+    QUERY_HTTP_RATE_LIMITED_REQUESTS_COUNT
+        .get_or_create(&labels)
+        .inc();
+}
+
 pub fn metrics_incr_http_response_errors_count(err: String, code: u16) {
     let labels = vec![("err", err), ("code", code.to_string())];
     QUERY_HTTP_RESPONSE_ERRORS_COUNT
@@ -15,6 +15,7 @@
 pub mod cache;
 pub mod cluster;
 pub mod external_server;
+pub mod flight;
 pub mod http;
 pub mod interpreter;
 pub mod lock;
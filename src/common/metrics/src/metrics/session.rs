@@ -26,6 +26,8 @@ pub static SESSION_CONNECT_NUMBERS: LazyLock<Counter> =
     LazyLock::new(|| register_counter("session_connect_numbers"));
 pub static SESSION_CLOSE_NUMBERS: LazyLock<Counter> =
     LazyLock::new(|| register_counter("session_close_numbers"));
+pub static SESSION_IDLE_REAPED_NUMBERS: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("session_idle_reaped_numbers"));
 pub static SESSION_ACTIVE_CONNECTIONS: LazyLock<Gauge> =
     LazyLock::new(|| register_gauge("session_connections"));
 pub static SESSION_QUQUED_QUERIES: LazyLock<Gauge> =
@@ -50,6 +52,10 @@ pub fn incr_session_close_numbers() {
     SESSION_CLOSE_NUMBERS.inc();
 }
 
+pub fn incr_session_idle_reaped_numbers() {
+    SESSION_IDLE_REAPED_NUMBERS.inc();
+}
+
 pub fn set_session_active_connections(num: usize) {
     SESSION_ACTIVE_CONNECTIONS.set(num as i64);
 }
@@ -14,11 +14,15 @@
 
 use std::sync::LazyLock;
 
+use databend_common_base::runtime::metrics::register_gauge;
 use databend_common_base::runtime::metrics::register_gauge_family;
 use databend_common_base::runtime::metrics::FamilyGauge;
+use databend_common_base::runtime::metrics::Gauge;
 
 pub static SYSTEM_VERSION_GAUGE: LazyLock<FamilyGauge<Vec<(&'static str, String)>>> =
     LazyLock::new(|| register_gauge_family("system_version"));
+pub static SYSTEM_MEMORY_USAGE_BYTES: LazyLock<Gauge> =
+    LazyLock::new(|| register_gauge("system_memory_usage_bytes"));
 
 pub fn set_system_version(component: &str, semver: &str, sha: &str) {
     let labels = &vec![
@@ -29,3 +33,7 @@ pub fn set_system_version(component: &str, semver: &str, sha: &str) {
 
     SYSTEM_VERSION_GAUGE.get_or_create(labels).set(1);
 }
+
+pub fn set_system_memory_usage_bytes(bytes: i64) {
+    SYSTEM_MEMORY_USAGE_BYTES.set(bytes);
+}
@@ -23,7 +23,9 @@ use databend_common_expression::Scalar;
 
 pub type F64 = OrderedFloat<f64>;
 
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
 pub enum Datum {
     Bool(bool),
     Int(i64),
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -25,6 +26,7 @@ pub struct Config {
     pub query: QueryLogConfig,
     pub profile: ProfileLogConfig,
     pub structlog: StructLogConfig,
+    pub audit: AuditLogConfig,
     pub tracing: TracingConfig,
 }
 
@@ -219,6 +221,41 @@ impl Default for StructLogConfig {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, serde::Serialize)]
+pub struct AuditLogConfig {
+    pub on: bool,
+    pub dir: String,
+    pub key: String,
+}
+
+impl Debug for AuditLogConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // The key signs the audit chain, never print it.
+        let key = if self.key.is_empty() { "" } else { "******" };
+        f.debug_struct("AuditLogConfig")
+            .field("on", &self.on)
+            .field("dir", &self.dir)
+            .field("key", &key)
+            .finish()
+    }
+}
+
+impl Display for AuditLogConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "enabled={}, dir={}", self.on, self.dir)
+    }
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            on: false,
+            dir: "".to_string(),
+            key: "".to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 pub struct TracingConfig {
     pub on: bool,
@@ -335,3 +372,20 @@ impl Default for OTLPEndpointConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_log_config_debug_masks_key() {
+        let config = AuditLogConfig {
+            on: true,
+            dir: "./.databend/logs/audit".to_string(),
+            key: "secret-audit-key".to_string(),
+        };
+        let debug = format!("{:?}", config);
+        assert!(!debug.contains("secret-audit-key"));
+        assert!(debug.contains("******"));
+    }
+}
@@ -14,6 +14,7 @@
 
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::str::FromStr;
 use std::time::Duration;
 
 use databend_common_base::base::tokio;
@@ -31,6 +32,9 @@ use logforth::Logger;
 use opentelemetry_otlp::WithExportConfig;
 
 use crate::config::OTLPProtocol;
+use crate::level::make_level_override_filter;
+use crate::level::set_reloaded_log_level;
+use crate::level::LogOutput;
 use crate::loggers::get_layout;
 use crate::loggers::new_rolling_file_appender;
 use crate::structlog::StructLogReporter;
@@ -180,13 +184,8 @@ pub fn init_logging(
         _drop_guards.push(flush_guard);
 
         let dispatch = Dispatch::new()
-            .filter(EnvFilter::new(
-                EnvFilterBuilder::new()
-                    .filter(Some("databend::log::query"), LevelFilter::Off)
-                    .filter(Some("databend::log::profile"), LevelFilter::Off)
-                    .filter(Some("databend::log::structlog"), LevelFilter::Off)
-                    .parse(&cfg.file.level),
-            ))
+            .filter(make_level_override_filter(LogOutput::File))
+            .filter(make_output_env_filter(LogOutput::File, &cfg.file.level))
             .filter(make_log_filter(&cfg.file.prefix_filter))
             .append(normal_log_file);
         logger = logger.dispatch(dispatch);
@@ -195,13 +194,8 @@ pub fn init_logging(
     // console logger
     if cfg.stderr.on {
         let dispatch = Dispatch::new()
-            .filter(EnvFilter::new(
-                EnvFilterBuilder::new()
-                    .filter(Some("databend::log::query"), LevelFilter::Off)
-                    .filter(Some("databend::log::profile"), LevelFilter::Off)
-                    .filter(Some("databend::log::structlog"), LevelFilter::Off)
-                    .parse(&cfg.stderr.level),
-            ))
+            .filter(make_level_override_filter(LogOutput::Stderr))
+            .filter(make_output_env_filter(LogOutput::Stderr, &cfg.stderr.level))
             .append(
                 logforth::append::Stderr::default().with_layout(get_layout(&cfg.stderr.format)),
             );
@@ -232,6 +226,7 @@ pub fn init_logging(
                     .filter(Some("databend::log::query"), LevelFilter::Off)
                     .filter(Some("databend::log::profile"), LevelFilter::Off)
                     .filter(Some("databend::log::structlog"), LevelFilter::Off)
+                    .filter(Some("databend::log::audit"), LevelFilter::Off)
                     .parse(&cfg.otlp.level),
             ))
             .append(otel);
@@ -251,7 +246,8 @@ pub fn init_logging(
                 EnvFilterBuilder::new()
                     .filter(Some("databend::log::query"), LevelFilter::Off)
                     .filter(Some("databend::log::profile"), LevelFilter::Off)
-                    .filter(Some("databend::log::structlog"), LevelFilter::Off),
+                    .filter(Some("databend::log::structlog"), LevelFilter::Off)
+                    .filter(Some("databend::log::audit"), LevelFilter::Off),
             ))
             .filter(level)
             .append(logforth::append::FastraceEvent::default());
@@ -357,6 +353,20 @@ pub fn init_logging(
         logger = logger.dispatch(dispatch);
     }
 
+    // audit logger
+    if cfg.audit.on && !cfg.audit.dir.is_empty() {
+        let (audit_log_file, flush_guard) =
+            new_rolling_file_appender(&cfg.audit.dir, log_name, cfg.file.limit);
+        _drop_guards.push(flush_guard);
+
+        let dispatch = Dispatch::new()
+            .filter(EnvFilter::new(
+                EnvFilterBuilder::new().filter(Some("databend::log::audit"), LevelFilter::Trace),
+            ))
+            .append(audit_log_file);
+        logger = logger.dispatch(dispatch);
+    }
+
     // set global logger
     if logger.apply().is_err() {
         eprintln!("logger has already been set");
@@ -366,6 +376,26 @@ pub fn init_logging(
     _drop_guards
 }
 
+/// Creates the target filter of the file or stderr logger.
+///
+/// A single level like `INFO` is applied by the level override filter instead,
+/// so that it can be changed at runtime.
+fn make_output_env_filter(output: LogOutput, level: &str) -> EnvFilter {
+    let builder = EnvFilterBuilder::new()
+        .filter(Some("databend::log::query"), LevelFilter::Off)
+        .filter(Some("databend::log::profile"), LevelFilter::Off)
+        .filter(Some("databend::log::structlog"), LevelFilter::Off)
+        .filter(Some("databend::log::audit"), LevelFilter::Off);
+
+    match LevelFilter::from_str(level) {
+        Ok(level) => {
+            set_reloaded_log_level(output, level);
+            EnvFilter::new(builder.filter(None, LevelFilter::Trace))
+        }
+        Err(_) => EnvFilter::new(builder.parse(level)),
+    }
+}
+
 /// Creates a log filter that matches log entries based on specified target prefixes or severity.
 fn make_log_filter(prefix_filter: &str) -> CustomFilter {
     let prefixes = prefix_filter
@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use log::LevelFilter;
+use log::Metadata;
+use logforth::filter::CustomFilter;
+use logforth::filter::FilterResult;
+
+// 0 means no level is set, otherwise the level is `LevelFilter as usize + 1`.
+static LOG_LEVEL_OVERRIDE: AtomicUsize = AtomicUsize::new(0);
+static FILE_LOG_LEVEL: AtomicUsize = AtomicUsize::new(0);
+static STDERR_LOG_LEVEL: AtomicUsize = AtomicUsize::new(0);
+
+const LEVELS: [LevelFilter; 6] = [
+    LevelFilter::Off,
+    LevelFilter::Error,
+    LevelFilter::Warn,
+    LevelFilter::Info,
+    LevelFilter::Debug,
+    LevelFilter::Trace,
+];
+
+/// The loggers whose level can be changed at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogOutput {
+    File,
+    Stderr,
+}
+
+impl LogOutput {
+    fn level(&self) -> &'static AtomicUsize {
+        match self {
+            LogOutput::File => &FILE_LOG_LEVEL,
+            LogOutput::Stderr => &STDERR_LOG_LEVEL,
+        }
+    }
+}
+
+fn store_level(value: &AtomicUsize, level: Option<LevelFilter>) {
+    let level = match level {
+        None => 0,
+        Some(level) => {
+            // Make sure the `log` macros are not filtered out before reaching our filters.
+            if level > log::max_level() {
+                log::set_max_level(level);
+            }
+            level as usize + 1
+        }
+    };
+    value.store(level, Ordering::Release);
+}
+
+fn load_level(value: &AtomicUsize) -> Option<LevelFilter> {
+    match value.load(Ordering::Acquire) {
+        0 => None,
+        value => LEVELS.get(value - 1).copied(),
+    }
+}
+
+/// Override the level of the file and stderr loggers at runtime,
+/// `None` restores the levels in the config.
+pub fn set_log_level_override(level: Option<LevelFilter>) {
+    store_level(&LOG_LEVEL_OVERRIDE, level);
+}
+
+pub fn get_log_level_override() -> Option<LevelFilter> {
+    load_level(&LOG_LEVEL_OVERRIDE)
+}
+
+/// Set the level of a logger from its level in the config, at startup and after a reload,
+/// it is used if there is no runtime override.
+pub fn set_reloaded_log_level(output: LogOutput, level: LevelFilter) {
+    store_level(output.level(), Some(level));
+}
+
+/// Creates a log filter that rejects the records above the runtime level of the logger,
+/// it is neutral otherwise.
+pub(crate) fn make_level_override_filter(output: LogOutput) -> CustomFilter {
+    CustomFilter::new(move |meta| match_level_override(meta, output))
+}
+
+fn match_level_override(meta: &Metadata, output: LogOutput) -> FilterResult {
+    // The query, profile and structlog logs are routed by their target, leave them alone.
+    if meta.target().starts_with("databend::log::") {
+        return FilterResult::Neutral;
+    }
+
+    // Leave the records within the level to the following filters, e.g. the target directives.
+    match get_log_level_override().or_else(|| load_level(output.level())) {
+        Some(level) if meta.level() > level => FilterResult::Reject,
+        _ => FilterResult::Neutral,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_level_override() {
+        assert_eq!(get_log_level_override(), None);
+
+        set_log_level_override(Some(LevelFilter::Debug));
+        assert_eq!(get_log_level_override(), Some(LevelFilter::Debug));
+
+        set_log_level_override(Some(LevelFilter::Off));
+        assert_eq!(get_log_level_override(), Some(LevelFilter::Off));
+
+        set_log_level_override(None);
+        assert_eq!(get_log_level_override(), None);
+    }
+
+    #[test]
+    fn test_reloaded_log_level() {
+        let meta = Metadata::builder()
+            .level(log::Level::Debug)
+            .target("databend_query")
+            .build();
+
+        set_reloaded_log_level(LogOutput::File, LevelFilter::Debug);
+        set_reloaded_log_level(LogOutput::Stderr, LevelFilter::Warn);
+        assert!(matches!(
+            match_level_override(&meta, LogOutput::File),
+            FilterResult::Neutral
+        ));
+        assert!(matches!(
+            match_level_override(&meta, LogOutput::Stderr),
+            FilterResult::Reject
+        ));
+    }
+}
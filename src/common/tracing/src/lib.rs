@@ -20,6 +20,7 @@
 mod config;
 mod crash_hook;
 mod init;
+mod level;
 mod loggers;
 mod panic_hook;
 mod structlog;
@@ -27,6 +28,7 @@ mod structlog;
 pub use crash_hook::pipe_file;
 pub use crash_hook::SignalListener;
 
+pub use crate::config::AuditLogConfig;
 pub use crate::config::Config;
 pub use crate::config::FileConfig;
 pub use crate::config::OTLPConfig;
@@ -42,6 +44,10 @@ pub use crate::init::init_logging;
 pub use crate::init::inject_span_to_tonic_request;
 pub use crate::init::start_trace_for_remote_request;
 pub use crate::init::GlobalLogger;
+pub use crate::level::get_log_level_override;
+pub use crate::level::set_log_level_override;
+pub use crate::level::set_reloaded_log_level;
+pub use crate::level::LogOutput;
 pub use crate::panic_hook::log_panic;
 pub use crate::panic_hook::set_panic_hook;
 pub use crate::structlog::DummyReporter;
@@ -1287,6 +1287,10 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, KVAppError> {
         debug!(req :? =(&req); "SchemaApi: {}", func_name!());
 
+        if req.swap {
+            return swap_table(self, &req).await;
+        }
+
         let tenant_dbname_tbname = &req.name_ident;
         let tenant_dbname = tenant_dbname_tbname.db_name_ident();
         let tenant_newdbname_newtbname = TableNameIdent {
@@ -4020,6 +4024,138 @@ async fn handle_undrop_table(
     }
 }
 
+/// The keys and seqs of a table name, read by `swap_table`.
+struct SwapTableName {
+    dbid_tbname: DBIdTableName,
+    tb_id_seq: u64,
+    table_id: u64,
+    dbid_tbname_idlist: TableIdHistoryIdent,
+    tb_id_list_seq: u64,
+    tb_id_list: TableIdList,
+    table_id_to_name_key: TableIdToName,
+    table_id_to_name_seq: u64,
+}
+
+/// Exchange the names of two tables in the same database in one transaction.
+///
+/// Only the name to id mappings are swapped, the table metas and data are untouched.
+async fn swap_table(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    req: &RenameTableReq,
+) -> Result<RenameTableReply, KVAppError> {
+    let tenant_dbname_tbname = &req.name_ident;
+    let tenant_dbname = tenant_dbname_tbname.db_name_ident();
+    let tenant_dbname_target = TableNameIdent {
+        tenant: tenant_dbname_tbname.tenant.clone(),
+        db_name: tenant_dbname_tbname.db_name.clone(),
+        table_name: req.new_table_name.clone(),
+    };
+
+    let mut trials = txn_backoff(None, func_name!());
+    loop {
+        trials.next().unwrap()?.await;
+
+        let (seq_db_id, db_meta) = get_db_or_err(kv_api, &tenant_dbname, "swap_table").await?;
+
+        let mut names = Vec::with_capacity(2);
+        for name_ident in [tenant_dbname_tbname, &tenant_dbname_target] {
+            let dbid_tbname = DBIdTableName {
+                db_id: *seq_db_id.data,
+                table_name: name_ident.table_name.clone(),
+            };
+            let (tb_id_seq, table_id) = get_u64_value(kv_api, &dbid_tbname).await?;
+            if req.if_exists && tb_id_seq == 0 && names.is_empty() {
+                return Ok(RenameTableReply { table_id: 0 });
+            }
+            assert_table_exist(tb_id_seq, name_ident, "swap_table")?;
+
+            let dbid_tbname_idlist = TableIdHistoryIdent {
+                database_id: *seq_db_id.data,
+                table_name: name_ident.table_name.clone(),
+            };
+            let seq_table_history = kv_api.get_pb(&dbid_tbname_idlist).await?;
+            let tb_id_list_seq = seq_table_history.seq();
+            let tb_id_list = seq_table_history
+                .into_value()
+                .unwrap_or_else(|| TableIdList::new_with_ids([table_id]));
+
+            let last = tb_id_list.last().copied();
+            if Some(table_id) != last {
+                let err_message = format!(
+                    "swap_table {:?} but last table id conflict, id list last: {:?}, current: {}",
+                    name_ident, last, table_id
+                );
+                error!("{}", err_message);
+
+                return Err(KVAppError::AppError(AppError::UnknownTable(
+                    UnknownTable::new(&name_ident.table_name, err_message),
+                )));
+            }
+
+            let table_id_to_name_key = TableIdToName { table_id };
+            let table_id_to_name_seq = kv_api.get_seq(&table_id_to_name_key).await?;
+
+            names.push(SwapTableName {
+                dbid_tbname,
+                tb_id_seq,
+                table_id,
+                dbid_tbname_idlist,
+                tb_id_list_seq,
+                tb_id_list,
+                table_id_to_name_key,
+                table_id_to_name_seq,
+            });
+        }
+        let mut b = names.pop().unwrap();
+        let mut a = names.pop().unwrap();
+
+        // The last id in the history of a name is the table it refers to.
+        a.tb_id_list.pop();
+        a.tb_id_list.append(b.table_id);
+        b.tb_id_list.pop();
+        b.tb_id_list.append(a.table_id);
+
+        let txn = TxnRequest {
+            condition: vec![
+                txn_cond_seq(&seq_db_id.data, Eq, db_meta.seq),
+                txn_cond_seq(&a.dbid_tbname, Eq, a.tb_id_seq),
+                txn_cond_seq(&b.dbid_tbname, Eq, b.tb_id_seq),
+                txn_cond_seq(&a.dbid_tbname_idlist, Eq, a.tb_id_list_seq),
+                txn_cond_seq(&b.dbid_tbname_idlist, Eq, b.tb_id_list_seq),
+                txn_cond_seq(&a.table_id_to_name_key, Eq, a.table_id_to_name_seq),
+                txn_cond_seq(&b.table_id_to_name_key, Eq, b.table_id_to_name_seq),
+            ],
+            if_then: vec![
+                txn_op_put(&a.dbid_tbname, serialize_u64(b.table_id)?), /* (db_id, tb_name_a) -> tb_id_b */
+                txn_op_put(&b.dbid_tbname, serialize_u64(a.table_id)?), /* (db_id, tb_name_b) -> tb_id_a */
+                // Changing a table in a db has to update the seq of db_meta,
+                // to block the batch-delete-tables when deleting a db.
+                txn_op_put(&seq_db_id.data, serialize_struct(&*db_meta)?), // (db_id) -> db_meta
+                txn_op_put(&a.dbid_tbname_idlist, serialize_struct(&a.tb_id_list)?),
+                txn_op_put(&b.dbid_tbname_idlist, serialize_struct(&b.tb_id_list)?),
+                txn_op_put(&a.table_id_to_name_key, serialize_struct(&b.dbid_tbname)?),
+                txn_op_put(&b.table_id_to_name_key, serialize_struct(&a.dbid_tbname)?),
+            ],
+            else_then: vec![],
+        };
+
+        let (succ, _responses) = send_txn(kv_api, txn).await?;
+
+        debug!(
+            name :? =(tenant_dbname_tbname),
+            with :? =(&tenant_dbname_target),
+            succ = succ;
+            "swap_table"
+        );
+
+        if succ {
+            return Ok(RenameTableReply {
+                table_id: a.table_id,
+            });
+        }
+    }
+}
+
 fn typ<K>() -> &'static str {
     type_name::<K>()
         .rsplit("::")
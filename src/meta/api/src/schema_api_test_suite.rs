@@ -302,6 +302,7 @@ impl SchemaApiTestSuite {
             .drop_table_without_table_id_list(&b.build().await)
             .await?;
         suite.table_rename(&b.build().await).await?;
+        suite.table_swap(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
         suite.table_update_mask_policy(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
@@ -535,6 +536,7 @@ impl SchemaApiTestSuite {
                     },
                     new_db_name: db2_name.to_string(),
                     new_table_name: table2_name.to_string(),
+                    swap: false,
                 })
                 .await;
             debug!("--- rename table on unknown database got: {:?}", got);
@@ -560,6 +562,7 @@ impl SchemaApiTestSuite {
                     },
                     new_db_name: db3_name.to_string(),
                     new_table_name: table3_name.to_string(),
+                    swap: false,
                 })
                 .await;
             debug!("--- rename table on unknown database got: {:?}", got);
@@ -2232,6 +2235,131 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[fastrace::trace]
+    async fn table_swap<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant_name = "tenant1";
+        let tenant = Tenant::new_or_err(tenant_name, func_name!())?;
+
+        let db_name = "db1";
+        let tb1_name = "tb1";
+        let tb2_name = "tb2";
+
+        let swap_req = |table_name: &str, other: &str, if_exists| RenameTableReq {
+            if_exists,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: db_name.to_string(),
+                table_name: table_name.to_string(),
+            },
+            new_db_name: db_name.to_string(),
+            new_table_name: other.to_string(),
+            swap: true,
+        };
+
+        let create_table_req = |table_name: &str| CreateTableReq {
+            create_option: CreateOption::Create,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: db_name.to_string(),
+                table_name: table_name.to_string(),
+            },
+            table_meta: TableMeta {
+                schema: Arc::new(TableSchema::new(vec![TableField::new(
+                    "number",
+                    TableDataType::Number(NumberDataType::UInt64),
+                )])),
+                engine: "JSON".to_string(),
+                options: maplit::btreemap! {"name".into() => table_name.into()},
+                ..TableMeta::default()
+            },
+            as_dropped: false,
+        };
+
+        info!("--- prepare db");
+        {
+            let plan = CreateDatabaseReq {
+                create_option: CreateOption::Create,
+                name_ident: DatabaseNameIdent::new(&tenant, db_name),
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+
+            mt.create_database(plan).await?;
+        }
+
+        let tb1_id = mt.create_table(create_table_req(tb1_name)).await?.table_id;
+
+        info!("--- swap with unknown table, error");
+        {
+            let res = mt.rename_table(swap_req(tb1_name, tb2_name, false)).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UNKNOWN_TABLE, ErrorCode::from(err).code());
+
+            let res = mt.rename_table(swap_req(tb2_name, tb1_name, false)).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UNKNOWN_TABLE, ErrorCode::from(err).code());
+        }
+
+        info!("--- swap unknown table with if_exists=true, ok");
+        {
+            mt.rename_table(swap_req(tb2_name, tb1_name, true)).await?;
+
+            let got = mt
+                .get_table((tenant_name, db_name, tb1_name).into())
+                .await?;
+            assert_eq!(tb1_id, got.ident.table_id);
+        }
+
+        let tb2_id = mt.create_table(create_table_req(tb2_name)).await?.table_id;
+
+        info!("--- swap tables, ok");
+        {
+            let old_db = mt.get_database(Self::req_get_db(&tenant, db_name)).await?;
+            mt.rename_table(swap_req(tb1_name, tb2_name, false)).await?;
+            let cur_db = mt.get_database(Self::req_get_db(&tenant, db_name)).await?;
+            assert!(old_db.meta.seq < cur_db.meta.seq);
+
+            let got = mt
+                .get_table((tenant_name, db_name, tb1_name).into())
+                .await?;
+            assert_eq!(tb2_id, got.ident.table_id);
+            assert_eq!(
+                Some(&tb2_name.to_string()),
+                got.meta.options.get("name"),
+                "the meta of a table is moved along with its id"
+            );
+
+            let got = mt
+                .get_table((tenant_name, db_name, tb2_name).into())
+                .await?;
+            assert_eq!(tb1_id, got.ident.table_id);
+            assert_eq!(Some(&tb1_name.to_string()), got.meta.options.get("name"));
+
+            let got = mt.get_table_name_by_id(tb1_id).await?;
+            assert_eq!(Some(tb2_name.to_string()), got);
+            let got = mt.get_table_name_by_id(tb2_id).await?;
+            assert_eq!(Some(tb1_name.to_string()), got);
+        }
+
+        info!("--- swap back, ok");
+        {
+            mt.rename_table(swap_req(tb2_name, tb1_name, false)).await?;
+
+            let got = mt
+                .get_table((tenant_name, db_name, tb1_name).into())
+                .await?;
+            assert_eq!(tb1_id, got.ident.table_id);
+            let got = mt
+                .get_table((tenant_name, db_name, tb2_name).into())
+                .await?;
+            assert_eq!(tb2_id, got.ident.table_id);
+        }
+
+        Ok(())
+    }
+
     #[fastrace::trace]
     async fn table_rename<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant_name = "tenant1";
@@ -2260,6 +2388,7 @@ impl SchemaApiTestSuite {
             },
             new_db_name: db1_name.to_string(),
             new_table_name: tb3_name.to_string(),
+            swap: false,
         };
 
         let table_meta = |created_on| TableMeta {
@@ -2420,6 +2549,7 @@ impl SchemaApiTestSuite {
                 },
                 new_db_name: db2_name.to_string(),
                 new_table_name: tb3_name.to_string(),
+                swap: false,
             };
             let res = mt.rename_table(req.clone()).await;
             debug!("--- rename table to other db got: {:?}", res);
@@ -2456,6 +2586,7 @@ impl SchemaApiTestSuite {
                 },
                 new_db_name: db2_name.to_string(),
                 new_table_name: tb3_name.to_string(),
+                swap: false,
             };
             let old_db1 = mt.get_database(Self::req_get_db(&tenant, db1_name)).await?;
             let old_db2 = mt.get_database(Self::req_get_db(&tenant, db2_name)).await?;
@@ -4897,6 +5028,7 @@ impl SchemaApiTestSuite {
                 name_ident: tbl_name_ident.clone(),
                 new_db_name: db_name.to_string(),
                 new_table_name: new_tbl_name.to_string(),
+                swap: false,
             };
 
             let old_db = mt.get_database(Self::req_get_db(&tenant, db_name)).await?;
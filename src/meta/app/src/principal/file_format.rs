@@ -47,6 +47,9 @@ const NULL_FIELD_AS: &str = "null_field_as";
 const NULL_IF: &str = "null_if";
 const OPT_EMPTY_FIELD_AS: &str = "empty_field_as";
 const OPT_BINARY_FORMAT: &str = "binary_format";
+const OPT_COMPRESSION: &str = "compression";
+const OPT_ROW_GROUP_SIZE: &str = "row_group_size";
+const OPT_ENABLE_STATISTICS: &str = "enable_statistics";
 
 /// File format parameters after checking and parsing.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -148,11 +151,25 @@ impl FileFormatParams {
                 )?)
             }
             StageFileFormatType::Parquet => {
+                let default = ParquetFileFormatParams::default();
                 let missing_field_as = reader.options.remove(MISSING_FIELD_AS);
                 let null_if = parse_null_if(reader.options.remove(NULL_IF))?;
+                let compression = reader
+                    .options
+                    .remove(OPT_COMPRESSION)
+                    .map(|c| StageFileCompression::from_str(&c))
+                    .transpose()
+                    .map_err(ErrorCode::IllegalFileFormat)?
+                    .unwrap_or(default.compression);
+                let row_group_size = reader.take_u64(OPT_ROW_GROUP_SIZE, default.row_group_size)?;
+                let enable_statistics =
+                    reader.take_bool(OPT_ENABLE_STATISTICS, default.enable_statistics)?;
                 FileFormatParams::Parquet(ParquetFileFormatParams::try_create(
                     missing_field_as.as_deref(),
                     null_if,
+                    compression,
+                    row_group_size,
+                    enable_statistics,
                 )?)
             }
             StageFileFormatType::Orc => {
@@ -290,10 +307,7 @@ impl FileFormatParams {
 
 impl Default for FileFormatParams {
     fn default() -> Self {
-        FileFormatParams::Parquet(ParquetFileFormatParams {
-            missing_field_as: NullAs::Error,
-            null_if: vec![],
-        })
+        FileFormatParams::Parquet(ParquetFileFormatParams::default())
     }
 }
 
@@ -668,18 +682,65 @@ impl NdJsonFileFormatParams {
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {
     pub missing_field_as: NullAs,
     pub null_if: Vec<String>,
+
+    // The following options only take effect when unloading.
+    pub compression: StageFileCompression,
+    /// Max number of rows in a row group.
+    pub row_group_size: u64,
+    /// Write the min/max/null count statistics of the columns.
+    pub enable_statistics: bool,
+}
+
+impl Default for ParquetFileFormatParams {
+    fn default() -> Self {
+        ParquetFileFormatParams {
+            missing_field_as: NullAs::default(),
+            null_if: vec![],
+            compression: StageFileCompression::Zstd,
+            row_group_size: 1024 * 1024,
+            enable_statistics: false,
+        }
+    }
 }
 
 impl ParquetFileFormatParams {
-    pub fn try_create(missing_field_as: Option<&str>, null_if: Vec<String>) -> Result<Self> {
+    pub fn try_create(
+        missing_field_as: Option<&str>,
+        null_if: Vec<String>,
+        compression: StageFileCompression,
+        row_group_size: u64,
+        enable_statistics: bool,
+    ) -> Result<Self> {
         let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
+        if !matches!(
+            compression,
+            StageFileCompression::None
+                | StageFileCompression::Snappy
+                | StageFileCompression::Gzip
+                | StageFileCompression::Lzo
+                | StageFileCompression::Brotli
+                | StageFileCompression::Zstd
+        ) {
+            return Err(ErrorCode::IllegalFileFormat(format!(
+                "Unsupported compression {:?} for PARQUET, expecting one of NONE, SNAPPY, GZIP, LZO, BROTLI or ZSTD.",
+                compression
+            )));
+        }
+        if row_group_size == 0 {
+            return Err(ErrorCode::IllegalFileFormat(format!(
+                "Invalid option {OPT_ROW_GROUP_SIZE}: expecting a positive number of rows."
+            )));
+        }
         Ok(Self {
             missing_field_as,
             null_if,
+            compression,
+            row_group_size,
+            enable_statistics,
         })
     }
 }
@@ -757,8 +818,11 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(params) => {
                 write!(
                     f,
-                    "TYPE = PARQUET MISSING_FIELD_AS = {}",
-                    params.missing_field_as
+                    "TYPE = PARQUET MISSING_FIELD_AS = {} COMPRESSION = {:?} ROW_GROUP_SIZE = {} ENABLE_STATISTICS = {}",
+                    params.missing_field_as,
+                    params.compression,
+                    params.row_group_size,
+                    params.enable_statistics
                 )
             }
             FileFormatParams::Orc(params) => {
@@ -189,6 +189,8 @@ pub struct UserOption {
     password_policy: Option<String>,
     disabled: Option<bool>,
     must_change_password: Option<bool>,
+    max_queries_per_hour: Option<u64>,
+    max_concurrent_queries: Option<u64>,
 }
 
 impl UserOption {
@@ -200,6 +202,8 @@ impl UserOption {
             password_policy: None,
             disabled: None,
             must_change_password: None,
+            max_queries_per_hour: None,
+            max_concurrent_queries: None,
         }
     }
 
@@ -237,6 +241,16 @@ impl UserOption {
         self
     }
 
+    pub fn with_max_queries_per_hour(mut self, max_queries_per_hour: Option<u64>) -> Self {
+        self.max_queries_per_hour = max_queries_per_hour;
+        self
+    }
+
+    pub fn with_max_concurrent_queries(mut self, max_concurrent_queries: Option<u64>) -> Self {
+        self.max_concurrent_queries = max_concurrent_queries;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -266,6 +280,17 @@ impl UserOption {
         self.must_change_password.as_ref()
     }
 
+    /// The max number of queries the user can start in an hour, `None` or 0 means no limit.
+    pub fn max_queries_per_hour(&self) -> Option<&u64> {
+        self.max_queries_per_hour.as_ref()
+    }
+
+    /// The max number of queries of the user running at the same time on a node,
+    /// `None` or 0 means no limit.
+    pub fn max_concurrent_queries(&self) -> Option<&u64> {
+        self.max_concurrent_queries.as_ref()
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -326,6 +351,8 @@ impl UserOption {
             UserOptionItem::UnsetPasswordPolicy => self.password_policy = None,
             UserOptionItem::Disabled(v) => self.disabled = Some(*v),
             UserOptionItem::MustChangePassword(v) => self.must_change_password = Some(*v),
+            UserOptionItem::MaxQueriesPerHour(v) => self.max_queries_per_hour = Some(*v),
+            UserOptionItem::MaxConcurrentQueries(v) => self.max_concurrent_queries = Some(*v),
         }
     }
 }
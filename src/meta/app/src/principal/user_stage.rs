@@ -174,6 +174,8 @@ pub enum StageFileFormatType {
     Orc,
     Parquet,
     Xml,
+    /// Arrow IPC stream, only supported as the output format of queries.
+    ArrowStream,
     None,
 }
 
@@ -194,6 +196,7 @@ impl FromStr for StageFileFormatType {
             "XML" => Ok(StageFileFormatType::Xml),
             "JSON" => Ok(StageFileFormatType::Json),
             "ORC" => Ok(StageFileFormatType::Orc),
+            "ARROWSTREAM" => Ok(StageFileFormatType::ArrowStream),
             "AVRO" => Err(format!(
                 "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC)"
             )),
@@ -215,6 +218,7 @@ impl Display for StageFileFormatType {
             StageFileFormatType::Orc => write!(f, "ORC"),
             StageFileFormatType::Parquet => write!(f, "PARQUET"),
             StageFileFormatType::Xml => write!(f, "XML"),
+            StageFileFormatType::ArrowStream => write!(f, "ARROWSTREAM"),
             StageFileFormatType::None => write!(f, "NONE"),
         }
     }
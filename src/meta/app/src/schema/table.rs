@@ -699,6 +699,9 @@ pub struct RenameTableReq {
     pub name_ident: TableNameIdent,
     pub new_db_name: String,
     pub new_table_name: String,
+    /// Exchange the names of the two tables instead of renaming one,
+    /// the tables must be in the same database.
+    pub swap: bool,
 }
 
 impl RenameTableReq {
@@ -717,7 +720,12 @@ impl Display for RenameTableReq {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
             f,
-            "rename_table:{}/{}-{}=>{}-{}",
+            "{}:{}/{}-{}=>{}-{}",
+            if self.swap {
+                "swap_table"
+            } else {
+                "rename_table"
+            },
             self.tenant().tenant_name(),
             self.db_name(),
             self.table_name(),
@@ -57,6 +57,10 @@ impl FromToProtoEnum for mt::principal::StageFileFormatType {
             mt::principal::StageFileFormatType::Orc => Ok(pb::StageFileFormatType::Orc),
             mt::principal::StageFileFormatType::Parquet => Ok(pb::StageFileFormatType::Parquet),
             mt::principal::StageFileFormatType::Xml => Ok(pb::StageFileFormatType::Xml),
+            mt::principal::StageFileFormatType::ArrowStream => Err(Incompatible {
+                reason: "StageFileFormatType::ArrowStream cannot be converted to protobuf"
+                    .to_string(),
+            }),
             mt::principal::StageFileFormatType::None => Err(Incompatible {
                 reason: "StageFileFormatType::None cannot be converted to protobuf".to_string(),
             }),
@@ -330,18 +334,38 @@ impl FromToProto for mt::principal::ParquetFileFormatParams {
     fn from_pb(p: pb::ParquetFileFormatParams) -> Result<Self, Incompatible>
     where Self: Sized {
         reader_check_msg(p.ver, p.min_reader_ver)?;
-        mt::principal::ParquetFileFormatParams::try_create(p.missing_field_as.as_deref(), p.null_if)
-            .map_err(|e| Incompatible {
-                reason: format!("{e}"),
-            })
+        let default = mt::principal::ParquetFileFormatParams::default();
+        let compression = match p.compression {
+            Some(c) => mt::principal::StageFileCompression::from_pb_enum(
+                FromPrimitive::from_i32(c).ok_or_else(|| Incompatible {
+                    reason: format!("invalid StageFileCompression: {}", c),
+                })?,
+            )?,
+            None => default.compression,
+        };
+        mt::principal::ParquetFileFormatParams::try_create(
+            p.missing_field_as.as_deref(),
+            p.null_if,
+            compression,
+            p.row_group_size.unwrap_or(default.row_group_size),
+            p.enable_statistics.unwrap_or(default.enable_statistics),
+        )
+        .map_err(|e| Incompatible {
+            reason: format!("{e}"),
+        })
     }
 
     fn to_pb(&self) -> Result<pb::ParquetFileFormatParams, Incompatible> {
+        let compression =
+            mt::principal::StageFileCompression::to_pb_enum(&self.compression)? as i32;
         Ok(pb::ParquetFileFormatParams {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
             missing_field_as: Some(self.missing_field_as.to_string()),
             null_if: self.null_if.clone(),
+            compression: Some(compression),
+            row_group_size: Some(self.row_group_size),
+            enable_statistics: Some(self.enable_statistics),
         })
     }
 }
@@ -106,7 +106,9 @@ impl FromToProto for mt::principal::UserOption {
             .with_network_policy(p.network_policy)
             .with_password_policy(p.password_policy)
             .with_disabled(p.disabled)
-            .with_must_change_password(p.must_change_password))
+            .with_must_change_password(p.must_change_password)
+            .with_max_queries_per_hour(p.max_queries_per_hour)
+            .with_max_concurrent_queries(p.max_concurrent_queries))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -119,6 +121,8 @@ impl FromToProto for mt::principal::UserOption {
             password_policy: self.password_policy().cloned(),
             disabled: self.disabled().cloned(),
             must_change_password: self.must_change_password().cloned(),
+            max_queries_per_hour: self.max_queries_per_hour().cloned(),
+            max_concurrent_queries: self.max_concurrent_queries().cloned(),
         })
     }
 }
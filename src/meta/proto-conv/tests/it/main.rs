@@ -108,3 +108,5 @@ mod v107_geography_datatype;
 mod v108_procedure;
 mod v109_procedure_with_args;
 mod v110_database_meta_gc_in_progress;
+mod v111_parquet_format_params_unload_options;
+mod v112_user_quota_option;
@@ -18,5 +18,6 @@ use crate::configs::Config;
 
 #[poem::handler]
 pub async fn config_handler(cfg: Data<&Config>) -> String {
+    // `Debug` of the config masks the admin api token.
     format!("{:?}", cfg.0)
 }
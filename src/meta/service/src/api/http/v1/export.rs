@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio::sync::mpsc;
+use databend_common_base::base::tokio::time::timeout;
+use databend_common_base::runtime;
+use databend_common_metrics::count::Count;
+use futures::StreamExt;
+use http::header::AUTHORIZATION;
+use http::StatusCode;
+use log::info;
+use log::warn;
+use poem::web::Data;
+use poem::Body;
+use poem::IntoResponse;
+use poem::Request;
+use subtle::ConstantTimeEq;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::configs::Config;
+use crate::meta_service::MetaNode;
+use crate::metrics::RequestInFlight;
+
+/// Abort the export if the client does not receive the next line in this time.
+const EXPORT_SEND_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Export all meta data of this node as a download.
+///
+/// Including header, raft state, logs and state machine.
+/// The body is the same as the output of `databend-metactl export`:
+/// one JSON encoded `RaftStoreEntry` per line.
+/// It is restored into a new cluster with `databend-metactl import --db <file>`.
+///
+/// It requires `Authorization: Bearer <admin_api_token>` and is disabled if no token is configured.
+#[poem::handler]
+pub async fn export_handler(
+    req: &Request,
+    cfg: Data<&Config>,
+    meta_node: Data<&Arc<MetaNode>>,
+) -> poem::Result<impl IntoResponse> {
+    check_admin_token(req, cfg.0)?;
+
+    let guard = RequestInFlight::guard();
+    let mut strm = meta_node.sto.inner().export();
+
+    // The export copies what it reads under the locks before yielding the first line,
+    // a slow client only holds the snapshot of the state machine, until the timeout.
+    let (tx, rx) = mpsc::channel(1024);
+    runtime::spawn(async move {
+        let _guard = guard;
+
+        while let Some(res) = strm.next().await {
+            let failed = res.is_err();
+            let item = res.map(|line| format!("{}\n", line));
+
+            match timeout(EXPORT_SEND_TIMEOUT, tx.send(item)).await {
+                Ok(Ok(())) => {}
+                Ok(Err(_)) => {
+                    info!("export is stopped, the client is disconnected");
+                    return;
+                }
+                Err(_) => {
+                    warn!(
+                        "export is aborted, the client did not receive data in {:?}",
+                        EXPORT_SEND_TIMEOUT
+                    );
+                    return;
+                }
+            }
+
+            if failed {
+                return;
+            }
+        }
+    });
+
+    Ok(Body::from_bytes_stream(ReceiverStream::new(rx))
+        .with_content_type("application/x-ndjson")
+        .with_header(
+            "Content-Disposition",
+            "attachment; filename=\"meta-export.ndjson\"",
+        ))
+}
+
+fn check_admin_token(req: &Request, cfg: &Config) -> poem::Result<()> {
+    if cfg.admin_api_token.is_empty() {
+        return Err(poem::Error::from_string(
+            "export is disabled, admin_api_token is not configured",
+            StatusCode::FORBIDDEN,
+        ));
+    }
+
+    let token = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .unwrap_or_default();
+
+    if bool::from(token.as_bytes().ct_eq(cfg.admin_api_token.as_bytes())) {
+        Ok(())
+    } else {
+        Err(poem::Error::from_string(
+            "invalid admin api token",
+            StatusCode::UNAUTHORIZED,
+        ))
+    }
+}
@@ -15,4 +15,5 @@
 pub mod cluster_state;
 pub mod config;
 pub mod ctrl;
+pub mod export;
 pub mod metrics;
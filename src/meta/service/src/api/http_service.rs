@@ -65,6 +65,10 @@ impl HttpService {
                 "/v1/ctrl/trigger_transfer_leader",
                 get(super::http::v1::ctrl::trigger_transfer_leader),
             )
+            .at(
+                "/v1/ctrl/export",
+                get(super::http::v1::export::export_handler),
+            )
             .at(
                 "/v1/cluster/nodes",
                 get(super::http::v1::cluster_state::nodes_handler),
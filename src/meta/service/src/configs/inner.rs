@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
 use std::net::SocketAddr;
 
 use databend_common_meta_raft_store::config::RaftConfig;
@@ -21,7 +24,7 @@ use databend_common_tracing::Config as LogConfig;
 
 use super::outer_v0::Config as OuterV0Config;
 
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, serde::Serialize)]
 pub struct Config {
     pub cmd: String,
     pub key: Vec<String>,
@@ -35,6 +38,9 @@ pub struct Config {
     pub admin_api_address: String,
     pub admin_tls_server_cert: String,
     pub admin_tls_server_key: String,
+    /// Not printed with the config, it grants reading all the meta data.
+    #[serde(skip_serializing)]
+    pub admin_api_token: String,
     pub grpc_api_address: String,
     pub grpc_api_advertise_host: Option<String>,
     /// Certificate for server to identify itself
@@ -43,6 +49,37 @@ pub struct Config {
     pub raft_config: RaftConfig,
 }
 
+impl Debug for Config {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // The token grants reading all the meta data, never print it.
+        let admin_api_token = if self.admin_api_token.is_empty() {
+            ""
+        } else {
+            "******"
+        };
+        f.debug_struct("Config")
+            .field("cmd", &self.cmd)
+            .field("key", &self.key)
+            .field("value", &self.value)
+            .field("expire_after", &self.expire_after)
+            .field("prefix", &self.prefix)
+            .field("username", &self.username)
+            .field("password", &self.password)
+            .field("config_file", &self.config_file)
+            .field("log", &self.log)
+            .field("admin_api_address", &self.admin_api_address)
+            .field("admin_tls_server_cert", &self.admin_tls_server_cert)
+            .field("admin_tls_server_key", &self.admin_tls_server_key)
+            .field("admin_api_token", &admin_api_token)
+            .field("grpc_api_address", &self.grpc_api_address)
+            .field("grpc_api_advertise_host", &self.grpc_api_advertise_host)
+            .field("grpc_tls_server_cert", &self.grpc_tls_server_cert)
+            .field("grpc_tls_server_key", &self.grpc_tls_server_key)
+            .field("raft_config", &self.raft_config)
+            .finish()
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -58,6 +95,7 @@ impl Default for Config {
             admin_api_address: "127.0.0.1:28002".to_string(),
             admin_tls_server_cert: "".to_string(),
             admin_tls_server_key: "".to_string(),
+            admin_api_token: "".to_string(),
             grpc_api_address: "127.0.0.1:9191".to_string(),
             grpc_api_advertise_host: None,
             grpc_tls_server_cert: "".to_string(),
@@ -20,6 +20,7 @@ use clap::Parser;
 use databend_common_meta_raft_store::config::get_default_raft_advertise_host;
 use databend_common_meta_raft_store::config::RaftConfig as InnerRaftConfig;
 use databend_common_meta_types::MetaStartupError;
+use databend_common_tracing::AuditLogConfig;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
 use databend_common_tracing::OTLPConfig;
@@ -109,6 +110,11 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub admin_tls_server_key: String,
 
+    /// Bearer token required by the admin API endpoints that read all the meta data,
+    /// such as `/v1/ctrl/export`. These endpoints are disabled if it is empty.
+    #[clap(long, default_value = "")]
+    pub admin_api_token: String,
+
     /// Listening address for public APIs
     ///
     /// This address is only used by meta service to build a listening endpoint.
@@ -161,6 +167,7 @@ impl From<Config> for InnerConfig {
             admin_api_address: outer.admin_api_address,
             admin_tls_server_cert: outer.admin_tls_server_cert,
             admin_tls_server_key: outer.admin_tls_server_key,
+            admin_api_token: outer.admin_api_token,
             grpc_api_address: outer.grpc_api_address,
             grpc_api_advertise_host: outer.grpc_api_advertise_host,
             grpc_tls_server_cert: outer.grpc_tls_server_cert,
@@ -187,6 +194,7 @@ impl From<InnerConfig> for Config {
             admin_api_address: inner.admin_api_address,
             admin_tls_server_cert: inner.admin_tls_server_cert,
             admin_tls_server_key: inner.admin_tls_server_key,
+            admin_api_token: inner.admin_api_token,
             grpc_api_address: inner.grpc_api_address,
             grpc_api_advertise_host: inner.grpc_api_advertise_host,
             grpc_tls_server_cert: inner.grpc_tls_server_cert,
@@ -274,6 +282,7 @@ pub struct ConfigViaEnv {
     pub admin_api_address: String,
     pub admin_tls_server_cert: String,
     pub admin_tls_server_key: String,
+    pub admin_api_token: String,
     pub metasrv_grpc_api_address: String,
     pub metasrv_grpc_api_advertise_host: Option<String>,
     pub grpc_tls_server_cert: String,
@@ -328,6 +337,7 @@ impl From<Config> for ConfigViaEnv {
             admin_api_address: cfg.admin_api_address,
             admin_tls_server_cert: cfg.admin_tls_server_cert,
             admin_tls_server_key: cfg.admin_tls_server_key,
+            admin_api_token: cfg.admin_api_token,
             metasrv_grpc_api_address: cfg.grpc_api_address,
             metasrv_grpc_api_advertise_host: cfg.grpc_api_advertise_host,
             grpc_tls_server_cert: cfg.grpc_tls_server_cert,
@@ -426,6 +436,7 @@ impl Into<Config> for ConfigViaEnv {
             admin_api_address: self.admin_api_address,
             admin_tls_server_cert: self.admin_tls_server_cert,
             admin_tls_server_key: self.admin_tls_server_key,
+            admin_api_token: self.admin_api_token,
             grpc_api_address: self.metasrv_grpc_api_address,
             grpc_api_advertise_host: self.metasrv_grpc_api_advertise_host,
             grpc_tls_server_cert: self.grpc_tls_server_cert,
@@ -665,6 +676,7 @@ impl Into<InnerLogConfig> for LogConfig {
             query: QueryLogConfig::default(),
             profile: ProfileLogConfig::default(),
             structlog: StructLogConfig::default(),
+            audit: AuditLogConfig::default(),
             tracing: TracingConfig::default(),
         }
     }
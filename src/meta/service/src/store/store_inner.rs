@@ -347,6 +347,10 @@ impl StoreInner {
         let raft_state = self.raft_state.read().await;
         let log = self.log.read().await;
 
+        // Collect everything protected by the locks before yielding anything,
+        // a slow consumer must not keep the locks or the compactor held.
+        let mut lines = vec![];
+
         // Export data header first
         {
             let header_tree = SledTree::open(&self.db, TREE_HEADER, false).map_err(invalid_data)?;
@@ -355,7 +359,7 @@ impl StoreInner {
 
             for kv in header_kvs.iter() {
                 let line = vec_kv_to_json(TREE_HEADER, kv)?;
-                yield line;
+                lines.push(line);
             }
         }
 
@@ -374,7 +378,7 @@ impl StoreInner {
                 };
 
                 let s = serde_json::to_string(&(tree_name, ent_id)).map_err(invalid_data)?;
-                yield s;
+                lines.push(s);
             }
 
             let vote = ks.get(&RaftStateKey::HardState)?.map(Vote::from);
@@ -386,7 +390,7 @@ impl StoreInner {
                 };
 
                 let s = serde_json::to_string(&(tree_name, ent_vote)).map_err(invalid_data)?;
-                yield s;
+                lines.push(s);
             }
 
             let committed = ks
@@ -399,7 +403,7 @@ impl StoreInner {
             };
 
             let s = serde_json::to_string(&(tree_name, ent_committed)).map_err(invalid_data)?;
-            yield s;
+            lines.push(s);
         };
 
         drop(raft_state);
@@ -419,6 +423,10 @@ impl StoreInner {
         let db = compactor.db().cloned();
         drop(compactor);
 
+        for line in lines {
+            yield line;
+        }
+
         for kv in log_kvs.iter() {
             let kv_entry = RaftStoreEntry::deserialize(&kv[0], &kv[1])?;
 
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_raft_store::key_spaces::RaftStoreEntry;
+use databend_meta::api::http::v1::export::export_handler;
+use databend_meta::meta_service::MetaNode;
+use futures::TryStreamExt;
+use http::Method;
+use http::StatusCode;
+use http::Uri;
+use poem::get;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::meta_node::timeout;
+use crate::tests::service::MetaSrvTestContext;
+
+/// Test http API "/v1/ctrl/export" returns the same lines as the export of the store,
+/// which is what `databend-metactl export` outputs and `databend-metactl import` reads.
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_export() -> anyhow::Result<()> {
+    let mut tc = MetaSrvTestContext::new(0);
+    tc.config.admin_api_token = "export-token".to_string();
+    let mn = MetaNode::start(&tc.config).await?;
+
+    // Wait for the leader to apply its blank log, after that the store does not change.
+    mn.raft
+        .wait(timeout())
+        .metrics(
+            |m| m.current_leader.is_some() && m.last_applied.map(|l| l.index) == m.last_log_index,
+            "the leader applied all logs",
+        )
+        .await?;
+
+    let router = Route::new()
+        .at("/v1/ctrl/export", get(export_handler))
+        .data(mn.clone())
+        .data(tc.config.clone());
+
+    let export = |auth: Option<&'static str>| {
+        let mut builder = Request::builder()
+            .uri(Uri::from_static("/v1/ctrl/export"))
+            .method(Method::GET);
+        if let Some(auth) = auth {
+            builder = builder.header("Authorization", auth);
+        }
+        router.get_response(builder.finish())
+    };
+
+    // Without or with a wrong token.
+    let response = export(None).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = export(Some("Bearer wrong-token")).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = export(Some("Bearer export-token")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().into_string().await?;
+    let got = body.lines().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    let want = mn.sto.inner().export().try_collect::<Vec<_>>().await?;
+    assert_eq!(want, got);
+
+    let entries = got
+        .iter()
+        .map(|line| serde_json::from_str::<(String, RaftStoreEntry)>(line))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert!(entries.iter().any(|(tree, _)| tree == "header"));
+    assert!(entries.iter().any(|(tree, _)| tree == "raft_state"));
+    Ok(())
+}
+
+/// Test http API "/v1/ctrl/export" is disabled if no token is configured.
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_export_disabled() -> anyhow::Result<()> {
+    let tc = MetaSrvTestContext::new(0);
+    let mn = MetaNode::start(&tc.config).await?;
+
+    let router = Route::new()
+        .at("/v1/ctrl/export", get(export_handler))
+        .data(mn.clone())
+        .data(tc.config.clone());
+
+    let response = router
+        .get_response(
+            Request::builder()
+                .uri(Uri::from_static("/v1/ctrl/export"))
+                .method(Method::GET)
+                .header("Authorization", "Bearer ")
+                .finish(),
+        )
+        .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    Ok(())
+}
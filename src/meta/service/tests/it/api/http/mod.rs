@@ -14,5 +14,6 @@
 
 pub mod cluster_state_test;
 pub mod config;
+pub mod export;
 pub mod metrics;
 pub mod transfer_leader;
@@ -45,6 +45,7 @@ metric_api_address = "127.0.0.1:8000"
 admin_api_address = "127.0.0.1:9000"
 admin_tls_server_cert = "admin tls cert"
 admin_tls_server_key = "admin tls key"
+admin_api_token = "admin token"
 grpc_api_address = "127.0.0.1:10000"
 grpc_tls_server_cert = "grpc server cert"
 grpc_tls_server_key = "grpc server key"
@@ -75,6 +76,8 @@ cluster_name = "foo_cluster"
         assert_eq!(cfg.admin_api_address, "127.0.0.1:9000");
         assert_eq!(cfg.admin_tls_server_cert, "admin tls cert");
         assert_eq!(cfg.admin_tls_server_key, "admin tls key");
+        assert_eq!(cfg.admin_api_token, "admin token");
+        assert!(!format!("{:?}", cfg).contains("admin token"));
         assert_eq!(cfg.grpc_api_address, "127.0.0.1:10000");
         assert_eq!(cfg.grpc_tls_server_cert, "grpc server cert");
         assert_eq!(cfg.grpc_tls_server_key, "grpc server key");
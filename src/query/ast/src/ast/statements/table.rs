@@ -394,6 +394,9 @@ pub enum AlterTableAction {
     RenameTable {
         new_table: Identifier,
     },
+    SwapWith {
+        target_table: Identifier,
+    },
     AddColumn {
         column: ColumnDefinition,
         option: AddColumnOption,
@@ -443,6 +446,9 @@ impl Display for AlterTableAction {
             AlterTableAction::RenameTable { new_table } => {
                 write!(f, "RENAME TO {new_table}")?;
             }
+            AlterTableAction::SwapWith { target_table } => {
+                write!(f, "SWAP WITH {target_table}")?;
+            }
             AlterTableAction::ModifyTableComment { new_comment } => {
                 write!(f, "COMMENT='{new_comment}'")?;
             }
@@ -729,6 +735,10 @@ pub enum Engine {
     Random,
     Iceberg,
     Delta,
+    Parquet,
+    Csv,
+    Tsv,
+    NdJson,
 }
 
 impl Display for Engine {
@@ -741,6 +751,10 @@ impl Display for Engine {
             Engine::Random => write!(f, "RANDOM"),
             Engine::Iceberg => write!(f, "ICEBERG"),
             Engine::Delta => write!(f, "DELTA"),
+            Engine::Parquet => write!(f, "PARQUET"),
+            Engine::Csv => write!(f, "CSV"),
+            Engine::Tsv => write!(f, "TSV"),
+            Engine::NdJson => write!(f, "NDJSON"),
         }
     }
 }
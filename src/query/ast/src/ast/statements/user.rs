@@ -262,6 +262,8 @@ pub enum UserOptionItem {
     SetPasswordPolicy(String),
     UnsetPasswordPolicy,
     MustChangePassword(bool),
+    MaxQueriesPerHour(u64),
+    MaxConcurrentQueries(u64),
 }
 
 impl Display for UserOptionItem {
@@ -276,6 +278,8 @@ impl Display for UserOptionItem {
             UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
             UserOptionItem::Disabled(v) => write!(f, "DISABLED = {}", v),
             UserOptionItem::MustChangePassword(v) => write!(f, "MUST_CHANGE_PASSWORD = {}", v),
+            UserOptionItem::MaxQueriesPerHour(v) => write!(f, "MAX_QUERIES_PER_HOUR = {}", v),
+            UserOptionItem::MaxConcurrentQueries(v) => write!(f, "MAX_CONCURRENT_QUERIES = {}", v),
         }
     }
 }
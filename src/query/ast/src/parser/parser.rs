@@ -18,6 +18,7 @@ use pretty_assertions::assert_eq;
 
 use crate::ast::ExplainKind;
 use crate::ast::Expr;
+use crate::ast::FileFormatOptions;
 use crate::ast::Identifier;
 use crate::ast::Literal;
 use crate::ast::SelectTarget;
@@ -25,6 +26,7 @@ use crate::ast::Statement;
 use crate::ast::StatementWithFormat;
 use crate::parser::common::comma_separated_list0;
 use crate::parser::common::comma_separated_list1;
+use crate::parser::common::dot_separated_idents_1_to_3;
 use crate::parser::common::ident;
 use crate::parser::common::transform_span;
 use crate::parser::common::IResult;
@@ -34,6 +36,7 @@ use crate::parser::expr::values_with_placeholder;
 use crate::parser::input::Dialect;
 use crate::parser::input::Input;
 use crate::parser::input::ParseMode;
+use crate::parser::stage::format_options;
 use crate::parser::statement::insert_stmt;
 use crate::parser::statement::replace_stmt;
 use crate::parser::statement::statement;
@@ -77,6 +80,25 @@ pub fn parse_comma_separated_idents(tokens: &[Token], dialect: Dialect) -> Resul
     })
 }
 
+/// Parse a table name like `[catalog.][database.]table`.
+pub fn parse_table_name(
+    tokens: &[Token],
+    dialect: Dialect,
+) -> Result<(Option<Identifier>, Option<Identifier>, Identifier)> {
+    run_parser(
+        tokens,
+        dialect,
+        ParseMode::Default,
+        false,
+        dot_separated_idents_1_to_3,
+    )
+}
+
+/// Parse the options inside `FILE_FORMAT = (...)`, e.g. `type = CSV skip_header = 1`.
+pub fn parse_file_format_options(tokens: &[Token], dialect: Dialect) -> Result<FileFormatOptions> {
+    run_parser(tokens, dialect, ParseMode::Default, false, format_options)
+}
+
 pub fn parse_values_with_placeholder(
     tokens: &[Token],
     dialect: Dialect,
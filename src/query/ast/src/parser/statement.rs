@@ -3462,6 +3462,12 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
         |(_, _, new_table)| AlterTableAction::RenameTable { new_table },
     );
+    let swap_with = map(
+        rule! {
+           SWAP ~ WITH ~ #ident
+        },
+        |(_, _, target_table)| AlterTableAction::SwapWith { target_table },
+    );
     let rename_column = map(
         rule! {
             RENAME ~ COLUMN? ~ #ident ~ TO ~ #ident
@@ -3555,6 +3561,7 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         #alter_table_cluster_key
         | #drop_table_cluster_key
         | #rename_table
+        | #swap_with
         | #rename_column
         | #modify_table_comment
         | #add_column
@@ -4049,6 +4056,10 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Random, rule! { RANDOM }),
         value(Engine::Iceberg, rule! { ICEBERG }),
         value(Engine::Delta, rule! { DELTA }),
+        value(Engine::Parquet, rule! { PARQUET }),
+        value(Engine::Csv, rule! { CSV }),
+        value(Engine::Tsv, rule! { TSV }),
+        value(Engine::NdJson, rule! { NDJSON }),
     ));
 
     map(
@@ -4132,6 +4143,18 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, val)| UserOptionItem::MustChangePassword(val),
     );
+    let max_queries_per_hour = map(
+        rule! {
+            MAX_QUERIES_PER_HOUR ~ ^"=" ~ ^#literal_u64
+        },
+        |(_, _, val)| UserOptionItem::MaxQueriesPerHour(val),
+    );
+    let max_concurrent_queries = map(
+        rule! {
+            MAX_CONCURRENT_QUERIES ~ ^"=" ~ ^#literal_u64
+        },
+        |(_, _, val)| UserOptionItem::MaxConcurrentQueries(val),
+    );
 
     rule!(
         #tenant_setting
@@ -4143,6 +4166,8 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         | #unset_password_policy
         | #set_disabled_option
         | #must_change_password
+        | #max_queries_per_hour
+        | #max_concurrent_queries
     )(i)
 }
 
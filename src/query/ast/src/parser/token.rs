@@ -788,8 +788,12 @@ pub enum TokenKind {
     MASKING,
     #[token("MAP", ignore(ascii_case))]
     MAP,
+    #[token("MAX_CONCURRENT_QUERIES", ignore(ascii_case))]
+    MAX_CONCURRENT_QUERIES,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_QUERIES_PER_HOUR", ignore(ascii_case))]
+    MAX_QUERIES_PER_HOUR,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEDIUM", ignore(ascii_case))]
@@ -1074,6 +1078,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("SWAP", ignore(ascii_case))]
+    SWAP,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
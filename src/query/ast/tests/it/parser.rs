@@ -168,6 +168,8 @@ fn test_statement() {
         r#"describe stream test2.s2;"#,
         r#"drop stream if exists test2.s2;"#,
         r#"rename table d.t to e.s;"#,
+        r#"alter table d.t swap with s;"#,
+        r#"alter table if exists t swap with s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
         r#"DROP table table1;"#,
@@ -183,6 +185,7 @@ fn test_statement() {
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1, DISABLED=true, TENANTSETTING;"#,
         r#"ALTER USER u1 WITH SET NETWORK POLICY = 'policy1';"#,
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER u1 WITH MAX_QUERIES_PER_HOUR = 100, MAX_CONCURRENT_QUERIES = 2;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH disabled=true"#,
@@ -27,10 +27,18 @@ use databend_common_storage::init_stage_operator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
 
+/// The default value of a column to fill the missing values of the files.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum RemoteDefaultExpr {
+    RemoteExpr(RemoteExpr),
+    /// `nextval(<sequence>)`, which has to be allocated for each row.
+    Sequence(String),
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq)]
 pub struct StageTableInfo {
     pub schema: TableSchemaRef,
-    pub default_values: Option<Vec<RemoteExpr>>,
+    pub default_values: Option<Vec<RemoteDefaultExpr>>,
     pub files_info: StageFilesInfo,
     pub stage_info: StageInfo,
     pub files_to_copy: Option<Vec<StageFileInfo>>,
@@ -20,6 +20,7 @@ use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use cidr::IpCidr;
 use clap::ArgAction;
 use clap::Args;
 use clap::Parser;
@@ -44,6 +45,7 @@ use databend_common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhd
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_app::tenant::TenantQuota;
 use databend_common_storage::StorageConfig as InnerStorageConfig;
+use databend_common_tracing::AuditLogConfig as InnerAuditLogConfig;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
 use databend_common_tracing::OTLPConfig as InnerOTLPLogConfig;
@@ -102,6 +104,11 @@ pub struct Config {
     #[clap(long)]
     pub cmd: Option<String>,
 
+    /// Run in local mode, the same as the `local` subcommand.
+    #[clap(long)]
+    #[serde(skip)]
+    pub local: bool,
+
     #[clap(long, short = 'c', value_name = "VALUE", default_value_t)]
     pub config_file: String,
 
@@ -158,6 +165,8 @@ pub struct Config {
 pub enum Commands {
     #[default]
     Ver,
+    /// Print the config merged from the config file, env and args, with the secrets masked.
+    ShowConfig,
     Local {
         #[clap(long, short = 'q', default_value_t)]
         query: String,
@@ -192,18 +201,29 @@ impl Config {
             arg_conf = Self::parse();
         }
 
-        if arg_conf.cmd == Some("ver".to_string()) {
-            arg_conf.subcommand = Some(Commands::Ver);
+        match arg_conf.cmd.as_deref() {
+            Some("ver") => arg_conf.subcommand = Some(Commands::Ver),
+            Some("show-config") => arg_conf.subcommand = Some(Commands::ShowConfig),
+            _ => {}
         }
 
-        if arg_conf.subcommand.is_some() {
+        if arg_conf.local && arg_conf.subcommand.is_none() {
+            arg_conf.subcommand = Some(Commands::Local {
+                query: "".to_string(),
+                output_format: "".to_string(),
+            });
+        }
+
+        // `show-config` needs the merged config, other subcommands don't.
+        let subcommand = arg_conf.subcommand.clone();
+        if subcommand.is_some() && subcommand != Some(Commands::ShowConfig) {
             return Ok(arg_conf);
         }
 
         let mut builder: serfig::Builder<Self> = serfig::Builder::default();
 
         // Load from config file first.
-        {
+        let config_file = {
             let config_file = if !arg_conf.config_file.is_empty() {
                 // TODO: remove this `allow(clippy::redundant_clone)`
                 // as soon as this issue is fixed:
@@ -219,7 +239,8 @@ impl Config {
             if !config_file.is_empty() {
                 builder = builder.collect(from_file(Toml, &config_file));
             }
-        }
+            config_file
+        };
 
         // Then, load from env.
         builder = builder.collect(from_env());
@@ -229,10 +250,19 @@ impl Config {
             builder = builder.collect(from_self(arg_conf));
         }
 
+        // The error names the unknown or malformed key, e.g. an unknown field of `[query]`.
+        let mut conf = builder.build().map_err(|e| {
+            let source = match config_file.is_empty() {
+                true => "env and args".to_string(),
+                false => format!("config file {:?}, env and args", config_file),
+            };
+            ErrorCode::InvalidConfig(format!("failed to load config from {}: {:?}", source, e))
+        })?;
+
         // Check obsoleted.
-        let conf = builder.build()?;
         conf.check_obsoleted()?;
 
+        conf.subcommand = subcommand;
         Ok(conf)
     }
 }
@@ -253,7 +283,7 @@ impl Config {
 /// type = "s3"
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct StorageConfig {
     #[clap(long = "storage-type", value_name = "VALUE", default_value = "fs")]
     #[serde(rename = "type")]
@@ -1402,12 +1432,24 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub mysql_tls_server_key: String,
 
+    /// CA used to verify client certificates, client certificates are required if set.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub mysql_tls_server_root_ca_cert: String,
+
+    /// The client ips or CIDR ranges allowed to connect to the mysql handler, empty means no restriction.
+    #[clap(long, value_name = "VALUE")]
+    pub mysql_handler_allowed_ip_list: Vec<String>,
+
     #[clap(long, value_name = "VALUE", default_value = "256")]
     pub max_active_sessions: u64,
 
     #[clap(long, value_name = "VALUE", default_value = "8")]
     pub max_running_queries: u64,
 
+    /// The max number of queries a single user can run at the same time, 0 means no limit.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub max_running_queries_per_user: u64,
+
     /// The max total memory in bytes that can be used by this process.
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub max_server_memory_usage: u64,
@@ -1443,6 +1485,14 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "60")]
     pub http_handler_result_timeout_secs: u64,
 
+    /// Max requests per second allowed for each client ip on the http handler, 0 means unlimited
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub http_handler_rate_limit_per_sec: u64,
+
+    /// The client ips or CIDR ranges allowed to connect to the http handler, empty means no restriction.
+    #[clap(long, value_name = "VALUE")]
+    pub http_handler_allowed_ip_list: Vec<String>,
+
     #[clap(long, value_name = "VALUE", default_value = "127.0.0.1")]
     pub flight_sql_handler_host: String,
 
@@ -1458,6 +1508,27 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "127.0.0.1:8080")]
     pub admin_api_address: String,
 
+    /// Enable the /debug endpoints (pprof, memory stats) of the admin api
+    #[clap(
+        long,
+        value_name = "VALUE",
+        value_parser = clap::value_parser!(bool),
+        default_value = "true"
+    )]
+    pub admin_api_enable_debug: bool,
+
+    /// Bearer token required by all the admin api endpoints, empty means no authentication
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub admin_api_auth_token: String,
+
+    /// Bearer token only allowed to access the read-only (GET) admin api endpoints
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub admin_api_read_only_token: String,
+
+    /// Max requests per second allowed for each client ip on the admin api, 0 means unlimited
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub admin_api_rate_limit_per_sec: u64,
+
     #[clap(long, value_name = "VALUE", default_value = "127.0.0.1:7070")]
     pub metric_api_address: String,
 
@@ -1467,6 +1538,7 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub http_handler_tls_server_key: String,
 
+    /// Client certificates are not supported by the http handler, it refuses to start if set.
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub http_handler_tls_server_root_ca_cert: String,
 
@@ -1512,6 +1584,7 @@ pub struct QueryConfig {
     )]
     pub table_engine_memory_enabled: bool,
 
+    /// On shutdown, the time to wait for the running queries to finish before aborting them.
     #[clap(long, value_name = "VALUE", default_value = "5000")]
     pub shutdown_wait_timeout_ms: u64,
 
@@ -1539,7 +1612,7 @@ pub struct QueryConfig {
     pub default_compression: String,
 
     #[clap(skip)]
-    users: Vec<UserConfig>,
+    pub(crate) users: Vec<UserConfig>,
 
     #[clap(skip)]
     udfs: Vec<UDFConfig>,
@@ -1713,8 +1786,13 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: self.mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: self.mysql_tls_server_cert,
             mysql_tls_server_key: self.mysql_tls_server_key,
+            mysql_tls_server_root_ca_cert: self.mysql_tls_server_root_ca_cert,
+            mysql_handler_allowed_ip_list: parse_allowed_ip_list(
+                &self.mysql_handler_allowed_ip_list,
+            )?,
             max_active_sessions: self.max_active_sessions,
             max_running_queries: self.max_running_queries,
+            max_running_queries_per_user: self.max_running_queries_per_user,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
@@ -1722,11 +1800,19 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             http_handler_host: self.http_handler_host,
             http_handler_port: self.http_handler_port,
             http_handler_result_timeout_secs: self.http_handler_result_timeout_secs,
+            http_handler_rate_limit_per_sec: self.http_handler_rate_limit_per_sec,
+            http_handler_allowed_ip_list: parse_allowed_ip_list(
+                &self.http_handler_allowed_ip_list,
+            )?,
             flight_api_address: self.flight_api_address,
             discovery_address: self.discovery_address,
             flight_sql_handler_host: self.flight_sql_handler_host,
             flight_sql_handler_port: self.flight_sql_handler_port,
             admin_api_address: self.admin_api_address,
+            admin_api_enable_debug: self.admin_api_enable_debug,
+            admin_api_auth_token: self.admin_api_auth_token,
+            admin_api_read_only_token: self.admin_api_read_only_token,
+            admin_api_rate_limit_per_sec: self.admin_api_rate_limit_per_sec,
             metric_api_address: self.metric_api_address,
             http_handler_tls_server_cert: self.http_handler_tls_server_cert,
             http_handler_tls_server_key: self.http_handler_tls_server_key,
@@ -1785,6 +1871,18 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
     }
 }
 
+/// Parse the client ips or CIDR ranges of an allowed ip list once at config load.
+fn parse_allowed_ip_list(allowed_ip_list: &[String]) -> Result<Vec<IpCidr>> {
+    allowed_ip_list
+        .iter()
+        .map(|allowed_ip| {
+            allowed_ip.parse().map_err(|e| {
+                ErrorCode::InvalidConfig(format!("invalid allowed ip `{}`: {}", allowed_ip, e))
+            })
+        })
+        .collect()
+}
+
 #[allow(deprecated)]
 impl From<InnerQueryConfig> for QueryConfig {
     fn from(inner: InnerQueryConfig) -> Self {
@@ -1798,8 +1896,15 @@ impl From<InnerQueryConfig> for QueryConfig {
                 .mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: inner.mysql_tls_server_cert,
             mysql_tls_server_key: inner.mysql_tls_server_key,
+            mysql_tls_server_root_ca_cert: inner.mysql_tls_server_root_ca_cert,
+            mysql_handler_allowed_ip_list: inner
+                .mysql_handler_allowed_ip_list
+                .iter()
+                .map(|cidr| cidr.to_string())
+                .collect(),
             max_active_sessions: inner.max_active_sessions,
             max_running_queries: inner.max_running_queries,
+            max_running_queries_per_user: inner.max_running_queries_per_user,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
 
@@ -1812,11 +1917,21 @@ impl From<InnerQueryConfig> for QueryConfig {
             http_handler_host: inner.http_handler_host,
             http_handler_port: inner.http_handler_port,
             http_handler_result_timeout_secs: inner.http_handler_result_timeout_secs,
+            http_handler_rate_limit_per_sec: inner.http_handler_rate_limit_per_sec,
+            http_handler_allowed_ip_list: inner
+                .http_handler_allowed_ip_list
+                .iter()
+                .map(|cidr| cidr.to_string())
+                .collect(),
             flight_api_address: inner.flight_api_address,
             flight_sql_handler_host: inner.flight_sql_handler_host,
             flight_sql_handler_port: inner.flight_sql_handler_port,
             discovery_address: inner.discovery_address,
             admin_api_address: inner.admin_api_address,
+            admin_api_enable_debug: inner.admin_api_enable_debug,
+            admin_api_auth_token: inner.admin_api_auth_token,
+            admin_api_read_only_token: inner.admin_api_read_only_token,
+            admin_api_rate_limit_per_sec: inner.admin_api_rate_limit_per_sec,
             metric_api_address: inner.metric_api_address,
             http_handler_tls_server_cert: inner.http_handler_tls_server_cert,
             http_handler_tls_server_key: inner.http_handler_tls_server_key,
@@ -1884,7 +1999,7 @@ impl From<InnerQueryConfig> for QueryConfig {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct LogConfig {
     /// Log level <DEBUG|INFO|ERROR>
     #[clap(long = "log-level", value_name = "VALUE", default_value = "INFO")]
@@ -1932,6 +2047,9 @@ pub struct LogConfig {
     #[clap(flatten)]
     pub structlog: StructLogConfig,
 
+    #[clap(flatten)]
+    pub audit: AuditLogConfig,
+
     #[clap(flatten)]
     pub tracing: TracingConfig,
 }
@@ -2015,6 +2133,22 @@ impl TryInto<InnerLogConfig> for LogConfig {
             }
         }
 
+        let mut audit: InnerAuditLogConfig = self.audit.try_into()?;
+        if audit.on && audit.dir.is_empty() {
+            if file.dir.is_empty() {
+                return Err(ErrorCode::InvalidConfig(
+                    "`dir` or `file.dir` must be set when `audit.on` is true".to_string(),
+                ));
+            } else {
+                audit.dir = format!("{}/audit", &file.dir);
+            }
+        }
+        if audit.on && audit.key.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "`key` must be set when `audit.on` is true".to_string(),
+            ));
+        }
+
         let tracing: InnerTracingConfig = self.tracing.try_into()?;
 
         Ok(InnerLogConfig {
@@ -2024,6 +2158,7 @@ impl TryInto<InnerLogConfig> for LogConfig {
             query,
             profile,
             structlog,
+            audit,
             tracing,
         })
     }
@@ -2040,6 +2175,7 @@ impl From<InnerLogConfig> for LogConfig {
             query: inner.query.into(),
             profile: inner.profile.into(),
             structlog: inner.structlog.into(),
+            audit: inner.audit.into(),
             tracing: inner.tracing.into(),
 
             // Deprecated fields
@@ -2052,7 +2188,7 @@ impl From<InnerLogConfig> for LogConfig {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct FileLogConfig {
     #[clap(
         long = "log-file-on", value_name = "VALUE", default_value = "true", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true"
@@ -2131,7 +2267,7 @@ impl From<InnerFileLogConfig> for FileLogConfig {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct StderrLogConfig {
     #[clap(
         long = "log-stderr-on", value_name = "VALUE", default_value = "false", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true"
@@ -2330,7 +2466,7 @@ impl From<InnerProfileLogConfig> for ProfileLogConfig {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct StructLogConfig {
     #[clap(
         long = "log-structlog-on", value_name = "VALUE", default_value = "false", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true"
@@ -2370,6 +2506,54 @@ impl From<InnerStructLogConfig> for StructLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuditLogConfig {
+    #[clap(
+        long = "log-audit-on", value_name = "VALUE", default_value = "false", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true"
+    )]
+    #[serde(rename = "on")]
+    pub log_audit_on: bool,
+
+    /// Audit Log file dir
+    #[clap(long = "log-audit-dir", value_name = "VALUE", default_value = "")]
+    #[serde(rename = "dir")]
+    pub log_audit_dir: String,
+
+    /// Key of the HMAC-SHA256 checksums chaining the audit events, required if the audit log is on
+    #[clap(long = "log-audit-key", value_name = "VALUE", default_value = "")]
+    #[serde(rename = "key")]
+    pub log_audit_key: String,
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        InnerAuditLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerAuditLogConfig> for AuditLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerAuditLogConfig> {
+        Ok(InnerAuditLogConfig {
+            on: self.log_audit_on,
+            dir: self.log_audit_dir,
+            key: self.log_audit_key,
+        })
+    }
+}
+
+impl From<InnerAuditLogConfig> for AuditLogConfig {
+    fn from(inner: InnerAuditLogConfig) -> Self {
+        Self {
+            log_audit_on: inner.on,
+            log_audit_dir: inner.dir,
+            log_audit_key: inner.key,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct TracingConfig {
@@ -2964,6 +3148,7 @@ mod cache_config_converters {
             Self {
                 subcommand: inner.subcommand,
                 cmd: None,
+                local: false,
                 config_file: inner.config_file,
                 query: inner.query.into(),
                 log: inner.log.into(),
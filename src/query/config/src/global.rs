@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_base::base::GlobalInstance;
 use databend_common_exception::Result;
+use parking_lot::RwLock;
 
 use crate::InnerConfig;
 
@@ -23,15 +24,23 @@ pub struct GlobalConfig;
 
 impl GlobalConfig {
     pub fn init(config: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(Arc::new(config.clone()));
+        GlobalInstance::set(Arc::new(RwLock::new(Arc::new(config.clone()))));
         Ok(())
     }
 
     pub fn instance() -> Arc<InnerConfig> {
-        GlobalInstance::get()
+        GlobalInstance::get::<Arc<RwLock<Arc<InnerConfig>>>>()
+            .read()
+            .clone()
     }
 
     pub fn try_get_instance() -> Option<Arc<InnerConfig>> {
-        GlobalInstance::try_get()
+        GlobalInstance::try_get::<Arc<RwLock<Arc<InnerConfig>>>>().map(|v| v.read().clone())
+    }
+
+    /// Replace the running config after its reloadable fields are applied,
+    /// see [`InnerConfig::check_reloadable`].
+    pub fn reload(config: InnerConfig) {
+        *GlobalInstance::get::<Arc<RwLock<Arc<InnerConfig>>>>().write() = Arc::new(config);
     }
 }
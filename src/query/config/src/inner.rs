@@ -21,6 +21,7 @@ use std::fmt::Formatter;
 use std::str::FromStr;
 use std::time::Duration;
 
+use cidr::IpCidr;
 use databend_common_base::base::mask_string;
 use databend_common_base::base::GlobalUniqName;
 use databend_common_base::base::OrderedFloat;
@@ -87,8 +88,8 @@ impl InnerConfig {
         // Handle auto detect for storage params.
         cfg.storage.params = cfg.storage.params.auto_detect().await?;
 
-        // Only check meta config when cmd is empty.
-        if cfg.subcommand.is_none() {
+        // Only check meta config when cmd is empty or `show-config`.
+        if matches!(cfg.subcommand, None | Some(Commands::ShowConfig)) {
             cfg.meta.check_valid()?;
         }
         Ok(cfg)
@@ -102,6 +103,46 @@ impl InnerConfig {
         Ok(cfg)
     }
 
+    /// Check the new loaded config against the running one, only the reloadable fields may differ:
+    ///
+    /// - log.file.level, log.stderr.level
+    /// - query.max_active_sessions, query.max_running_queries_per_user
+    pub fn check_reloadable(&self, new: &InnerConfig) -> Result<()> {
+        let mut expected = new.clone();
+        expected.log.file.level = self.log.file.level.clone();
+        expected.log.stderr.level = self.log.stderr.level.clone();
+        expected.query.max_active_sessions = self.query.max_active_sessions;
+        expected.query.max_running_queries_per_user = self.query.max_running_queries_per_user;
+
+        // Generated for each process, see `InnerConfig::load`.
+        expected.query.node_id = self.query.node_id.clone();
+        expected.query.node_secret = self.query.node_secret.clone();
+
+        let sections = [
+            ("query", expected.query != self.query),
+            ("log", expected.log != self.log),
+            ("meta", expected.meta != self.meta),
+            ("storage", expected.storage != self.storage),
+            ("catalogs", expected.catalogs != self.catalogs),
+            ("cache", expected.cache != self.cache),
+            ("spill", expected.spill != self.spill),
+            ("background", expected.background != self.background),
+        ];
+        let changed = sections
+            .iter()
+            .filter(|(_, changed)| *changed)
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+
+        if !changed.is_empty() {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "Cannot reload the changes in [{}] without restart",
+                changed.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
     pub fn tls_query_cli_enabled(&self) -> bool {
         !self.query.rpc_tls_query_server_root_ca_cert.is_empty()
             && !self.query.rpc_tls_query_service_domain_name.is_empty()
@@ -170,8 +211,11 @@ pub struct QueryConfig {
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
     pub mysql_tls_server_cert: String,
     pub mysql_tls_server_key: String,
+    pub mysql_tls_server_root_ca_cert: String,
+    pub mysql_handler_allowed_ip_list: Vec<IpCidr>,
     pub max_active_sessions: u64,
     pub max_running_queries: u64,
+    pub max_running_queries_per_user: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
     pub clickhouse_http_handler_host: String,
@@ -179,11 +223,17 @@ pub struct QueryConfig {
     pub http_handler_host: String,
     pub http_handler_port: u16,
     pub http_handler_result_timeout_secs: u64,
+    pub http_handler_rate_limit_per_sec: u64,
+    pub http_handler_allowed_ip_list: Vec<IpCidr>,
     pub flight_api_address: String,
     pub discovery_address: String,
     pub flight_sql_handler_host: String,
     pub flight_sql_handler_port: u16,
     pub admin_api_address: String,
+    pub admin_api_enable_debug: bool,
+    pub admin_api_auth_token: String,
+    pub admin_api_read_only_token: String,
+    pub admin_api_rate_limit_per_sec: u64,
     pub metric_api_address: String,
     pub http_handler_tls_server_cert: String,
     pub http_handler_tls_server_key: String,
@@ -262,8 +312,11 @@ impl Default for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: 120,
             mysql_tls_server_cert: "".to_string(),
             mysql_tls_server_key: "".to_string(),
+            mysql_tls_server_root_ca_cert: "".to_string(),
+            mysql_handler_allowed_ip_list: Vec::new(),
             max_active_sessions: 256,
             max_running_queries: 8,
+            max_running_queries_per_user: 0,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
@@ -271,11 +324,17 @@ impl Default for QueryConfig {
             http_handler_host: "127.0.0.1".to_string(),
             http_handler_port: 8000,
             http_handler_result_timeout_secs: 60,
+            http_handler_rate_limit_per_sec: 0,
+            http_handler_allowed_ip_list: Vec::new(),
             flight_api_address: "127.0.0.1:9090".to_string(),
             flight_sql_handler_host: "127.0.0.1".to_string(),
             flight_sql_handler_port: 8900,
             discovery_address: "".to_string(),
             admin_api_address: "127.0.0.1:8080".to_string(),
+            admin_api_enable_debug: true,
+            admin_api_auth_token: "".to_string(),
+            admin_api_read_only_token: "".to_string(),
+            admin_api_rate_limit_per_sec: 0,
             metric_api_address: "127.0.0.1:7070".to_string(),
             api_tls_server_cert: "".to_string(),
             api_tls_server_key: "".to_string(),
@@ -343,6 +402,8 @@ impl QueryConfig {
             .clone()
             .map(|s| mask_string(&s, 3));
         sanitized.openai_api_key = mask_string(&self.openai_api_key, 3);
+        sanitized.admin_api_auth_token = mask_string(&self.admin_api_auth_token, 3);
+        sanitized.admin_api_read_only_token = mask_string(&self.admin_api_read_only_token, 3);
         sanitized
     }
 }
@@ -52,6 +52,7 @@ pub use inner::InnerConfig;
 pub use inner::SpillConfig;
 pub use inner::ThriftProtocol;
 pub use version::DATABEND_COMMIT_VERSION;
+pub use version::QUERY_BUILD_TIMESTAMP;
 pub use version::QUERY_GIT_SEMVER;
 pub use version::QUERY_GIT_SHA;
 pub use version::QUERY_SEMVER;
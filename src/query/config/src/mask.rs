@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::builtin::UserAuthConfig;
+use crate::builtin::UserConfig;
 use crate::config::AzblobStorageConfig;
 use crate::config::CosStorageConfig;
 use crate::config::FsStorageConfig;
 use crate::config::GcsStorageConfig;
 use crate::config::HdfsConfig;
+use crate::config::LogConfig;
 use crate::config::MetaConfig;
 use crate::config::ObsStorageConfig;
 use crate::config::OssStorageConfig;
@@ -44,9 +47,10 @@ impl Config {
         Config {
             subcommand: self.subcommand,
             cmd: self.cmd,
+            local: self.local,
             config_file: self.config_file,
             query: self.query.mask_display(),
-            log: self.log,
+            log: self.log.mask_display(),
             meta: self.meta.mask_display(),
             storage: self.storage.mask_display(),
             catalog: self.catalog,
@@ -71,6 +75,39 @@ impl QueryConfig {
         // Mask OpenAI API key
         masked_config.openai_api_key = mask_sensitive_field(&self.openai_api_key);
 
+        // Mask admin api tokens
+        masked_config.admin_api_auth_token = mask_sensitive_field(&self.admin_api_auth_token);
+        masked_config.admin_api_read_only_token =
+            mask_sensitive_field(&self.admin_api_read_only_token);
+
+        // Mask builtin users auth string
+        masked_config.users = self
+            .users
+            .iter()
+            .map(|user| UserConfig {
+                name: user.name.clone(),
+                auth: UserAuthConfig {
+                    auth_type: user.auth.auth_type.clone(),
+                    auth_string: user
+                        .auth
+                        .auth_string
+                        .as_ref()
+                        .map(|auth_string| mask_sensitive_field(auth_string)),
+                },
+            })
+            .collect();
+
+        masked_config
+    }
+}
+
+impl LogConfig {
+    fn mask_display(&self) -> Self {
+        let mut masked_config = self.clone();
+
+        // Mask the key of the audit log checksums
+        masked_config.audit.log_audit_key = mask_sensitive_field(&self.audit.log_audit_key);
+
         masked_config
     }
 }
@@ -248,6 +285,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_query_config_mask_display() {
+        let config = QueryConfig {
+            openai_api_key: "openai_api_key".to_string(),
+            users: vec![UserConfig {
+                name: "root".to_string(),
+                auth: UserAuthConfig {
+                    auth_type: "double_sha1_password".to_string(),
+                    auth_string: Some("auth_string".to_string()),
+                },
+            }],
+            ..QueryConfig::default()
+        };
+
+        let masked_config = config.mask_display();
+        assert_eq!(masked_config.openai_api_key, "***********key");
+        assert_eq!(masked_config.users[0].name, "root");
+        assert_eq!(
+            masked_config.users[0].auth.auth_string.as_deref(),
+            Some("********ing")
+        );
+    }
+
     #[test]
     fn test_meta_config_mask_display() {
         let config = MetaConfig {
@@ -32,3 +32,22 @@ fn test_config_default() {
         "default setting is different from default config, please check again"
     )
 }
+
+#[test]
+fn test_config_check_reloadable() {
+    let running = InnerConfig::default();
+
+    let mut new = running.clone();
+    new.log.file.level = "DEBUG".to_string();
+    new.query.max_active_sessions = 1024;
+    new.query.node_id = "another_node".to_string();
+    assert!(running.check_reloadable(&new).is_ok());
+
+    new.query.http_handler_port = 8001;
+    new.meta.endpoints = vec!["127.0.0.1:9191".to_string()];
+    let err = running.check_reloadable(&new).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Cannot reload the changes in [query, meta] without restart"
+    );
+}
@@ -95,11 +95,21 @@ pub enum FunctionEval {
     },
 }
 
+/// The behavior of integer arithmetic when the result overflows its type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NumericOverflowMode {
+    #[default]
+    Wrap,
+    Saturate,
+    Error,
+}
+
 #[derive(Clone)]
 pub struct FunctionContext {
     pub tz: TzLUT,
     pub now: DateTime<Utc>,
     pub rounding_mode: bool,
+    pub numeric_overflow_mode: NumericOverflowMode,
     pub disable_variant_check: bool,
 
     pub openai_api_chat_base_url: String,
@@ -122,6 +132,7 @@ impl Default for FunctionContext {
             tz: Default::default(),
             now: Default::default(),
             rounding_mode: false,
+            numeric_overflow_mode: NumericOverflowMode::Wrap,
             disable_variant_check: false,
             openai_api_chat_base_url: "".to_string(),
             openai_api_embedding_base_url: "".to_string(),
@@ -21,6 +21,7 @@ use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_settings::Settings;
 
+use crate::output_format::ArrowOutputFormat;
 use crate::output_format::CSVOutputFormat;
 use crate::output_format::CSVWithNamesAndTypesOutputFormat;
 use crate::output_format::CSVWithNamesOutputFormat;
@@ -115,6 +116,11 @@ impl FileFormatOptionsExt {
         schema: TableSchemaRef,
         settings: &Settings,
     ) -> Result<Box<dyn OutputFormat>> {
+        // ArrowStream is only an output format, it can't be used as the file format of stages.
+        if typ.typ == StageFileFormatType::ArrowStream {
+            let options = FileFormatOptionsExt::create_from_clickhouse_format(typ, settings)?;
+            return Ok(Box::new(ArrowOutputFormat::try_create(schema, &options)?));
+        }
         let params = FileFormatParams::default_by_type(typ.typ.clone())?;
         let mut options = FileFormatOptionsExt::create_from_clickhouse_format(typ, settings)?;
         options.get_output_format(schema, params)
@@ -206,6 +212,7 @@ impl FileFormatTypeExt for StageFileFormatType {
             StageFileFormatType::Parquet => "application/octet-stream",
             StageFileFormatType::NdJson => "application/x-ndjson; charset=UTF-8",
             StageFileFormatType::Json => "application/json; charset=UTF-8",
+            StageFileFormatType::ArrowStream => "application/vnd.apache.arrow.stream",
             _ => "text/plain; charset=UTF-8",
         }
         .to_string()
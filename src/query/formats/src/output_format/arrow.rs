@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_ipc::writer::StreamWriter;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+/// Arrow IPC streaming format: the schema message, one record batch message
+/// per block, and the end-of-stream marker.
+pub struct ArrowOutputFormat {
+    schema: TableSchemaRef,
+    writer: StreamWriter<Vec<u8>>,
+}
+
+impl ArrowOutputFormat {
+    pub fn try_create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Result<Self> {
+        let writer = StreamWriter::try_new(Vec::new(), &schema.as_ref().into())?;
+        Ok(Self { schema, writer })
+    }
+
+    fn take_buffer(&mut self) -> Vec<u8> {
+        std::mem::take(self.writer.get_mut())
+    }
+}
+
+impl OutputFormat for ArrowOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        let batch = block.clone().to_record_batch(&self.schema)?;
+        self.writer.write(&batch)?;
+        Ok(self.take_buffer())
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        self.writer.finish()?;
+        Ok(self.take_buffer())
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use arrow_ipc::reader::StreamReader;
+use databend_common_exception::Result;
+
+use crate::get_output_format_clickhouse;
+use crate::output_format_utils::get_simple_block;
+
+#[test]
+fn test_arrow_output_format() -> Result<()> {
+    for is_nullable in [false, true] {
+        let (schema, block) = get_simple_block(is_nullable);
+        let mut formatter = get_output_format_clickhouse("ArrowStream", schema.clone())?;
+
+        let mut buffer = formatter.serialize_prefix()?;
+        buffer.extend(formatter.serialize_block(&block)?);
+        buffer.extend(formatter.serialize_block(&block)?);
+        buffer.extend(formatter.finalize()?);
+
+        let reader = StreamReader::try_new(Cursor::new(buffer), None)?;
+        let names = reader
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["c1", "c2", "c3", "c4", "c5"]);
+
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0], block.clone().to_record_batch(&schema)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_arrow_file_format_is_not_stream() {
+    // `Arrow` is the IPC file format in ClickHouse, only `ArrowStream` is supported.
+    let (schema, _) = get_simple_block(false);
+    assert!(get_output_format_clickhouse("Arrow", schema).is_err());
+}
@@ -26,6 +26,7 @@ use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::AggregateFunctionRef;
 use databend_common_expression::Column;
 use databend_common_expression::ColumnBuilder;
+use databend_common_expression::NumericOverflowMode;
 use databend_common_expression::Scalar;
 use databend_common_expression::StateAddr;
 use num_traits::AsPrimitive;
@@ -35,6 +36,7 @@ use super::FunctionData;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
 use crate::aggregates::aggregate_unary::UnaryState;
 use crate::aggregates::AggregateUnaryFunction;
+use crate::scalars::OverflowArithmetic;
 
 pub trait SumState: BorshSerialize + BorshDeserialize + Send + Sync + Default + 'static {
     fn merge(&mut self, other: &Self) -> Result<()>;
@@ -154,6 +156,79 @@ where
     }
 }
 
+/// The sum of numbers that saturates or fails on overflow instead of wrapping.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct CheckedNumberSumState<N, const SATURATE: bool>
+where N: ValueType
+{
+    pub value: N::Scalar,
+}
+
+impl<N, const SATURATE: bool> Default for CheckedNumberSumState<N, SATURATE>
+where
+    N: ValueType,
+    N::Scalar: Number + BorshSerialize + BorshDeserialize,
+{
+    fn default() -> Self {
+        Self {
+            value: N::Scalar::default(),
+        }
+    }
+}
+
+impl<N, const SATURATE: bool> CheckedNumberSumState<N, SATURATE>
+where
+    N: ValueType,
+    N::Scalar: OverflowArithmetic,
+{
+    fn add_value(&mut self, other: N::Scalar) -> Result<()> {
+        let mode = if SATURATE {
+            NumericOverflowMode::Saturate
+        } else {
+            NumericOverflowMode::Error
+        };
+        match self.value.add_with_mode(other, mode) {
+            Some(value) => {
+                self.value = value;
+                Ok(())
+            }
+            None => Err(ErrorCode::Overflow(format!(
+                "Number overflow: sum of {:?} and {:?}",
+                self.value, other
+            ))),
+        }
+    }
+}
+
+impl<T, N, const SATURATE: bool> UnaryState<T, N> for CheckedNumberSumState<N, SATURATE>
+where
+    T: ValueType + Sync + Send,
+    N: ValueType,
+    N::Scalar: OverflowArithmetic + BorshSerialize + BorshDeserialize,
+    for<'a> T::ScalarRef<'a>: Number + AsPrimitive<N::Scalar>,
+{
+    fn add(
+        &mut self,
+        other: T::ScalarRef<'_>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.add_value(other.as_())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.add_value(rhs.value)
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut N::ColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        N::push_item(builder, N::to_scalar_ref(&self.value));
+        Ok(())
+    }
+}
+
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct DecimalSumState<const OVERFLOW: bool, T>
 where
@@ -224,15 +299,41 @@ pub fn try_create_aggregate_sum_function(
         data_type = Int8Type::data_type();
     }
 
+    let overflow_mode = get_overflow_mode(&params, display_name)?;
+
     with_number_mapped_type!(|NUM| match &data_type {
         DataType::Number(NumberDataType::NUM) => {
             type TSum = <NUM as ResultTypeOfUnary>::Sum;
             let return_type = NumberType::<TSum>::data_type();
-            AggregateUnaryFunction::<
-                NumberSumState<NumberType<TSum>>,
-                NumberType<NUM>,
-                NumberType<TSum>,
-            >::try_create_unary(display_name, return_type, params, arguments[0].clone())
+            match overflow_mode {
+                NumericOverflowMode::Wrap => {
+                    AggregateUnaryFunction::<
+                        NumberSumState<NumberType<TSum>>,
+                        NumberType<NUM>,
+                        NumberType<TSum>,
+                    >::try_create_unary(
+                        display_name, return_type, params, arguments[0].clone()
+                    )
+                }
+                NumericOverflowMode::Saturate => {
+                    AggregateUnaryFunction::<
+                        CheckedNumberSumState<NumberType<TSum>, true>,
+                        NumberType<NUM>,
+                        NumberType<TSum>,
+                    >::try_create_unary(
+                        display_name, return_type, params, arguments[0].clone()
+                    )
+                }
+                NumericOverflowMode::Error => {
+                    AggregateUnaryFunction::<
+                        CheckedNumberSumState<NumberType<TSum>, false>,
+                        NumberType<NUM>,
+                        NumberType<TSum>,
+                    >::try_create_unary(
+                        display_name, return_type, params, arguments[0].clone()
+                    )
+                }
+            }
         }
         DataType::Decimal(DecimalDataType::Decimal128(s)) => {
             let p = MAX_DECIMAL128_PRECISION;
@@ -298,6 +399,27 @@ pub fn try_create_aggregate_sum_function(
     })
 }
 
+/// The overflow mode of the integer sum is passed in params by the binder,
+/// from the setting `numeric_overflow_mode`.
+fn get_overflow_mode(params: &[Scalar], display_name: &str) -> Result<NumericOverflowMode> {
+    match params {
+        [] => Ok(NumericOverflowMode::Wrap),
+        [Scalar::String(mode)] => match mode.as_str() {
+            "wrap" => Ok(NumericOverflowMode::Wrap),
+            "saturate" => Ok(NumericOverflowMode::Saturate),
+            "error" => Ok(NumericOverflowMode::Error),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "The overflow mode of aggregate function {} must be one of wrap, saturate or error, but got {}",
+                display_name, mode
+            ))),
+        },
+        _ => Err(ErrorCode::BadArguments(format!(
+            "The parameter of aggregate function {} must be an overflow mode",
+            display_name
+        ))),
+    }
+}
+
 pub fn aggregate_sum_function_desc() -> AggregateFunctionDescription {
     let features = super::aggregate_function_factory::AggregateFunctionFeatures {
         is_decomposable: true,
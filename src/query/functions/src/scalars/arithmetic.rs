@@ -61,15 +61,19 @@ use databend_common_expression::with_unsigned_integer_mapped_type;
 use databend_common_expression::Domain;
 use databend_common_expression::EvalContext;
 use databend_common_expression::Function;
+use databend_common_expression::FunctionContext;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionEval;
 use databend_common_expression::FunctionRegistry;
 use databend_common_expression::FunctionSignature;
+use databend_common_expression::NumericOverflowMode;
 use ethnum::i256;
 use lexical_core::FormattedSize;
 use num_traits::AsPrimitive;
 
 use super::arithmetic_modulo::vectorize_modulo;
+use super::arithmetic_overflow::vectorize_overflow_arithmetic;
+use super::arithmetic_overflow::OverflowArithmetic;
 use super::decimal::register_decimal_to_int;
 use crate::scalars::decimal::register_decimal_arithmetic;
 use crate::scalars::decimal::register_decimal_to_float;
@@ -90,14 +94,23 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_unary_arithmetic(registry);
 }
 
+/// The domain of an arithmetic result that may overflow.
+fn overflow_domain<T>(ctx: &FunctionContext) -> FunctionDomain<NumberType<T>>
+where T: Number {
+    match ctx.numeric_overflow_mode {
+        NumericOverflowMode::Error => FunctionDomain::MayThrow,
+        _ => FunctionDomain::Full,
+    }
+}
+
 macro_rules! register_plus {
     ( $lt:ty, $rt:ty, $registry:expr) => {
         type L = $lt;
         type R = $rt;
         type T = <(L, R) as ResultTypeOfBinary>::AddMul;
-        $registry.register_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
+        $registry.register_passthrough_nullable_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
             "plus",
-            |_, lhs, rhs| {
+            |ctx, lhs, rhs| {
                 (|| {
                     let lm: T = num_traits::cast::cast(lhs.max)?;
                     let ln: T = num_traits::cast::cast(lhs.min)?;
@@ -109,9 +122,9 @@ macro_rules! register_plus {
                         max: lm.checked_add(rm)?,
                     }))
                 })()
-                .unwrap_or(FunctionDomain::Full)
+                .unwrap_or_else(|| overflow_domain(ctx))
             },
-            |a, b, _| (AsPrimitive::<T>::as_(a)) + (AsPrimitive::<T>::as_(b)),
+            vectorize_overflow_arithmetic::<L, R, T>(T::add_with_mode),
         );
     };
 }
@@ -121,9 +134,9 @@ macro_rules! register_minus {
         type L = $lt;
         type R = $rt;
         type T = <(L, R) as ResultTypeOfBinary>::Minus;
-        $registry.register_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
+        $registry.register_passthrough_nullable_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
             "minus",
-            |_, lhs, rhs| {
+            |ctx, lhs, rhs| {
                 (|| {
                     let lm: T = num_traits::cast::cast(lhs.max)?;
                     let ln: T = num_traits::cast::cast(lhs.min)?;
@@ -135,9 +148,9 @@ macro_rules! register_minus {
                         max: lm.checked_sub(rn)?,
                     }))
                 })()
-                .unwrap_or(FunctionDomain::Full)
+                .unwrap_or_else(|| overflow_domain(ctx))
             },
-            |a, b, _| (AsPrimitive::<T>::as_(a)) - (AsPrimitive::<T>::as_(b)),
+            vectorize_overflow_arithmetic::<L, R, T>(T::sub_with_mode),
         );
     };
 }
@@ -147,9 +160,9 @@ macro_rules! register_multiply {
         type L = $lt;
         type R = $rt;
         type T = <(L, R) as ResultTypeOfBinary>::AddMul;
-        $registry.register_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
+        $registry.register_passthrough_nullable_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
             "multiply",
-            |_, lhs, rhs| {
+            |ctx, lhs, rhs| {
                 (|| {
                     let lm: T = num_traits::cast::cast(lhs.max)?;
                     let ln: T = num_traits::cast::cast(lhs.min)?;
@@ -166,9 +179,9 @@ macro_rules! register_multiply {
                         max: x.max(y).max(m).max(n),
                     }))
                 })()
-                .unwrap_or(FunctionDomain::Full)
+                .unwrap_or_else(|| overflow_domain(ctx))
             },
-            |a, b, _| (AsPrimitive::<T>::as_(a)) * (AsPrimitive::<T>::as_(b)),
+            vectorize_overflow_arithmetic::<L, R, T>(T::mul_with_mode),
         );
     };
 }
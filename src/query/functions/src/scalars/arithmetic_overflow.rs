@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::number::*;
+use databend_common_expression::vectorize_2_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::NumericOverflowMode;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use num_traits::AsPrimitive;
+
+/// Arithmetic honoring `FunctionContext::numeric_overflow_mode`, returns `None` if
+/// the result overflows in `NumericOverflowMode::Error`. Floats never overflow.
+pub(crate) trait OverflowArithmetic: Number {
+    fn add_with_mode(self, rhs: Self, mode: NumericOverflowMode) -> Option<Self>;
+    fn sub_with_mode(self, rhs: Self, mode: NumericOverflowMode) -> Option<Self>;
+    fn mul_with_mode(self, rhs: Self, mode: NumericOverflowMode) -> Option<Self>;
+}
+
+macro_rules! impl_integer_overflow_arithmetic {
+    ($($t:ty),*) => {$(
+        impl OverflowArithmetic for $t {
+            #[inline]
+            fn add_with_mode(self, rhs: Self, mode: NumericOverflowMode) -> Option<Self> {
+                match mode {
+                    NumericOverflowMode::Wrap => Some(self.wrapping_add(rhs)),
+                    NumericOverflowMode::Saturate => Some(self.saturating_add(rhs)),
+                    NumericOverflowMode::Error => self.checked_add(rhs),
+                }
+            }
+
+            #[inline]
+            fn sub_with_mode(self, rhs: Self, mode: NumericOverflowMode) -> Option<Self> {
+                match mode {
+                    NumericOverflowMode::Wrap => Some(self.wrapping_sub(rhs)),
+                    NumericOverflowMode::Saturate => Some(self.saturating_sub(rhs)),
+                    NumericOverflowMode::Error => self.checked_sub(rhs),
+                }
+            }
+
+            #[inline]
+            fn mul_with_mode(self, rhs: Self, mode: NumericOverflowMode) -> Option<Self> {
+                match mode {
+                    NumericOverflowMode::Wrap => Some(self.wrapping_mul(rhs)),
+                    NumericOverflowMode::Saturate => Some(self.saturating_mul(rhs)),
+                    NumericOverflowMode::Error => self.checked_mul(rhs),
+                }
+            }
+        }
+    )*};
+}
+
+impl_integer_overflow_arithmetic!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+macro_rules! impl_float_overflow_arithmetic {
+    ($($t:ty),*) => {$(
+        impl OverflowArithmetic for $t {
+            #[inline]
+            fn add_with_mode(self, rhs: Self, _mode: NumericOverflowMode) -> Option<Self> {
+                Some(self + rhs)
+            }
+
+            #[inline]
+            fn sub_with_mode(self, rhs: Self, _mode: NumericOverflowMode) -> Option<Self> {
+                Some(self - rhs)
+            }
+
+            #[inline]
+            fn mul_with_mode(self, rhs: Self, _mode: NumericOverflowMode) -> Option<Self> {
+                Some(self * rhs)
+            }
+        }
+    )*};
+}
+
+impl_float_overflow_arithmetic!(F32, F64);
+
+/// Casts an operand to the result type. Out-of-range operands, like `UInt64::MAX` for an
+/// `Int64` result, are wrapped, saturated or rejected according to `mode`.
+#[inline]
+fn cast_with_mode<S, T>(v: S, mode: NumericOverflowMode) -> Option<T>
+where
+    S: Number + AsPrimitive<T>,
+    T: Number,
+{
+    match mode {
+        NumericOverflowMode::Wrap => Some(v.as_()),
+        NumericOverflowMode::Saturate => Some(num_traits::cast::<_, T>(v).unwrap_or({
+            if v > S::default() {
+                T::MAX
+            } else {
+                T::MIN
+            }
+        })),
+        NumericOverflowMode::Error => num_traits::cast::<_, T>(v),
+    }
+}
+
+pub(crate) fn vectorize_overflow_arithmetic<L, R, T>(
+    op: fn(T, T, NumericOverflowMode) -> Option<T>,
+) -> impl Fn(ValueRef<NumberType<L>>, ValueRef<NumberType<R>>, &mut EvalContext) -> Value<NumberType<T>>
++ Copy
+where
+    L: Number + AsPrimitive<T>,
+    R: Number + AsPrimitive<T>,
+    T: Number,
+{
+    move |arg1, arg2, ctx| match ctx.func_ctx.numeric_overflow_mode {
+        // Wrapping never fails, keep the faster vectorization without a builder.
+        NumericOverflowMode::Wrap => {
+            vectorize_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>>(|a, b, _| {
+                op(a.as_(), b.as_(), NumericOverflowMode::Wrap).unwrap_or_default()
+            })(arg1, arg2, ctx)
+        }
+        mode => vectorize_with_builder_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>>(
+            move |a, b, output, ctx| match cast_with_mode(a, mode)
+                .zip(cast_with_mode(b, mode))
+                .and_then(|(a, b)| op(a, b, mode))
+            {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "number overflowed");
+                    output.push(T::default());
+                }
+            },
+        )(arg1, arg2, ctx),
+    }
+}
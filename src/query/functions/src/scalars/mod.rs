@@ -16,6 +16,7 @@ use databend_common_expression::FunctionRegistry;
 
 mod arithmetic;
 mod arithmetic_modulo;
+mod arithmetic_overflow;
 mod array;
 mod binary;
 mod bitmap;
@@ -39,6 +40,7 @@ mod tuple;
 mod variant;
 mod vector;
 
+pub(crate) use arithmetic_overflow::OverflowArithmetic;
 pub use comparison::ALL_COMP_FUNC_NAMES;
 pub use string::ALL_STRING_FUNC_NAMES;
 
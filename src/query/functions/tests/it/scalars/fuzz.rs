@@ -0,0 +1,170 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_expression::type_check;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RawExpr;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+const NUM_ROWS: usize = 16;
+const NUM_COLUMNS: usize = 4;
+const MAX_DEPTH: usize = 4;
+const ITERATIONS: u64 = 1000;
+
+/// Deterministic functions with their number of arguments. Non-deterministic
+/// functions like `rand()` can't be compared before and after constant folding.
+const FUNCTIONS: &[(&str, usize)] = &[
+    ("plus", 2),
+    ("minus", 2),
+    ("multiply", 2),
+    ("divide", 2),
+    ("modulo", 2),
+    ("minus", 1),
+    ("abs", 1),
+    ("eq", 2),
+    ("noteq", 2),
+    ("lt", 2),
+    ("lte", 2),
+    ("gt", 2),
+    ("gte", 2),
+    ("and", 2),
+    ("or", 2),
+    ("xor", 2),
+    ("not", 1),
+    ("is_true", 1),
+    ("is_not_null", 1),
+    ("assume_not_null", 1),
+    ("if", 3),
+    ("concat", 2),
+    ("length", 1),
+    ("upper", 1),
+    ("lower", 1),
+    ("to_string", 1),
+    ("to_int64", 1),
+];
+
+fn random_data_type(rng: &mut SmallRng) -> DataType {
+    let ty = match rng.gen_range(0..6) {
+        0 => DataType::Boolean,
+        1 => DataType::Number(NumberDataType::UInt8),
+        2 => DataType::Number(NumberDataType::Int32),
+        3 => DataType::Number(NumberDataType::Int64),
+        4 => DataType::Number(NumberDataType::Float64),
+        _ => DataType::String,
+    };
+    if rng.gen_bool(0.3) {
+        ty.wrap_nullable()
+    } else {
+        ty
+    }
+}
+
+fn random_expr(rng: &mut SmallRng, columns: &[Column], depth: usize) -> RawExpr {
+    if depth >= MAX_DEPTH || rng.gen_bool(0.3) {
+        let id = rng.gen_range(0..columns.len());
+        if rng.gen_bool(0.2) {
+            let seed = rng.gen();
+            let column = Column::random(&columns[id].data_type(), 1, Some(seed));
+            return RawExpr::Constant {
+                span: None,
+                scalar: column.index(0).unwrap().to_owned(),
+            };
+        }
+        return RawExpr::ColumnRef {
+            span: None,
+            id,
+            data_type: columns[id].data_type(),
+            display_name: format!("c{id}"),
+        };
+    }
+
+    let (name, num_args) = FUNCTIONS[rng.gen_range(0..FUNCTIONS.len())];
+    RawExpr::FunctionCall {
+        span: None,
+        name: name.to_string(),
+        params: vec![],
+        args: (0..num_args)
+            .map(|_| random_expr(rng, columns, depth + 1))
+            .collect(),
+    }
+}
+
+/// Type check random expression trees over random columns. The well-typed ones must evaluate
+/// without panicking, and give the same result before and after constant folding.
+#[test]
+fn test_fuzz_expression() {
+    for seed in 0..ITERATIONS {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let columns = (0..NUM_COLUMNS)
+            .map(|i| Column::random(&random_data_type(&mut rng), NUM_ROWS, Some(seed + i as u64)))
+            .collect::<Vec<_>>();
+        let raw_expr = random_expr(&mut rng, &columns, 0);
+
+        // Ill-typed expressions must be rejected by the type checker with an error.
+        let Ok(expr) = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS) else {
+            continue;
+        };
+
+        let input_domains = columns
+            .iter()
+            .map(|col| col.domain())
+            .enumerate()
+            .collect::<HashMap<_, _>>();
+        let func_ctx = FunctionContext::default();
+        let (optimized_expr, _) =
+            ConstantFolder::fold_with_domain(&expr, &input_domains, &func_ctx, &BUILTIN_FUNCTIONS);
+
+        let block = DataBlock::new(
+            columns
+                .iter()
+                .map(|col| BlockEntry::new(col.data_type(), Value::Column(col.clone())))
+                .collect(),
+            NUM_ROWS,
+        );
+        let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let result = evaluator.run(&expr);
+        let optimized_result = evaluator.run(&optimized_expr);
+        match (&result, &optimized_result) {
+            (Ok(result), Ok(optimized_result)) => assert!(
+                result
+                    .as_ref()
+                    .semantically_eq(&optimized_result.as_ref()),
+                "seed {seed}: {result} should eq {optimized_result}, expr: {}, optimized_expr: {}",
+                expr.sql_display(),
+                optimized_expr.sql_display()
+            ),
+            (Err(_), Err(_)) => {}
+            _ => panic!(
+                "seed {seed}: evaluation result {:?} differs from the constant folded one {:?}, expr: {}, optimized_expr: {}",
+                result.as_ref().err(),
+                optimized_result.as_ref().err(),
+                expr.sql_display(),
+                optimized_expr.sql_display()
+            ),
+        }
+    }
+}
@@ -271,6 +271,14 @@ impl ClusterDiscovery {
         }
     }
 
+    /// Check whether the local node is registered to the metastore.
+    /// Returns an error if the metastore is unreachable.
+    #[async_backtrace::framed]
+    pub async fn is_registered(&self) -> Result<bool> {
+        let nodes = self.api_provider.get_nodes().await?;
+        Ok(nodes.iter().any(|node| node.id == self.local_id))
+    }
+
     fn cached_cluster(self: &Arc<Self>) -> Option<Arc<Cluster>> {
         (*self.cached_cluster.read()).clone()
     }
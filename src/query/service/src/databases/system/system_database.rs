@@ -22,6 +22,7 @@ use databend_common_meta_app::schema::DatabaseInfo;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::seq_value::SeqV;
+use databend_common_storages_system::AuditLogTable;
 use databend_common_storages_system::BackgroundJobTable;
 use databend_common_storages_system::BackgroundTaskTable;
 use databend_common_storages_system::BacktraceTable;
@@ -53,6 +54,7 @@ use databend_common_storages_system::ProcessesTable;
 use databend_common_storages_system::QueriesProfilingTable;
 use databend_common_storages_system::QueryCacheTable;
 use databend_common_storages_system::QueryLogTable;
+use databend_common_storages_system::QuotasUsageTable;
 use databend_common_storages_system::RolesTable;
 use databend_common_storages_system::SettingsTable;
 use databend_common_storages_system::StagesTable;
@@ -146,6 +148,11 @@ impl SystemDatabase {
             TemporaryTablesTable::create(sys_db_meta.next_table_id()),
             ProceduresTable::create(sys_db_meta.next_table_id()),
             DictionariesTable::create(sys_db_meta.next_table_id()),
+            QuotasUsageTable::create(sys_db_meta.next_table_id()),
+            Arc::new(AuditLogTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
         ];
 
         let disable_tables = Self::disable_system_tables();
@@ -34,6 +34,7 @@ use databend_common_tracing::GlobalLogger;
 use databend_common_users::builtin::BuiltIn;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
+use databend_common_users::UserQuotaManager;
 use databend_storages_common_cache::CacheManager;
 use databend_storages_common_cache::TempDirManager;
 
@@ -42,6 +43,7 @@ use crate::builtin::BuiltinUDFs;
 use crate::builtin::BuiltinUsers;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::AuditLog;
 use crate::locks::LockManager;
 #[cfg(feature = "enable_queries_executor")]
 use crate::pipelines::executor::GlobalQueriesExecutor;
@@ -135,6 +137,7 @@ impl GlobalServices {
         }
 
         RoleCacheManager::init()?;
+        UserQuotaManager::init()?;
 
         DataOperator::init(&config.storage).await?;
         ShareTableConfig::init(
@@ -154,6 +157,7 @@ impl GlobalServices {
         }
 
         ProfilesLogQueue::init(config.query.max_cached_queries_profiles);
+        AuditLog::init(config)?;
 
         #[cfg(feature = "enable_queries_executor")]
         {
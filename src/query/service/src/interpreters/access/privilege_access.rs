@@ -958,6 +958,9 @@ impl AccessChecker for PrivilegeAccess {
                 let privileges = vec![UserPrivilegeType::Alter, UserPrivilegeType::Drop];
                 for privilege in privileges {
                     self.validate_table_access(&plan.catalog, &plan.database, &plan.table, privilege, plan.if_exists, false).await?;
+                    if plan.is_swap {
+                        self.validate_table_access(&plan.catalog, &plan.new_database, &plan.new_table, privilege, false, false).await?;
+                    }
                 }
                 self.validate_db_access(&plan.catalog, &plan.new_database, UserPrivilegeType::Create, false).await?;
             }
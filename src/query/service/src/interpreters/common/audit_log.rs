@@ -0,0 +1,307 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_config::GlobalConfig;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_sql::plans::Plan;
+use databend_common_storages_system::AuditLogElement;
+use databend_common_storages_system::AuditLogQueue;
+use hmac::Hmac;
+use hmac::Mac;
+use log::error;
+use log::info;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Sha256;
+
+use crate::sessions::convert_query_log_timestamp;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const AUDIT_CHAIN_FILE: &str = "audit_chain.json";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditEventType {
+    Login,
+    AccessDenied,
+    Ddl,
+    Account,
+    Grant,
+    Revoke,
+    Set,
+}
+
+impl Display for AuditEventType {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl AuditEventType {
+    /// The type of the event audited when the plan is executed, `None` if
+    /// the plan is not security-relevant.
+    pub fn from_plan(plan: &Plan) -> Option<AuditEventType> {
+        match plan {
+            Plan::CreateUser(_)
+            | Plan::AlterUser(_)
+            | Plan::DropUser(_)
+            | Plan::CreateRole(_)
+            | Plan::DropRole(_) => Some(AuditEventType::Account),
+            Plan::GrantRole(_) | Plan::GrantPriv(_) => Some(AuditEventType::Grant),
+            Plan::RevokeRole(_) | Plan::RevokePriv(_) => Some(AuditEventType::Revoke),
+            Plan::Set(_) | Plan::Unset(_) => Some(AuditEventType::Set),
+            Plan::CreateCatalog(_)
+            | Plan::DropCatalog(_)
+            | Plan::CreateDatabase(_)
+            | Plan::DropDatabase(_)
+            | Plan::UndropDatabase(_)
+            | Plan::RenameDatabase(_)
+            | Plan::CreateTable(_)
+            | Plan::DropTable(_)
+            | Plan::UndropTable(_)
+            | Plan::RenameTable(_)
+            | Plan::ModifyTableComment(_)
+            | Plan::RenameTableColumn(_)
+            | Plan::AddTableColumn(_)
+            | Plan::DropTableColumn(_)
+            | Plan::ModifyTableColumn(_)
+            | Plan::AlterTableClusterKey(_)
+            | Plan::DropTableClusterKey(_)
+            | Plan::RevertTable(_)
+            | Plan::TruncateTable(_)
+            | Plan::SetOptions(_)
+            | Plan::UnsetOptions(_)
+            | Plan::CreateView(_)
+            | Plan::AlterView(_)
+            | Plan::DropView(_)
+            | Plan::CreateStream(_)
+            | Plan::DropStream(_)
+            | Plan::CreateIndex(_)
+            | Plan::DropIndex(_)
+            | Plan::CreateTableIndex(_)
+            | Plan::DropTableIndex(_)
+            | Plan::CreateVirtualColumn(_)
+            | Plan::AlterVirtualColumn(_)
+            | Plan::DropVirtualColumn(_)
+            | Plan::CreateUDF(_)
+            | Plan::AlterUDF(_)
+            | Plan::DropUDF(_)
+            | Plan::CreateFileFormat(_)
+            | Plan::DropFileFormat(_)
+            | Plan::CreateStage(_)
+            | Plan::DropStage(_)
+            | Plan::CreateConnection(_)
+            | Plan::DropConnection(_)
+            | Plan::CreateDatamaskPolicy(_)
+            | Plan::DropDatamaskPolicy(_)
+            | Plan::CreateNetworkPolicy(_)
+            | Plan::AlterNetworkPolicy(_)
+            | Plan::DropNetworkPolicy(_)
+            | Plan::CreatePasswordPolicy(_)
+            | Plan::AlterPasswordPolicy(_)
+            | Plan::DropPasswordPolicy(_)
+            | Plan::CreateTask(_)
+            | Plan::AlterTask(_)
+            | Plan::DropTask(_)
+            | Plan::CreateDynamicTable(_)
+            | Plan::CreateNotification(_)
+            | Plan::AlterNotification(_)
+            | Plan::DropNotification(_)
+            | Plan::CreateProcedure(_)
+            | Plan::DropProcedure(_)
+            | Plan::CreateSequence(_)
+            | Plan::DropSequence(_)
+            | Plan::CreateDictionary(_)
+            | Plan::DropDictionary(_) => Some(AuditEventType::Ddl),
+            _ => None,
+        }
+    }
+}
+
+/// The sequence number and the checksum of the last audit event of this node.
+#[derive(Default, Serialize, Deserialize)]
+struct AuditChain {
+    seq: u64,
+    checksum: String,
+}
+
+/// Writes the audit events to the `databend::log::audit` log target and `system.audit_log`,
+/// if `log.audit.on` is enabled.
+pub struct AuditLog {
+    enabled: bool,
+    key: Vec<u8>,
+    // Keeps the last event of the chain, so that the chain continues after a restart.
+    chain_file: Option<PathBuf>,
+    chain: Mutex<AuditChain>,
+}
+
+impl AuditLog {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        let audit = &config.log.audit;
+        if audit.on && audit.key.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "`log.audit.key` must be set when `log.audit.on` is true",
+            ));
+        }
+
+        let chain_file = match audit.on && !audit.dir.is_empty() {
+            true => Some(Path::new(&audit.dir).join(AUDIT_CHAIN_FILE)),
+            false => None,
+        };
+        let chain = match &chain_file {
+            Some(path) => Self::load_chain(path)?,
+            None => AuditChain::default(),
+        };
+
+        GlobalInstance::set(Arc::new(AuditLog {
+            enabled: audit.on,
+            key: audit.key.as_bytes().to_vec(),
+            chain_file,
+            chain: Mutex::new(chain),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<AuditLog> {
+        GlobalInstance::get()
+    }
+
+    pub fn log_login(tenant: &Tenant, user: &str, client_address: &str, err: Option<&ErrorCode>) {
+        let audit_log = Self::instance();
+        if !audit_log.enabled {
+            return;
+        }
+        let event = AuditLogElement {
+            seq: 0,
+            event_time: convert_query_log_timestamp(SystemTime::now()),
+            event_type: AuditEventType::Login.to_string(),
+            tenant_id: tenant.tenant_name().to_string(),
+            node_id: GlobalConfig::instance().query.node_id.clone(),
+            user: user.to_string(),
+            client_address: client_address.to_string(),
+            query_id: "".to_string(),
+            query_text: "".to_string(),
+            success: err.is_none(),
+            error_message: err.map(|e| e.to_string()).unwrap_or_default(),
+            checksum: "".to_string(),
+        };
+        audit_log
+            .write_log(event)
+            .unwrap_or_else(|e| error!("fail to write audit_log {:?}", e));
+    }
+
+    pub fn log_access_denied(ctx: &QueryContext, err: &ErrorCode) {
+        let audit_log = Self::instance();
+        if audit_log.enabled {
+            audit_log.log_query_event(ctx, AuditEventType::AccessDenied, Some(err));
+        }
+    }
+
+    /// Audit the query once it is finished, if its plan is security-relevant.
+    pub fn log_query(ctx: &QueryContext, err: Option<&ErrorCode>) {
+        let audit_log = Self::instance();
+        if !audit_log.enabled {
+            return;
+        }
+        if let Some(event_type) = ctx.get_audit_event_type() {
+            audit_log.log_query_event(ctx, event_type, err);
+        }
+    }
+
+    fn log_query_event(
+        &self,
+        ctx: &QueryContext,
+        event_type: AuditEventType,
+        err: Option<&ErrorCode>,
+    ) {
+        let user = ctx
+            .get_current_user()
+            .map(|u| u.identity().display().to_string())
+            .unwrap_or_default();
+        let event = AuditLogElement {
+            seq: 0,
+            event_time: convert_query_log_timestamp(SystemTime::now()),
+            event_type: event_type.to_string(),
+            tenant_id: ctx.get_tenant().tenant_name().to_string(),
+            node_id: ctx.get_cluster().local_id.clone(),
+            user,
+            client_address: ctx.get_client_address().unwrap_or_default(),
+            query_id: ctx.get_id(),
+            query_text: ctx.get_query_str(),
+            success: err.is_none(),
+            error_message: err.map(|e| e.to_string()).unwrap_or_default(),
+            checksum: "".to_string(),
+        };
+        self.write_log(event)
+            .unwrap_or_else(|e| error!("fail to write audit_log {:?}", e));
+    }
+
+    fn write_log(&self, mut event: AuditLogElement) -> Result<()> {
+        // Hold the lock until the event is written, so that the events are
+        // written in the order of the chain.
+        let mut chain = self.chain.lock();
+        event.seq = chain.seq + 1;
+        let mut mac = HmacSha256::new_from_slice(&self.key)
+            .map_err(|e| ErrorCode::InvalidConfig(format!("invalid log.audit.key: {}", e)))?;
+        mac.update(chain.checksum.as_bytes());
+        mac.update(&serde_json::to_vec(&event)?);
+        event.checksum = hex::encode(mac.finalize().into_bytes());
+
+        let event_str = serde_json::to_string(&event)?;
+        info!(target: "databend::log::audit", "{}", event_str);
+        chain.seq = event.seq;
+        chain.checksum = event.checksum.clone();
+        if let Some(path) = &self.chain_file {
+            Self::save_chain(path, &chain)?;
+        }
+        AuditLogQueue::instance()?.append_data(event)
+    }
+
+    fn load_chain(path: &Path) -> Result<AuditChain> {
+        match std::fs::read(path) {
+            Ok(data) => serde_json::from_slice(&data).map_err(|e| {
+                ErrorCode::InvalidConfig(format!(
+                    "Cannot continue the audit log chain from {}: {}",
+                    path.display(),
+                    e
+                ))
+            }),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(AuditChain::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save_chain(path: &Path, chain: &AuditChain) -> Result<()> {
+        // Write to a temporary file first, so a crash never leaves a partial chain file.
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_vec(chain)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
@@ -20,11 +20,14 @@ use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_storages_system::LogType;
 use databend_common_storages_system::QueryLogElement;
 use databend_common_storages_system::QueryLogQueue;
 use log::error;
 use log::info;
+use log::warn;
+use serde::Serialize;
 use serde_json;
 
 use crate::sessions::convert_query_log_timestamp;
@@ -221,6 +224,35 @@ impl InterpreterQueryLog {
         })
     }
 
+    /// Log the plan and the operator statistics of a query running longer than
+    /// `slow_query_threshold_ms`, returns them in JSON for the `extra` column of the query log.
+    fn log_slow_query(
+        ctx: &QueryContext,
+        query_duration_ms: i64,
+        slow_query_threshold_ms: u64,
+    ) -> Result<String> {
+        #[derive(Serialize)]
+        struct SlowQuery {
+            slow_query_threshold_ms: u64,
+            plan: String,
+            profiles: Vec<PlanProfile>,
+        }
+
+        let slow_query = SlowQuery {
+            slow_query_threshold_ms,
+            plan: ctx.get_query_plan().unwrap_or_default(),
+            profiles: ctx.get_query_profiles(),
+        };
+        warn!(
+            "slow query: {} took {}ms, exceeds slow_query_threshold_ms {}ms, plan: \n{}",
+            ctx.get_id(),
+            query_duration_ms,
+            slow_query_threshold_ms,
+            slow_query.plan
+        );
+        Ok(serde_json::to_string(&slow_query)?)
+    }
+
     pub fn log_finish<C>(
         ctx: &QueryContext,
         now: SystemTime,
@@ -323,6 +355,16 @@ impl InterpreterQueryLog {
         let txn_id = guard.txn_id().to_string();
         drop(guard);
 
+        // Slow query.
+        let slow_query_threshold_ms = ctx.get_settings().get_slow_query_threshold_ms()?;
+        let extra = if slow_query_threshold_ms != 0
+            && query_duration_ms >= slow_query_threshold_ms as i64
+        {
+            Self::log_slow_query(ctx, query_duration_ms, slow_query_threshold_ms)?
+        } else {
+            "".to_string()
+        };
+
         Self::write_log(QueryLogElement {
             log_type,
             log_type_name,
@@ -381,7 +423,7 @@ impl InterpreterQueryLog {
             stack_trace,
             server_version: DATABEND_COMMIT_VERSION.to_string(),
             session_settings,
-            extra: "".to_string(),
+            extra,
             has_profiles,
             txn_state,
             txn_id,
@@ -29,6 +29,8 @@ use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use databend_common_storages_stage::TextExternalTable;
+use databend_common_storages_stage::TEXT_TABLE_OPTIONS;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
@@ -89,6 +91,22 @@ pub fn is_valid_create_opt<S: AsRef<str>>(opt_key: S) -> bool {
     CREATE_TABLE_OPTIONS.contains(opt_key.as_ref().to_lowercase().as_str())
 }
 
+/// The file format options can only occur in the 'create table statement' of CSV, TSV and NDJSON tables.
+pub fn is_valid_text_table_opt<S: AsRef<str>>(engine: &str, opt_key: S) -> bool {
+    TextExternalTable::is_text_engine(engine)
+        && TEXT_TABLE_OPTIONS.contains(&opt_key.as_ref().to_lowercase().as_str())
+}
+
+pub fn is_valid_text_table_options(
+    engine: &str,
+    options: &BTreeMap<String, String>,
+) -> databend_common_exception::Result<()> {
+    if TextExternalTable::is_text_engine(engine) {
+        TextExternalTable::read_params(engine, options)?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_block_per_segment(
     options: &BTreeMap<String, String>,
 ) -> databend_common_exception::Result<()> {
@@ -52,6 +52,7 @@ use md5::Md5;
 use super::hook::vacuum_hook::hook_disk_temp_dir;
 use super::hook::vacuum_hook::hook_vacuum_temp_files;
 use super::interpreter_txn_commit::CommitInterpreter;
+use super::AuditLog;
 use super::InterpreterMetrics;
 use super::InterpreterQueryLog;
 use crate::pipelines::executor::ExecutorSettings;
@@ -200,6 +201,8 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
         SessionManager::instance().status.write().query_finish(now);
     }
 
+    AuditLog::log_query(ctx, error.as_ref());
+
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error, has_profiles) {
         error!("interpreter.finish.error: {:?}", error)
     }
@@ -253,6 +256,9 @@ async fn plan_sql(
         return Ok((plan, extras, AcquireQueueGuard::create(None)));
     }
 
+    // Only the queries of the clients acquire the queue, and count in the user's quota.
+    SessionManager::instance().charge_user_query(&ctx.get_current_session())?;
+
     let need_acquire_lock = need_acquire_lock(ctx.clone(), &extras.statement);
     if need_acquire_lock {
         // If a lock is required, acquire the queue guard before
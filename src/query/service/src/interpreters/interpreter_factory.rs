@@ -95,10 +95,23 @@ impl InterpreterFactory {
             .map_err(|e| match e.code() {
                 ErrorCode::PERMISSION_DENIED => {
                     error!("Access.denied(v2): {:?}", e);
+                    AuditLog::log_access_denied(&ctx, &e);
                     e
                 }
                 _ => e,
             })?;
+        if let Some(event_type) = AuditEventType::from_plan(plan) {
+            ctx.set_audit_event_type(event_type);
+        }
+        // The plan is only printed by the slow query log, the select interpreter
+        // records the physical plan of a query instead.
+        if !matches!(plan, Plan::Query { .. })
+            && ctx.get_settings().get_slow_query_threshold_ms()? != 0
+        {
+            if let Ok(query_plan) = plan.format_indent(false) {
+                ctx.set_query_plan(query_plan);
+            }
+        }
         Self::get_inner(ctx, plan)
     }
 
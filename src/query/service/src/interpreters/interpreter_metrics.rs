@@ -29,6 +29,7 @@ const LABEL_HANDLER: &str = "handler";
 const LABEL_KIND: &str = "kind";
 const LABEL_TENANT: &str = "tenant";
 const LABEL_CLUSTER: &str = "cluster";
+const LABEL_NODE: &str = "node";
 const LABEL_CODE: &str = "code";
 
 impl InterpreterMetrics {
@@ -37,12 +38,14 @@ impl InterpreterMetrics {
         let query_kind = ctx.get_query_kind().to_string();
         let tenant_id = ctx.get_tenant();
         let cluster_id = GlobalConfig::instance().query.cluster_id.clone();
+        let node_id = ctx.get_cluster().local_id.clone();
 
         vec![
             (LABEL_HANDLER, handler_type),
             (LABEL_KIND, query_kind),
             (LABEL_TENANT, tenant_id.tenant_name().to_string()),
             (LABEL_CLUSTER, cluster_id),
+            (LABEL_NODE, node_id),
         ]
     }
 
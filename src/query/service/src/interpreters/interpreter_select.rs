@@ -289,6 +289,7 @@ impl Interpreter for SelectInterpreter {
             .format_pretty()?;
 
         info!("Query physical plan: \n{}", query_plan);
+        self.ctx.set_query_plan(query_plan);
 
         if self.ctx.get_settings().get_enable_query_result_cache()?
             && self.ctx.get_cacheable()
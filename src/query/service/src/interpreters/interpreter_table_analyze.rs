@@ -299,6 +299,7 @@ fn remove_exchange(plan: PhysicalPlan) -> PhysicalPlan {
                     group_by: plan.group_by,
                     agg_funcs: plan.agg_funcs,
                     rank_limit: plan.rank_limit,
+                    input_sorted: plan.input_sorted,
                     enable_experimental_aggregate_hashtable: plan
                         .enable_experimental_aggregate_hashtable,
                     group_by_display: plan.group_by_display,
@@ -61,6 +61,7 @@ impl Interpreter for RenameTableInterpreter {
                 },
                 new_db_name: self.plan.new_database.clone(),
                 new_table_name: self.plan.new_table.clone(),
+                swap: self.plan.is_swap,
             })
             .await?;
 
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::quote::display_ident;
+use databend_common_ast::ast::quote::QuotedString;
 use databend_common_ast::parser::Dialect;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::table::Table;
@@ -192,12 +193,7 @@ impl ShowCreateTableInterpreter {
                 // compatibility: creating table in the old planner will not have `fields_comments`
                 let comment = if field_comments.len() == n_fields && !field_comments[idx].is_empty()
                 {
-                    // make the display more readable.
-                    // can not use debug print, will add double quote
-                    format!(
-                        " COMMENT '{}'",
-                        &field_comments[idx].as_str().replace('\'', "\\'")
-                    )
+                    format!(" COMMENT {}", QuotedString(&field_comments[idx], '\''))
                 } else {
                     "".to_string()
                 };
@@ -228,7 +224,7 @@ impl ShowCreateTableInterpreter {
                 let column_names_str = column_names.join(", ").to_string();
                 let mut options = Vec::with_capacity(index_field.options.len());
                 for (key, value) in index_field.options.iter() {
-                    let option = format!("{} = '{}'", key, value);
+                    let option = format!("{} = {}", key, QuotedString(value, '\''));
                     options.push(option);
                 }
                 let mut index_str = format!(
@@ -266,27 +262,43 @@ impl ShowCreateTableInterpreter {
                 .push_str(format!(" CLUSTER BY {}{}", cluster_type, cluster_keys_str).as_str());
         }
 
-        if !hide_options_in_show_create_table || engine == "ICEBERG" || engine == "DELTA" {
+        if !hide_options_in_show_create_table
+            || engine == "ICEBERG"
+            || engine == "DELTA"
+            || engine == "PARQUET"
+            || engine == "CSV"
+            || engine == "TSV"
+            || engine == "NDJSON"
+        {
             table_create_sql.push_str({
                 let mut opts = table_info.options().iter().collect::<Vec<_>>();
                 opts.sort_by_key(|(k, _)| *k);
                 opts.iter()
                     .filter(|(k, _)| !is_internal_opt_key(k))
-                    .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
+                    .map(|(k, v)| format!(" {}={}", k.to_uppercase(), QuotedString(v, '\'')))
                     .collect::<Vec<_>>()
                     .join("")
                     .as_str()
             });
         }
 
-        if engine != "ICEBERG" && engine != "DELTA" {
+        if !matches!(
+            engine,
+            "ICEBERG" | "DELTA" | "PARQUET" | "CSV" | "TSV" | "NDJSON"
+        ) {
             if let Some(sp) = &table_info.meta.storage_params {
                 table_create_sql.push_str(format!(" LOCATION = '{}'", sp).as_str());
             }
         }
 
         if !table_info.meta.comment.is_empty() {
-            table_create_sql.push_str(format!(" COMMENT = '{}'", table_info.meta.comment).as_str());
+            table_create_sql.push_str(
+                format!(
+                    " COMMENT = {}",
+                    QuotedString(&table_info.meta.comment, '\'')
+                )
+                .as_str(),
+            );
         }
         Ok(table_create_sql)
     }
@@ -325,7 +337,7 @@ impl ShowCreateTableInterpreter {
 
         let comment = stream_table.get_table_info().meta.comment.clone();
         if !comment.is_empty() {
-            create_sql.push_str(format!(" COMMENT = '{}'", comment).as_str());
+            create_sql.push_str(format!(" COMMENT = {}", QuotedString(&comment, '\'')).as_str());
         }
         Ok(create_sql)
     }
@@ -146,7 +146,10 @@ mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
 mod util;
 
+pub use access::Accessor;
 pub use access::ManagementModeAccess;
+pub use common::AuditEventType;
+pub use common::AuditLog;
 pub use common::InterpreterQueryLog;
 pub use hook::HookOperator;
 pub use interpreter::interpreter_plan_sql;
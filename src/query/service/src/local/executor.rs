@@ -27,9 +27,6 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::SendableDataBlockStream;
-use databend_common_meta_app::principal::GrantObject;
-use databend_common_meta_app::principal::UserInfo;
-use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::Planner;
 use futures_util::StreamExt;
@@ -45,11 +42,10 @@ use super::config::Settings;
 use super::display::ChunkDisplay;
 use super::display::FormatDisplay;
 use super::helper::CliHelper;
+use super::LocalSession;
 use crate::interpreters::InterpreterFactory;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
-use crate::sessions::SessionManager;
-use crate::sessions::SessionType;
 
 /// Session to execute local commands.
 pub(crate) struct SessionExecutor {
@@ -66,21 +62,7 @@ static PROMPT_SQL: &str = "select name from system.tables union all select name
 impl SessionExecutor {
     pub async fn try_new(is_repl: bool, output_format: &str) -> Result<Self> {
         let mut keywords = Vec::with_capacity(1024);
-        let session_manager = SessionManager::instance();
-
-        let session = session_manager
-            .create_session(SessionType::Local)
-            .await
-            .unwrap();
-
-        let session = session_manager.register_session(session)?;
-
-        let mut user = UserInfo::new_no_auth("root", "%");
-        user.grants.grant_privileges(
-            &GrantObject::Global,
-            UserPrivilegeSet::available_privileges_on_global(),
-        );
-        session.set_authed_user(user, None).await.unwrap();
+        let session = LocalSession::try_create().await?.session().clone();
 
         let config = Config::load();
         let mut settings = Settings::default();
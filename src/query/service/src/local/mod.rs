@@ -16,6 +16,7 @@ mod config;
 mod display;
 mod executor;
 pub(crate) mod helper;
+mod session;
 
 use std::env;
 use std::io::stdin;
@@ -30,38 +31,47 @@ use databend_common_license::license_manager::OssLicenseManager;
 use databend_common_meta_app::storage::StorageFsConfig;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_embedded::MetaEmbedded;
+pub use session::LocalSession;
 
 use crate::clusters::ClusterDiscovery;
 use crate::GlobalServices;
 
-pub async fn query_local(query_sql: &str, output_format: &str) -> Result<()> {
-    let temp_dir = tempfile::tempdir()?;
-    let p = env::var("DATABEND_DATA_PATH");
-    let path = match &p {
-        Ok(p) => Path::new(p),
-        Err(_) => temp_dir.path(),
-    };
-
+/// Start the services of the query engine in this process without a cluster:
+/// the meta data is kept in an embedded meta store under `<path>/_meta`,
+/// and the table data is kept in the local fs under `<path>/_data`.
+///
+/// This is the entry point to embed the query engine in tests and tools, it must be
+/// called once per process, then the queries are run with [`LocalSession`].
+pub async fn init_local(path: &Path, with_args: bool) -> Result<InnerConfig> {
     env::set_var("META_EMBEDDED_DIR", path.join("_meta"));
-    let mut conf: InnerConfig = Config::load(true).unwrap().try_into().unwrap();
+    let mut conf: InnerConfig = Config::load(with_args)?.try_into()?;
     conf.storage.allow_insecure = true;
     conf.storage.params = StorageParams::Fs(StorageFsConfig {
         root: path.join("_data").to_str().unwrap().to_owned(),
     });
 
     let meta_dir = path.join("_meta");
-    MetaEmbedded::init_global_meta_store(meta_dir.to_string_lossy().to_string())
-        .await
-        .unwrap();
+    MetaEmbedded::init_global_meta_store(meta_dir.to_string_lossy().to_string()).await?;
 
     GlobalServices::init(&conf).await?;
     // init oss license manager
-    OssLicenseManager::init(conf.query.tenant_id.tenant_name().to_string()).unwrap();
+    OssLicenseManager::init(conf.query.tenant_id.tenant_name().to_string())?;
 
     // Cluster register.
     ClusterDiscovery::instance()
         .register_to_metastore(&conf)
         .await?;
+    Ok(conf)
+}
+
+pub async fn query_local(query_sql: &str, output_format: &str) -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let p = env::var("DATABEND_DATA_PATH");
+    let path = match &p {
+        Ok(p) => Path::new(p),
+        Err(_) => temp_dir.path(),
+    };
+    init_local(path, true).await?;
 
     let is_terminal = stdin().is_terminal();
     let is_repl = is_terminal && query_sql.is_empty();
@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_sql::Planner;
+use futures_util::TryStreamExt;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// A session of the local mode, authed as `root` with all the privileges.
+///
+/// ```ignore
+/// init_local(path, false).await?;
+/// let session = LocalSession::try_create().await?;
+/// session.query("CREATE TABLE t(a INT)").await?;
+/// let blocks = session.query("SELECT * FROM t").await?;
+/// ```
+pub struct LocalSession {
+    session: Arc<Session>,
+}
+
+impl LocalSession {
+    pub async fn try_create() -> Result<Self> {
+        let session_manager = SessionManager::instance();
+        let session = session_manager.create_session(SessionType::Local).await?;
+        let session = session_manager.register_session(session)?;
+
+        let mut user = UserInfo::new_no_auth("root", "%");
+        user.grants.grant_privileges(
+            &GrantObject::Global,
+            UserPrivilegeSet::available_privileges_on_global(),
+        );
+        session.set_authed_user(user, None).await?;
+        Ok(LocalSession { session })
+    }
+
+    pub fn session(&self) -> &Arc<Session> {
+        &self.session
+    }
+
+    /// Run the query and collect all the result blocks.
+    pub async fn query(&self, sql: &str) -> Result<Vec<DataBlock>> {
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+        let stream = interpreter.execute(context).await?;
+        stream.try_collect().await
+    }
+}
@@ -48,6 +48,7 @@ use crate::pipelines::processors::transforms::aggregator::TransformExpandGroupin
 use crate::pipelines::processors::transforms::aggregator::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::aggregator::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::aggregator::TransformPartialGroupBy;
+use crate::pipelines::processors::transforms::aggregator::TransformStreamingAggregate;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -98,7 +99,10 @@ impl PipelineBuilder {
 
     pub(crate) fn build_aggregate_partial(&mut self, aggregate: &AggregatePartial) -> Result<()> {
         self.build_pipeline(&aggregate.input)?;
+        self.add_aggregate_partial_transforms(aggregate)
+    }
 
+    fn add_aggregate_partial_transforms(&mut self, aggregate: &AggregatePartial) -> Result<()> {
         let max_block_size = self.settings.get_max_block_size()?;
         let max_threads = self.settings.get_max_threads()?;
         let max_spill_io_requests = self.settings.get_max_spill_io_requests()?;
@@ -263,6 +267,26 @@ impl PipelineBuilder {
             return Ok(());
         }
 
+        let old_inject = self.exchange_injector.clone();
+
+        // The input sorted by the group by keys can be aggregated in streaming, if it's not split.
+        let mut input_built = false;
+        if let PhysicalPlan::AggregatePartial(partial) = aggregate.input.as_ref() {
+            if partial.input_sorted {
+                let _guard = self.add_plan_scope(&aggregate.input)?;
+                self.build_pipeline(&partial.input)?;
+                if self.main_pipeline.output_len() == 1 {
+                    self.exchange_injector = old_inject;
+                    return self.main_pipeline.try_add_accumulating_transformer(|| {
+                        TransformStreamingAggregate::try_new(&params)
+                    });
+                }
+
+                self.add_aggregate_partial_transforms(partial)?;
+                input_built = true;
+            }
+        }
+
         let efficiently_memory = self.settings.get_efficiently_memory_group_by()?;
 
         let group_cols = &params.group_columns;
@@ -270,8 +294,6 @@ impl PipelineBuilder {
         let sample_block = DataBlock::empty_with_schema(schema_before_group_by);
         let method = DataBlock::choose_hash_method(&sample_block, group_cols, efficiently_memory)?;
 
-        let old_inject = self.exchange_injector.clone();
-
         match params.aggregate_functions.is_empty() {
             true => with_hash_method!(|T| match method {
                 HashMethodKind::T(v) => {
@@ -284,7 +306,9 @@ impl PipelineBuilder {
                         );
                     }
 
-                    self.build_pipeline(&aggregate.input)?;
+                    if !input_built {
+                        self.build_pipeline(&aggregate.input)?;
+                    }
                     self.exchange_injector = old_inject;
                     build_partition_bucket::<_, ()>(v, &mut self.main_pipeline, params.clone())
                 }
@@ -299,7 +323,9 @@ impl PipelineBuilder {
                             params.clone(),
                         );
                     }
-                    self.build_pipeline(&aggregate.input)?;
+                    if !input_built {
+                        self.build_pipeline(&aggregate.input)?;
+                    }
                     self.exchange_injector = old_inject;
                     build_partition_bucket::<_, usize>(v, &mut self.main_pipeline, params.clone())
                 }
@@ -40,6 +40,7 @@ use log::info;
 
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
 use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::processors::TransformDropSourceFileMeta;
 use crate::pipelines::processors::TransformNullIf;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -159,6 +160,8 @@ impl PipelineBuilder {
                     func_ctx.clone(),
                 )
             })?;
+        } else {
+            main_pipeline.add_transformer(|| TransformDropSourceFileMeta);
         }
 
         if !plan_values_consts.is_empty() {
@@ -16,11 +16,19 @@ use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_common_sql::executor::physical_plans::AsyncFunctionDesc;
+use databend_common_sql::parse_sequence_default_expr;
+use databend_common_sql::plans::AsyncFunctionArgument;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformAsyncFunction;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -40,6 +48,11 @@ impl PipelineBuilder {
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
         let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
 
+        // Fill missing columns with `nextval(seq)` default from the sequences,
+        // the values of a block are allocated in one batch.
+        let source_schema =
+            Self::fill_sequence_columns(ctx.clone(), pipeline, &default_schema, source_schema)?;
+
         // Fill missing default columns and resort the columns.
         if source_schema != default_schema {
             pipeline.try_add_transformer(|| {
@@ -65,4 +78,43 @@ impl PipelineBuilder {
 
         Ok(())
     }
+
+    fn fill_sequence_columns(
+        ctx: Arc<QueryContext>,
+        pipeline: &mut Pipeline,
+        default_schema: &DataSchemaRef,
+        source_schema: DataSchemaRef,
+    ) -> Result<DataSchemaRef> {
+        let mut fields = source_schema.fields().clone();
+        let mut async_func_descs = vec![];
+        for field in default_schema.fields() {
+            if source_schema.has_field(field.name()) {
+                continue;
+            }
+            let Some(default_expr) = field.default_expr() else {
+                continue;
+            };
+            if let Some(sequence_name) = parse_sequence_default_expr(default_expr)? {
+                let data_type = DataType::Number(NumberDataType::UInt64);
+                async_func_descs.push(AsyncFunctionDesc {
+                    func_name: "nextval".to_string(),
+                    display_name: default_expr.clone(),
+                    output_column: fields.len(),
+                    arg_indices: vec![],
+                    data_type: Box::new(data_type.clone()),
+                    func_arg: AsyncFunctionArgument::SequenceFunction(sequence_name),
+                });
+                fields.push(DataField::new(field.name(), data_type));
+            }
+        }
+        if async_func_descs.is_empty() {
+            return Ok(source_schema);
+        }
+
+        let operators = TransformAsyncFunction::init_operators(&async_func_descs)?;
+        pipeline.add_async_transformer(|| {
+            TransformAsyncFunction::new(ctx.clone(), async_func_descs.clone(), operators.clone())
+        });
+        Ok(Arc::new(DataSchema::new(fields)))
+    }
 }
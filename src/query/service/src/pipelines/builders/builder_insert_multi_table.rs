@@ -188,7 +188,8 @@ impl PipelineBuilder {
             compact_task_builders
                 .push(Box::new(self.block_compact_task_builder(block_thresholds)?));
             compact_transform_builders.push(Box::new(self.block_compact_transform_builder()?));
-            let schema: Arc<DataSchema> = DataSchema::from(table.schema()).into();
+            let schema: Arc<DataSchema> =
+                DataSchema::from(&table.schema().remove_virtual_computed_fields()).into();
             let num_input_columns = schema.num_fields();
             let fuse_table = FuseTable::try_from_table(table.as_ref())?;
             let cluster_stats_gen = fuse_table.get_cluster_stats_gen(
@@ -116,7 +116,7 @@ impl PipelineBuilder {
         let segment_partition_num = std::cmp::min(segments.len(), max_threads as usize);
         let table = self.ctx.build_table_by_table_info(table_info, None)?;
         let table = FuseTable::try_from_table(table.as_ref())?;
-        let schema = DataSchema::from(table.schema()).into();
+        let schema = DataSchema::from(&table.schema().remove_virtual_computed_fields()).into();
         let cluster_stats_gen =
             table.get_cluster_stats_gen(self.ctx.clone(), 0, *block_thresholds, Some(schema))?;
         self.build_pipeline(input)?;
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use databend_common_base::runtime::MemStat;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 
@@ -25,6 +26,8 @@ pub struct ExecutorSettings {
     pub enable_queries_executor: bool,
     pub max_execute_time_in_seconds: Duration,
     pub executor_node_id: String,
+    // The memory tracker of the query, the memory used by the executor is also fed to it.
+    pub mem_stat: Option<Arc<MemStat>>,
 }
 
 impl ExecutorSettings {
@@ -34,12 +37,16 @@ impl ExecutorSettings {
         let max_threads = settings.get_max_threads()?;
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
 
+        let mem_stat = ctx.get_query_mem_stat();
+        mem_stat.set_limit(settings.get_max_query_memory_usage()? as i64);
+
         Ok(ExecutorSettings {
             enable_queries_executor: settings.get_enable_experimental_queries_executor()?,
             query_id: Arc::new(query_id),
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
             max_threads,
             executor_node_id: ctx.get_cluster().local_id.clone(),
+            mem_stat: Some(mem_stat),
         })
     }
 }
@@ -35,12 +35,12 @@ pub struct PipelineCompleteExecutor {
 
 // Use this executor when the pipeline is complete pipeline (has source and sink)
 impl PipelineCompleteExecutor {
-    fn execution_tracking_payload(query_id: &str) -> TrackingPayload {
+    fn execution_tracking_payload(settings: &ExecutorSettings) -> TrackingPayload {
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
-        tracking_payload.mem_stat = Some(MemStat::create(format!(
-            "QueryExecutionMemStat-{}",
-            query_id
-        )));
+        tracking_payload.mem_stat = Some(MemStat::create_child(
+            format!("QueryExecutionMemStat-{}", settings.query_id),
+            settings.mem_stat.clone().into_iter().collect(),
+        ));
         tracking_payload
     }
 
@@ -48,7 +48,7 @@ impl PipelineCompleteExecutor {
         pipeline: Pipeline,
         settings: ExecutorSettings,
     ) -> Result<PipelineCompleteExecutor> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         if !pipeline.is_complete_pipeline()? {
@@ -68,7 +68,7 @@ impl PipelineCompleteExecutor {
         pipelines: Vec<Pipeline>,
         settings: ExecutorSettings,
     ) -> Result<Arc<PipelineCompleteExecutor>> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         for pipeline in &pipelines {
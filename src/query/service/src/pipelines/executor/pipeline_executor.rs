@@ -225,9 +225,10 @@ impl PipelineExecutor {
                 let max_execute_future = Box::pin(tokio::time::sleep(max_execute_time_in_seconds));
                 if let Either::Left(_) = select(max_execute_future, finished_future).await {
                     if let Some(graph) = this_graph.upgrade() {
-                        graph.should_finish(Err(ErrorCode::AbortedQuery(
-                            "Aborted query, because the execution time exceeds the maximum execution time limit",
-                        ))).expect("exceed max execute time, but cannot send error message");
+                        graph.should_finish(Err(ErrorCode::QueryTimeout(format!(
+                            "Aborted query, because the execution time exceeds the max_execute_time_in_seconds limit ({}s)",
+                            max_execute_time_in_seconds.as_secs()
+                        )))).expect("exceed max execute time, but cannot send error message");
                     }
                 }
             });
@@ -102,12 +102,12 @@ pub struct PipelinePullingExecutor {
 }
 
 impl PipelinePullingExecutor {
-    fn execution_tracking_payload(query_id: &str) -> TrackingPayload {
+    fn execution_tracking_payload(settings: &ExecutorSettings) -> TrackingPayload {
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
-        tracking_payload.mem_stat = Some(MemStat::create(format!(
-            "QueryExecutionMemStat-{}",
-            query_id
-        )));
+        tracking_payload.mem_stat = Some(MemStat::create_child(
+            format!("QueryExecutionMemStat-{}", settings.query_id),
+            settings.mem_stat.clone().into_iter().collect(),
+        ));
         tracking_payload
     }
 
@@ -142,7 +142,7 @@ impl PipelinePullingExecutor {
         mut pipeline: Pipeline,
         settings: ExecutorSettings,
     ) -> Result<PipelinePullingExecutor> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         let (sender, receiver) = std::sync::mpsc::sync_channel(pipeline.output_len());
@@ -166,7 +166,7 @@ impl PipelinePullingExecutor {
         build_res: PipelineBuildResult,
         settings: ExecutorSettings,
     ) -> Result<PipelinePullingExecutor> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         let mut main_pipeline = build_res.main_pipeline;
@@ -341,9 +341,10 @@ impl QueryPipelineExecutor {
                 let max_execute_future = Box::pin(tokio::time::sleep(max_execute_time_in_seconds));
                 if let Either::Left(_) = select(max_execute_future, finished_future).await {
                     if let Some(executor) = this.upgrade() {
-                        executor.finish(Some(ErrorCode::AbortedQuery(
-                            "Aborted query, because the execution time exceeds the maximum execution time limit",
-                        )));
+                        executor.finish(Some(ErrorCode::QueryTimeout(format!(
+                            "Aborted query, because the execution time exceeds the max_execute_time_in_seconds limit ({}s)",
+                            max_execute_time_in_seconds.as_secs()
+                        ))));
                     }
                 }
             });
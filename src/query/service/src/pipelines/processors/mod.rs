@@ -21,6 +21,7 @@ pub use transforms::HashJoinState;
 pub use transforms::TransformAddStreamColumns;
 pub use transforms::TransformCastSchema;
 pub use transforms::TransformCreateSets;
+pub use transforms::TransformDropSourceFileMeta;
 pub use transforms::TransformLimit;
 pub use transforms::TransformNullIf;
 pub use transforms::TransformResortAddOn;
@@ -0,0 +1,226 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+use databend_common_functions::aggregates::AggregateFunctionRef;
+use databend_common_functions::aggregates::StateAddr;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+
+use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+
+/// Aggregate the input sorted by the group by keys, e.g.
+///
+/// `SELECT a, COUNT(*) FROM (SELECT a FROM t ORDER BY a) GROUP BY a`
+///
+/// The rows of a group are adjacent, so a group is finished as soon as the keys change,
+/// and only the states of the current group are kept instead of a hash table.
+pub struct TransformStreamingAggregate {
+    #[allow(dead_code)]
+    arena: Bump,
+    places: Vec<StateAddr>,
+    funcs: Vec<AggregateFunctionRef>,
+    arg_indices: Vec<Vec<usize>>,
+    group_columns: Vec<usize>,
+    max_block_size: usize,
+
+    // The keys of the current group, `None` before the first row.
+    current_keys: Option<Vec<Scalar>>,
+    agg_builders: Vec<ColumnBuilder>,
+    group_builders: Vec<ColumnBuilder>,
+}
+
+impl TransformStreamingAggregate {
+    pub fn try_new(params: &Arc<AggregatorParams>) -> Result<Self> {
+        let arena = Bump::new();
+        let mut places = Vec::with_capacity(params.aggregate_functions.len());
+        if let Some(layout) = params.layout {
+            let place: StateAddr = arena.alloc_layout(layout).into();
+            for (idx, func) in params.aggregate_functions.iter().enumerate() {
+                let arg_place = place.next(params.offsets_aggregate_states[idx]);
+                func.init_state(arg_place);
+                places.push(arg_place);
+            }
+        }
+
+        let mut transform = TransformStreamingAggregate {
+            arena,
+            places,
+            funcs: params.aggregate_functions.clone(),
+            arg_indices: params.aggregate_functions_arguments.clone(),
+            group_columns: params.group_columns.clone(),
+            max_block_size: params.max_block_size,
+            current_keys: None,
+            agg_builders: vec![],
+            group_builders: vec![],
+        };
+        transform.agg_builders = transform.new_agg_builders()?;
+        transform.group_builders = Self::new_group_builders(params);
+        Ok(transform)
+    }
+
+    fn new_agg_builders(&self) -> Result<Vec<ColumnBuilder>> {
+        self.funcs
+            .iter()
+            .map(|func| {
+                Ok(ColumnBuilder::with_capacity(
+                    &func.return_type()?,
+                    self.max_block_size,
+                ))
+            })
+            .collect()
+    }
+
+    fn new_group_builders(params: &AggregatorParams) -> Vec<ColumnBuilder> {
+        params
+            .group_data_types
+            .iter()
+            .map(|ty| ColumnBuilder::with_capacity(ty, params.max_block_size))
+            .collect()
+    }
+
+    /// The number of the finished groups not output yet.
+    fn num_groups(&self) -> usize {
+        self.group_builders[0].len()
+    }
+
+    /// Output the current group and reset the states for the next one.
+    fn finish_group(&mut self) -> Result<()> {
+        let Some(keys) = self.current_keys.take() else {
+            return Ok(());
+        };
+
+        for ((place, func), builder) in self
+            .places
+            .iter()
+            .zip(self.funcs.iter())
+            .zip(self.agg_builders.iter_mut())
+        {
+            func.merge_result(*place, builder)?;
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(*place) }
+            }
+            func.init_state(*place);
+        }
+        for (key, builder) in keys.iter().zip(self.group_builders.iter_mut()) {
+            builder.push(key.as_ref());
+        }
+        Ok(())
+    }
+
+    fn take_output(&mut self) -> Result<DataBlock> {
+        let group_builders = self
+            .group_builders
+            .iter()
+            .map(|builder| ColumnBuilder::with_capacity(&builder.data_type(), self.max_block_size))
+            .collect();
+        let agg_builders = self.new_agg_builders()?;
+        let agg_builders = std::mem::replace(&mut self.agg_builders, agg_builders);
+        let group_builders = std::mem::replace(&mut self.group_builders, group_builders);
+
+        let columns = agg_builders
+            .into_iter()
+            .chain(group_builders)
+            .map(|builder| builder.build())
+            .collect();
+        Ok(DataBlock::new_from_columns(columns))
+    }
+
+    fn accumulate(&mut self, block: &DataBlock) -> Result<()> {
+        for (idx, func) in self.funcs.iter().enumerate() {
+            let columns = InputColumns::new_block_proxy(self.arg_indices[idx].as_slice(), block);
+            func.accumulate(self.places[idx], columns, None, block.num_rows())?;
+        }
+        Ok(())
+    }
+}
+
+impl AccumulatingTransform for TransformStreamingAggregate {
+    const NAME: &'static str = "TransformStreamingAggregate";
+
+    fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        let block = block.consume_convert_to_full();
+        let group_columns = self
+            .group_columns
+            .iter()
+            .map(|i| block.get_by_offset(*i).value.as_column().unwrap().clone())
+            .collect::<Vec<_>>();
+
+        let mut blocks = vec![];
+        let mut start = 0;
+        for row in 0..block.num_rows() {
+            let is_same_group = match &self.current_keys {
+                Some(keys) => keys
+                    .iter()
+                    .zip(group_columns.iter())
+                    .all(|(key, column)| column.index(row) == Some(key.as_ref())),
+                None => false,
+            };
+            if is_same_group {
+                continue;
+            }
+
+            if row > start {
+                self.accumulate(&block.slice(start..row))?;
+            }
+            self.finish_group()?;
+            if self.num_groups() >= self.max_block_size {
+                blocks.push(self.take_output()?);
+            }
+
+            start = row;
+            self.current_keys = Some(
+                group_columns
+                    .iter()
+                    .map(|column| {
+                        column
+                            .index(row)
+                            .map(|v| v.to_owned())
+                            .ok_or_else(|| ErrorCode::Internal("group key out of range"))
+                    })
+                    .collect::<Result<_>>()?,
+            );
+        }
+        if block.num_rows() > start {
+            self.accumulate(&block.slice(start..block.num_rows()))?;
+        }
+
+        Ok(blocks)
+    }
+
+    fn on_finish(&mut self, generate_data: bool) -> Result<Vec<DataBlock>> {
+        let mut blocks = vec![];
+        if generate_data {
+            self.finish_group()?;
+            if self.num_groups() > 0 {
+                blocks.push(self.take_output()?);
+            }
+        }
+
+        // destroy states
+        for (place, func) in self.places.iter().zip(self.funcs.iter()) {
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(*place) }
+            }
+        }
+        Ok(blocks)
+    }
+}
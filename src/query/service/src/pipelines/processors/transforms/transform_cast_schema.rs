@@ -17,6 +17,7 @@ use std::sync::Arc;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::check_cast;
 use databend_common_expression::BlockEntry;
+use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Evaluator;
@@ -25,6 +26,8 @@ use databend_common_expression::FunctionContext;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_transforms::processors::Transform;
 use databend_common_pipeline_transforms::processors::Transformer;
+use databend_storages_common_stage::first_null_row;
+use databend_storages_common_stage::SourceFileMeta;
 
 use crate::pipelines::processors::InputPort;
 use crate::pipelines::processors::OutputPort;
@@ -87,7 +90,15 @@ where Self: Transform
 impl Transform for TransformCastSchema {
     const NAME: &'static str = "CastSchemaTransform";
 
-    fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+    fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        // The stage files of the rows if they are loaded by COPY, ignored if rows were
+        // filtered out since the block was read.
+        let source = data_block
+            .take_meta()
+            .and_then(SourceFileMeta::downcast_from)
+            .filter(|meta| {
+                meta.ranges.iter().map(|(_, _, n)| n).sum::<usize>() == data_block.num_rows()
+            });
         let mut columns = Vec::with_capacity(self.exprs.len());
         let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
         for (i, (field, expr)) in self
@@ -98,13 +109,40 @@ impl Transform for TransformCastSchema {
             .enumerate()
         {
             let value = evaluator.run(expr).map_err(|err| {
-                let msg = format!(
+                let mut msg = format!(
                     "fail to auto cast column {} ({}) to column {} ({})",
                     self.select_schema.fields[i].name(),
                     self.select_schema.fields[i].data_type(),
                     field.name(),
                     field.data_type(),
                 );
+                let null_row = match field.is_nullable() {
+                    true => None,
+                    false => first_null_row(data_block.get_by_offset(i)),
+                };
+                match (null_row, &source) {
+                    (Some(row), Some(source)) => {
+                        let (file, row) = source.locate(row).unwrap();
+                        msg.push_str(&format!(
+                            ", NULL value at row {} of file {} violates the NOT NULL constraint of column {}",
+                            row + 1,
+                            file,
+                            field.name()
+                        ));
+                    }
+                    (Some(row), None) => {
+                        msg.push_str(&format!(
+                            ", NULL value at row {} of the block of {} rows violates the NOT NULL constraint of column {}",
+                            row + 1,
+                            data_block.num_rows(),
+                            field.name()
+                        ));
+                    }
+                    (None, Some(source)) if source.ranges.len() == 1 => {
+                        msg.push_str(&format!(", in file {}", source.ranges[0].0));
+                    }
+                    (None, _) => {}
+                }
                 err.add_message(msg)
             })?;
             let column = BlockEntry::new(field.data_type().clone(), value);
@@ -113,3 +151,16 @@ impl Transform for TransformCastSchema {
         Ok(DataBlock::new(columns, data_block.num_rows()))
     }
 }
+
+/// Drops the stage files of the rows attached by the sources of COPY, for the loads
+/// that need no [`TransformCastSchema`] to report them.
+pub struct TransformDropSourceFileMeta;
+
+impl Transform for TransformDropSourceFileMeta {
+    const NAME: &'static str = "DropSourceFileMetaTransform";
+
+    fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        data_block.take_meta();
+        Ok(data_block)
+    }
+}
@@ -183,7 +183,7 @@ where Self: Transform
 impl Transform for TransformNullIf {
     const NAME: &'static str = "NullIfTransform";
 
-    fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+    fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
         let mut columns = Vec::with_capacity(self.exprs.len());
         let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
         for (field, expr) in self.schema.fields().iter().zip(self.exprs.iter()) {
@@ -191,6 +191,12 @@ impl Transform for TransformNullIf {
             let column = BlockEntry::new(field.data_type().clone(), value);
             columns.push(column);
         }
-        Ok(DataBlock::new(columns, data_block.num_rows()))
+        // Keep the source files of the rows for the cast after it.
+        let meta = data_block.take_meta();
+        Ok(DataBlock::new_with_meta(
+            columns,
+            data_block.num_rows(),
+            meta,
+        ))
     }
 }
@@ -99,11 +99,17 @@ pub fn build_expression_transform(
         } else {
             let field = input_schema.field_with_name(f.name()).unwrap();
             let id = input_schema.index_of(f.name()).unwrap();
-            Expr::ColumnRef {
+            let expr = Expr::ColumnRef {
                 span: None,
                 id,
                 data_type: field.data_type().clone(),
                 display_name: field.name().clone(),
+            };
+            // e.g. the UInt64 values allocated from a sequence for a `nextval(seq)` default.
+            if field.data_type() != f.data_type() {
+                check_cast(None, false, expr, f.data_type(), &BUILTIN_FUNCTIONS)?
+            } else {
+                expr
             }
         };
         exprs.push(expr);
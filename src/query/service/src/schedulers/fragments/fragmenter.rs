@@ -39,6 +39,7 @@ use crate::servers::flight::v1::exchange::BroadcastExchange;
 use crate::servers::flight::v1::exchange::DataExchange;
 use crate::servers::flight::v1::exchange::MergeExchange;
 use crate::servers::flight::v1::exchange::ShuffleDataExchange;
+use crate::servers::flight::v1::exchange::ShufflePartitioner;
 use crate::sessions::QueryContext;
 use crate::sql::executor::physical_plans::Mutation;
 use crate::sql::executor::PhysicalPlan;
@@ -98,10 +99,21 @@ impl Fragmenter {
         plan: &PhysicalPlan,
     ) -> Result<Option<DataExchange>> {
         match plan {
-            PhysicalPlan::ExchangeSink(plan) => match plan.kind {
+            PhysicalPlan::ExchangeSink(plan) => match &plan.kind {
                 FragmentKind::Normal => Ok(Some(ShuffleDataExchange::create(
                     Self::get_executors(ctx),
                     plan.keys.clone(),
+                    ShufflePartitioner::Hash,
+                ))),
+                FragmentKind::Range(bounds) => Ok(Some(ShuffleDataExchange::create(
+                    Self::get_executors(ctx),
+                    plan.keys.clone(),
+                    ShufflePartitioner::Range(bounds.clone()),
+                ))),
+                FragmentKind::RoundRobin => Ok(Some(ShuffleDataExchange::create(
+                    Self::get_executors(ctx),
+                    vec![],
+                    ShufflePartitioner::RoundRobin,
                 ))),
                 FragmentKind::Merge => Ok(Some(MergeExchange::create(
                     Self::get_local_executor(ctx),
@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use headers::authorization::Bearer;
+use headers::authorization::Credentials;
+use http::header::AUTHORIZATION;
+use http::Method;
+use http::StatusCode;
+use poem::Endpoint;
+use poem::IntoResponse;
+use poem::Middleware;
+use poem::Request;
+use poem::Response;
+use subtle::ConstantTimeEq;
+
+// The probes must be reachable without the token.
+const UNAUTHENTICATED_PATHS: [&str; 2] = ["/v1/health", "/v1/readiness"];
+
+/// Check the bearer token of the admin api requests:
+///
+/// - `auth_token` can access all the endpoints.
+/// - `read_only_token` can only access the GET endpoints.
+///
+/// No authentication is required if both are empty.
+pub struct AdminAuthMiddleware {
+    auth_token: String,
+    read_only_token: String,
+}
+
+impl AdminAuthMiddleware {
+    pub fn create(auth_token: String, read_only_token: String) -> Self {
+        Self {
+            auth_token,
+            read_only_token,
+        }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for AdminAuthMiddleware {
+    type Output = AdminAuthEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        AdminAuthEndpoint {
+            ep,
+            auth_token: self.auth_token.clone(),
+            read_only_token: self.read_only_token.clone(),
+        }
+    }
+}
+
+pub struct AdminAuthEndpoint<E> {
+    ep: E,
+    auth_token: String,
+    read_only_token: String,
+}
+
+impl<E> AdminAuthEndpoint<E> {
+    fn check(&self, req: &Request) -> poem::Result<()> {
+        if self.auth_token.is_empty() && self.read_only_token.is_empty() {
+            return Ok(());
+        }
+
+        if UNAUTHENTICATED_PATHS.contains(&req.uri().path()) {
+            return Ok(());
+        }
+
+        let token = req
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(Bearer::decode)
+            .map(|bearer| bearer.token().to_string())
+            .ok_or_else(|| {
+                poem::Error::from_string("missing bearer token", StatusCode::UNAUTHORIZED)
+            })?;
+
+        if !self.auth_token.is_empty() && token_eq(&token, &self.auth_token) {
+            return Ok(());
+        }
+
+        if !self.read_only_token.is_empty() && token_eq(&token, &self.read_only_token) {
+            return match req.method() == Method::GET || req.method() == Method::HEAD {
+                true => Ok(()),
+                false => Err(poem::Error::from_string(
+                    "read-only token is not allowed to modify",
+                    StatusCode::FORBIDDEN,
+                )),
+            };
+        }
+
+        Err(poem::Error::from_string(
+            "invalid bearer token",
+            StatusCode::UNAUTHORIZED,
+        ))
+    }
+}
+
+// Compare in constant time, so the token can not be guessed from the response time.
+fn token_eq(token: &str, expected: &str) -> bool {
+    token.as_bytes().ct_eq(expected.as_bytes()).into()
+}
+
+impl<E: Endpoint> Endpoint for AdminAuthEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        self.check(&req)?;
+        let output = self.ep.call(req).await?;
+        Ok(output.into_response())
+    }
+}
@@ -21,6 +21,7 @@ use databend_common_http::health_handler;
 use databend_common_http::home::debug_home_handler;
 #[cfg(feature = "memory-profiling")]
 use databend_common_http::jeprof::debug_jeprof_dump_handler;
+use databend_common_http::mem::debug_mem_handler;
 use databend_common_http::pprof::debug_pprof_handler;
 use databend_common_http::stack::debug_dump_stack;
 use databend_common_http::HttpError;
@@ -28,12 +29,16 @@ use databend_common_http::HttpShutdownHandler;
 use databend_common_meta_types::anyerror::AnyError;
 use log::info;
 use log::warn;
+use poem::delete;
 use poem::get;
 use poem::listener::OpensslTlsConfig;
 use poem::post;
 use poem::Endpoint;
+use poem::EndpointExt;
 use poem::Route;
 
+use crate::servers::admin::AdminAuthMiddleware;
+use crate::servers::http::middleware::RateLimitMiddleware;
 use crate::servers::Server;
 
 pub struct AdminService {
@@ -50,10 +55,23 @@ impl AdminService {
     }
 
     fn build_router(&self) -> impl Endpoint {
-        #[cfg_attr(not(feature = "memory-profiling"), allow(unused_mut))]
         let mut route = Route::new()
             .at("/v1/health", get(health_handler))
+            .at(
+                "/v1/readiness",
+                get(super::v1::readiness::readiness_handler),
+            )
             .at("/v1/config", get(super::v1::config::config_handler))
+            .at(
+                "/v1/config/reload",
+                post(super::v1::config::config_reload_handler),
+            )
+            .at("/v1/logs", get(super::v1::logs::logs_handler))
+            .at(
+                "/v1/logs/level",
+                get(super::v1::logs::get_log_level_handler)
+                    .put(super::v1::logs::set_log_level_handler),
+            )
             .at("/v1/system", get(super::v1::system::system_handler))
             .at(
                 "/v1/status",
@@ -63,6 +81,10 @@ impl AdminService {
                 "/v1/processlist",
                 get(super::v1::processes::processlist_handler),
             )
+            .at(
+                "/v1/query/:query_id",
+                delete(super::v1::processes::kill_query_handler),
+            )
             .at(
                 "/v1/tables",
                 get(super::v1::tenant_tables::list_tables_handler),
@@ -75,9 +97,18 @@ impl AdminService {
                 "v1/queries/:query_id/profiling",
                 get(super::v1::query_profiling::query_profiling_handler),
             )
-            .at("/debug/home", get(debug_home_handler))
-            .at("/debug/pprof/profile", get(debug_pprof_handler))
-            .at("/debug/async_tasks/dump", get(debug_dump_stack));
+            .at(
+                "/v1/queries/:query_id/profile",
+                get(super::v1::query_profiling::query_profile_tree_handler),
+            );
+
+        if self.config.query.admin_api_enable_debug {
+            route = route
+                .at("/debug/home", get(debug_home_handler))
+                .at("/debug/pprof/profile", get(debug_pprof_handler))
+                .at("/debug/mem", get(debug_mem_handler))
+                .at("/debug/async_tasks/dump", get(debug_dump_stack));
+        }
 
         // Multiple tenants admin api
         if self.config.query.management_mode {
@@ -115,18 +146,28 @@ impl AdminService {
 
         #[cfg(feature = "memory-profiling")]
         {
-            route = route.at(
-                // to follow the conversions of jeprof, we arrange the path in
-                // this way, so that jeprof could be invoked like:
-                //   `jeprof ./target/debug/databend-query http://localhost:8080/debug/mem`
-                // and jeprof will translate the above url into sth like:
-                //    "http://localhost:8080/debug/mem/pprof/profile?seconds=30"
-                "/debug/mem/pprof/profile",
-                get(debug_jeprof_dump_handler),
-            );
+            if self.config.query.admin_api_enable_debug {
+                route = route.at(
+                    // to follow the conversions of jeprof, we arrange the path in
+                    // this way, so that jeprof could be invoked like:
+                    //   `jeprof ./target/debug/databend-query http://localhost:8080/debug/mem`
+                    // and jeprof will translate the above url into sth like:
+                    //    "http://localhost:8080/debug/mem/pprof/profile?seconds=30"
+                    "/debug/mem/pprof/profile",
+                    get(debug_jeprof_dump_handler),
+                );
+            }
         };
 
         route
+            .with(AdminAuthMiddleware::create(
+                self.config.query.admin_api_auth_token.clone(),
+                self.config.query.admin_api_read_only_token.clone(),
+            ))
+            .with(RateLimitMiddleware::create(
+                "admin",
+                self.config.query.admin_api_rate_limit_per_sec,
+            ))
     }
 
     fn build_tls(config: &InnerConfig) -> Result<OpensslTlsConfig, std::io::Error> {
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod admin_auth;
 mod admin_service;
 pub mod v1;
 
+pub use admin_auth::AdminAuthMiddleware;
 pub use admin_service::AdminService;
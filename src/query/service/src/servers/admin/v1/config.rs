@@ -12,9 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
+
 use databend_common_config::GlobalConfig;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_tracing::set_reloaded_log_level;
+use databend_common_tracing::LogOutput;
+use http::StatusCode;
+use log::info;
+use log::LevelFilter;
+use parking_lot::Mutex;
 use poem::web::Json;
 use poem::IntoResponse;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::sessions::SessionManager;
 
 #[poem::handler]
 #[async_backtrace::framed]
@@ -27,3 +42,87 @@ pub async fn config_handler() -> poem::Result<impl IntoResponse> {
             .with_mask(),
     ))
 }
+
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+pub struct ConfigReloadResponse {
+    // the config fields which have been applied
+    pub reloaded: Vec<String>,
+}
+
+// POST /v1/config/reload
+// load the config from file, env and args again and apply the reloadable fields,
+// the changes of the other fields are rejected.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn config_reload_handler() -> poem::Result<impl IntoResponse> {
+    let reloaded = match InnerConfig::load().await {
+        Ok(new_config) => reload_config(&new_config),
+        Err(cause) => Err(cause),
+    }
+    .map_err(|cause| poem::Error::from_string(cause.message(), StatusCode::BAD_REQUEST))?;
+
+    Ok(Json(ConfigReloadResponse { reloaded }))
+}
+
+/// Apply the reloadable fields of the new config to the running instance,
+/// returns the fields which have changed.
+pub fn reload_config(new_config: &InnerConfig) -> Result<Vec<String>> {
+    // Serialize the reloads, so a reload is always compared with the config applied by the last one.
+    static RELOAD_LOCK: Mutex<()> = Mutex::new(());
+    let _guard = RELOAD_LOCK.lock();
+
+    let running = GlobalConfig::instance();
+    running.check_reloadable(new_config)?;
+
+    // Check all the levels before applying anything.
+    let file_level = reloaded_log_level(&running.log.file.level, &new_config.log.file.level)?;
+    let stderr_level = reloaded_log_level(&running.log.stderr.level, &new_config.log.stderr.level)?;
+
+    let mut applied = running.as_ref().clone();
+    let mut reloaded = vec![];
+
+    if let Some(level) = file_level {
+        set_reloaded_log_level(LogOutput::File, level);
+        applied.log.file.level = new_config.log.file.level.clone();
+        reloaded.push("log.file.level".to_string());
+    }
+
+    if let Some(level) = stderr_level {
+        set_reloaded_log_level(LogOutput::Stderr, level);
+        applied.log.stderr.level = new_config.log.stderr.level.clone();
+        reloaded.push("log.stderr.level".to_string());
+    }
+
+    if new_config.query.max_active_sessions != running.query.max_active_sessions {
+        applied.query.max_active_sessions = new_config.query.max_active_sessions;
+        reloaded.push("query.max_active_sessions".to_string());
+    }
+
+    if new_config.query.max_running_queries_per_user != running.query.max_running_queries_per_user {
+        applied.query.max_running_queries_per_user = new_config.query.max_running_queries_per_user;
+        reloaded.push("query.max_running_queries_per_user".to_string());
+    }
+
+    SessionManager::instance().set_limits(
+        applied.query.max_active_sessions as usize,
+        applied.query.max_running_queries_per_user as usize,
+    );
+
+    GlobalConfig::reload(applied);
+
+    info!("Reloaded config fields: {:?}", reloaded);
+    Ok(reloaded)
+}
+
+fn reloaded_log_level(running: &str, new: &str) -> Result<Option<LevelFilter>> {
+    if new == running {
+        return Ok(None);
+    }
+
+    LevelFilter::from_str(new).map(Some).map_err(|_| {
+        ErrorCode::InvalidConfig(format!(
+            "Cannot reload log level {:?}, only a single level like INFO is supported",
+            new
+        ))
+    })
+}
@@ -14,6 +14,10 @@
 
 use std::time::SystemTime;
 
+use databend_common_config::GlobalConfig;
+use databend_common_config::QUERY_BUILD_TIMESTAMP;
+use databend_common_config::QUERY_GIT_SEMVER;
+use databend_common_config::QUERY_GIT_SHA;
 use poem::web::Json;
 use poem::IntoResponse;
 use serde::Deserialize;
@@ -40,6 +44,16 @@ pub struct InstanceStatus {
     pub instance_started_at: u64,
     // the local timestamp, may be useful to avoid the clock drift issues
     pub instance_timestamp: u64,
+    // the seconds elapsed since the instance started up
+    pub uptime_secs: u64,
+    // the build info of the running binary
+    pub version: String,
+    pub git_sha: String,
+    pub build_timestamp: String,
+    pub cluster_id: String,
+    pub node_id: String,
+    // the cargo features enabled in the running binary
+    pub features: Vec<String>,
 }
 
 // lightweight way to get status
@@ -50,6 +64,7 @@ pub async fn instance_status_handler() -> poem::Result<impl IntoResponse> {
     let session_manager = SessionManager::instance();
     let queue_manager = QueriesQueueManager::instance();
     let status = session_manager.get_current_session_status();
+    let config = GlobalConfig::instance();
     let status = InstanceStatus {
         running_queries_count: status.running_queries_count,
         active_sessions_count: status.active_sessions_count,
@@ -59,10 +74,39 @@ pub async fn instance_status_handler() -> poem::Result<impl IntoResponse> {
         max_running_query_executed_secs: status.max_running_query_executed_secs,
         instance_started_at: unix_timestamp_secs(status.instance_started_at),
         instance_timestamp: unix_timestamp_secs(SystemTime::now()),
+        uptime_secs: SystemTime::now()
+            .duration_since(status.instance_started_at)
+            .unwrap_or_default()
+            .as_secs(),
+        version: QUERY_GIT_SEMVER.clone(),
+        git_sha: QUERY_GIT_SHA.clone(),
+        build_timestamp: QUERY_BUILD_TIMESTAMP.clone(),
+        cluster_id: config.query.cluster_id.clone(),
+        node_id: config.query.node_id.clone(),
+        features: enabled_features(),
     };
     Ok(Json(status))
 }
 
+fn enabled_features() -> Vec<String> {
+    let features = [
+        ("simd", cfg!(feature = "simd")),
+        ("python-udf", cfg!(feature = "python-udf")),
+        ("memory-profiling", cfg!(feature = "memory-profiling")),
+        ("storage-hdfs", cfg!(feature = "storage-hdfs")),
+        ("io-uring", cfg!(feature = "io-uring")),
+        (
+            "enable_queries_executor",
+            cfg!(feature = "enable_queries_executor"),
+        ),
+    ];
+    features
+        .into_iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
 // Convert SystemTime to an u64 timestamp. The clock is almost impossible to drift before
 // 1970, so we can safely use the expect() here.
 fn unix_timestamp_secs(t: SystemTime) -> u64 {
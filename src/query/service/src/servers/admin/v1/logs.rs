@@ -0,0 +1,249 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path as FsPath;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use async_stream::stream;
+use databend_common_base::base::tokio;
+use databend_common_base::runtime::spawn_blocking;
+use databend_common_config::GlobalConfig;
+use databend_common_tracing::get_log_level_override;
+use databend_common_tracing::set_log_level_override;
+use http::StatusCode;
+use log::LevelFilter;
+use poem::web::sse::Event;
+use poem::web::sse::SSE;
+use poem::web::Json;
+use poem::web::Query;
+use poem::IntoResponse;
+use poem::Response;
+use serde::Deserialize;
+use serde::Serialize;
+
+const DEFAULT_TAIL_LINES: usize = 100;
+const MAX_TAIL_LINES: usize = 10000;
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Deserialize, Debug)]
+pub struct LogsQuery {
+    pub lines: Option<usize>,
+    pub follow: Option<bool>,
+}
+
+// GET /v1/logs?lines=100&follow=false
+// return the last lines of the current log file as plain text,
+// with `follow=true` keep streaming the new lines as server-sent events.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn logs_handler(Query(query): Query<LogsQuery>) -> poem::Result<Response> {
+    let file_config = &GlobalConfig::instance().log.file;
+    if !file_config.on {
+        return Err(poem::Error::from_string(
+            "file logging is disabled",
+            StatusCode::NOT_FOUND,
+        ));
+    }
+
+    let dir = file_config.dir.clone();
+    let lines = query
+        .lines
+        .unwrap_or(DEFAULT_TAIL_LINES)
+        .min(MAX_TAIL_LINES);
+    let (content, path, offset) = run_blocking(move || {
+        let path = latest_log_file(&dir).ok_or_else(|| {
+            poem::Error::from_string(
+                format!("no log file found in {}", dir),
+                StatusCode::NOT_FOUND,
+            )
+        })?;
+        let (content, offset) = tail_lines(&path, lines).map_err(internal_error)?;
+        Ok((content, path, offset))
+    })
+    .await?;
+
+    if !query.follow.unwrap_or(false) {
+        return Ok(content.into_response());
+    }
+
+    let dir = file_config.dir.clone();
+    let events = stream! {
+        for line in content.lines() {
+            yield Event::message(line.to_string());
+        }
+
+        let mut path = path;
+        let mut offset = offset;
+        loop {
+            tokio::time::sleep(FOLLOW_POLL_INTERVAL).await;
+            let dir = dir.clone();
+            let res = run_blocking(move || Ok(follow_log_file(&dir, path, offset))).await;
+            let Ok((appended, new_path, new_offset)) = res else {
+                break;
+            };
+            path = new_path;
+            offset = new_offset;
+            for line in appended.lines() {
+                yield Event::message(line.to_string());
+            }
+        }
+    };
+    Ok(SSE::new(events).into_response())
+}
+
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+pub struct LogLevel {
+    // the runtime log level, None means the level in the config
+    pub level: Option<String>,
+}
+
+// GET /v1/logs/level
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn get_log_level_handler() -> poem::Result<impl IntoResponse> {
+    Ok(Json(LogLevel {
+        level: get_log_level_override().map(|level| level.to_string()),
+    }))
+}
+
+// PUT /v1/logs/level
+// change the level of the file and stderr loggers without a restart,
+// `{"level": null}` restores the level in the config.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn set_log_level_handler(Json(req): Json<LogLevel>) -> poem::Result<impl IntoResponse> {
+    let level = match &req.level {
+        None => None,
+        Some(level) => Some(LevelFilter::from_str(level).map_err(|_| {
+            poem::Error::from_string(
+                format!("invalid log level: {}", level),
+                StatusCode::BAD_REQUEST,
+            )
+        })?),
+    };
+
+    set_log_level_override(level);
+    Ok(Json(LogLevel {
+        level: level.map(|level| level.to_string()),
+    }))
+}
+
+fn internal_error(cause: std::io::Error) -> poem::Error {
+    poem::Error::from_string(
+        format!("failed to read log file: {}", cause),
+        StatusCode::INTERNAL_SERVER_ERROR,
+    )
+}
+
+// Run the blocking file operations out of the async runtime.
+async fn run_blocking<F, R>(f: F) -> poem::Result<R>
+where
+    F: FnOnce() -> poem::Result<R> + Send + 'static,
+    R: Send + 'static,
+{
+    spawn_blocking(f).await.map_err(|cause| {
+        poem::Error::from_string(
+            format!("failed to read log file: {}", cause),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        )
+    })?
+}
+
+fn latest_log_file(dir: &str) -> Option<PathBuf> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            match metadata.is_file() {
+                true => Some((metadata.modified().ok()?, entry.path())),
+                false => None,
+            }
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+// Read the last `lines` lines of the file, returns the content and the end offset.
+fn tail_lines(path: &FsPath, lines: usize) -> std::io::Result<(String, u64)> {
+    const CHUNK_SIZE: u64 = 64 * 1024;
+
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+
+    let mut start = len;
+    let mut buf = Vec::new();
+    // Read backwards until there are enough lines, the last line may not end with '\n'.
+    while start > 0 && buf.iter().filter(|b| **b == b'\n').count() <= lines {
+        let read_size = CHUNK_SIZE.min(start);
+        start -= read_size;
+        file.seek(SeekFrom::Start(start))?;
+        let mut chunk = vec![0; read_size as usize];
+        file.read_exact(&mut chunk)?;
+        chunk.extend_from_slice(&buf);
+        buf = chunk;
+    }
+
+    let content = String::from_utf8_lossy(&buf);
+    let all_lines = content.lines().collect::<Vec<_>>();
+    let skip = all_lines.len().saturating_sub(lines);
+    let mut tail = all_lines[skip..].join("\n");
+    if !tail.is_empty() {
+        tail.push('\n');
+    }
+    Ok((tail, len))
+}
+
+// Read the content appended after `offset` of the followed file, returns the content,
+// and the file and the offset to read from in next round.
+//
+// Once the logger rolls over to a newer file, the rest of the old file is returned
+// and the newer file is followed from its beginning.
+fn follow_log_file(dir: &str, path: PathBuf, offset: u64) -> (String, PathBuf, u64) {
+    // The file may have been removed, wait for the newer one.
+    let (content, offset) = read_from(&path, offset).unwrap_or((String::new(), offset));
+
+    match latest_log_file(dir) {
+        Some(latest) if latest != path => (content, latest, 0),
+        _ => (content, path, offset),
+    }
+}
+
+// Read the content appended after `offset`, returns the content and the new end offset.
+fn read_from(path: &FsPath, offset: u64) -> std::io::Result<(String, u64)> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    // The file has been truncated in place, read it again from the beginning.
+    let offset = if len < offset { 0 } else { offset };
+
+    file.seek(SeekFrom::Start(offset))?;
+    let mut buf = Vec::with_capacity((len - offset) as usize);
+    file.by_ref().take(len - offset).read_to_end(&mut buf)?;
+
+    // Only return complete lines, the rest will be read in next round.
+    let end = buf
+        .iter()
+        .rposition(|b| *b == b'\n')
+        .map_or(0, |pos| pos + 1);
+    buf.truncate(end);
+    Ok((
+        String::from_utf8_lossy(&buf).into_owned(),
+        offset + end as u64,
+    ))
+}
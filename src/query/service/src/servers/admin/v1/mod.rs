@@ -16,8 +16,10 @@ pub mod background_tasks;
 pub mod cluster;
 pub mod config;
 pub mod instance_status;
+pub mod logs;
 pub mod processes;
 pub mod query_profiling;
+pub mod readiness;
 pub mod settings;
 pub mod stream_status;
 pub mod system;
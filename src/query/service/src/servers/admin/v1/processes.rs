@@ -14,7 +14,9 @@
 
 use std::time::SystemTime;
 
+use http::StatusCode;
 use poem::web::Json;
+use poem::web::Path;
 use poem::IntoResponse;
 use serde::Deserialize;
 use serde::Serialize;
@@ -35,6 +37,8 @@ pub struct ProcessInfo {
     pub created_time: SystemTime,
     pub status_info: Option<String>,
     pub current_query_id: Option<String>,
+    pub elapsed_secs: u64,
+    pub scan_rows: u64,
 }
 
 #[poem::handler]
@@ -65,7 +69,27 @@ pub async fn processlist_handler() -> poem::Result<impl IntoResponse> {
             created_time: process.created_time,
             status_info: process.status_info.clone(),
             current_query_id: process.current_query_id.clone(),
+            elapsed_secs: SystemTime::now()
+                .duration_since(process.created_time)
+                .unwrap_or_default()
+                .as_secs(),
+            scan_rows: process
+                .scan_progress_value
+                .as_ref()
+                .map(|progress| progress.rows as u64)
+                .unwrap_or(0),
         })
         .collect::<Vec<_>>();
     Ok(Json(processes))
 }
+
+// DELETE /v1/query/:query_id
+// cancel the running or queued query on this node
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn kill_query_handler(Path(query_id): Path<String>) -> poem::Result<impl IntoResponse> {
+    SessionManager::instance()
+        .kill_query(&query_id)
+        .map_err(|cause| poem::Error::from_string(cause.message(), StatusCode::NOT_FOUND))?;
+    Ok(StatusCode::OK)
+}
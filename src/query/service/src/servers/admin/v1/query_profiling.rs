@@ -14,10 +14,12 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_base::runtime::profile::get_statistics_desc;
 use databend_common_base::runtime::profile::ProfileDesc;
+use databend_common_base::runtime::profile::ProfileLabel;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
@@ -44,14 +46,104 @@ pub async fn query_profiling_handler(
         profiles: Vec<PlanProfile>,
         statistics_desc: Arc<BTreeMap<ProfileStatisticsName, ProfileDesc>>,
     }
-    match get_profile_from_cache(&query_id) {
-        Ok(profiles) => {
-            return Ok(Json(QueryProfiles {
-                query_id: query_id.clone(),
-                profiles,
-                statistics_desc: get_statistics_desc(),
-            }));
+    let profiles = fetch_query_profiles(&query_id).await?;
+    Ok(Json(QueryProfiles {
+        query_id: query_id.clone(),
+        profiles,
+        statistics_desc: get_statistics_desc(),
+    }))
+}
+
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn query_profile_tree_handler(
+    Path(query_id): Path<String>,
+) -> poem::Result<impl IntoResponse> {
+    #[derive(serde::Serialize)]
+    struct QueryProfileTree {
+        query_id: String,
+        operators: Vec<OperatorProfile>,
+    }
+
+    let profiles = fetch_query_profiles(&query_id).await?;
+    Ok(Json(QueryProfileTree {
+        query_id,
+        operators: build_profile_tree(&profiles),
+    }))
+}
+
+/// One operator of the physical plan, with the statistics of the processors
+/// belonging to it and the operators it reads from as children.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct OperatorProfile {
+    pub id: Option<u32>,
+    pub name: Option<String>,
+    pub title: String,
+    pub labels: Vec<ProfileLabel>,
+    /// Keyed by [`ProfileStatisticsName`], e.g. `CpuTime`, `WaitTime`, `OutputRows`.
+    pub statistics: BTreeMap<String, usize>,
+    pub errors: usize,
+    pub children: Vec<OperatorProfile>,
+}
+
+/// Link the flat plan profiles into trees by `parent_id`. Profiles whose parent
+/// is absent (e.g. the root of the plan) become roots.
+pub fn build_profile_tree(profiles: &[PlanProfile]) -> Vec<OperatorProfile> {
+    let ids = profiles.iter().filter_map(|p| p.id).collect::<HashSet<_>>();
+
+    let mut roots = vec![];
+    let mut children = HashMap::<u32, Vec<&PlanProfile>>::new();
+    for profile in profiles {
+        match profile.parent_id {
+            Some(parent_id) if ids.contains(&parent_id) => {
+                children.entry(parent_id).or_default().push(profile)
+            }
+            _ => roots.push(profile),
+        }
+    }
+
+    fn build(
+        profile: &PlanProfile,
+        children: &HashMap<u32, Vec<&PlanProfile>>,
+        desc: &BTreeMap<ProfileStatisticsName, ProfileDesc>,
+    ) -> OperatorProfile {
+        let mut child_profiles = profile
+            .id
+            .and_then(|id| children.get(&id))
+            .map(|v| {
+                v.iter()
+                    .map(|p| build(p, children, desc))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        child_profiles.sort_by_key(|p| p.id);
+
+        OperatorProfile {
+            id: profile.id,
+            name: profile.name.clone(),
+            title: profile.title.to_string(),
+            labels: profile.labels.as_ref().clone(),
+            statistics: desc
+                .iter()
+                .map(|(name, desc)| (name.to_string(), profile.statistics[desc.index]))
+                .collect(),
+            errors: profile.errors.len(),
+            children: child_profiles,
         }
+    }
+
+    let desc = get_statistics_desc();
+    let mut operators = roots
+        .into_iter()
+        .map(|p| build(p, &children, &desc))
+        .collect::<Vec<_>>();
+    operators.sort_by_key(|p| p.id);
+    operators
+}
+
+async fn fetch_query_profiles(query_id: &str) -> poem::Result<Vec<PlanProfile>> {
+    match get_profile_from_cache(query_id) {
+        Ok(profiles) => return Ok(profiles),
         Err(cause) => {
             if cause.code() != ErrorCode::UNKNOWN_QUERY {
                 return Err(poem::Error::from_string(
@@ -61,10 +153,10 @@ pub async fn query_profiling_handler(
             }
         }
     }
-    let res = match SessionManager::instance().get_query_profiles(&query_id) {
+    let profiles = match SessionManager::instance().get_query_profiles(query_id) {
         Ok(profiles) => profiles,
         Err(cause) => match cause.code() == ErrorCode::UNKNOWN_QUERY {
-            true => match get_cluster_profile(&query_id).await {
+            true => match get_cluster_profile(query_id).await {
                 Ok(profiles) => profiles,
                 Err(cause) => {
                     return Err(match cause.code() == ErrorCode::UNKNOWN_QUERY {
@@ -85,11 +177,7 @@ pub async fn query_profiling_handler(
         },
     };
 
-    Ok(Json(QueryProfiles {
-        query_id: query_id.clone(),
-        profiles: res,
-        statistics_desc: get_statistics_desc(),
-    }))
+    Ok(profiles)
 }
 
 async fn get_cluster_profile(query_id: &str) -> Result<Vec<PlanProfile>, ErrorCode> {
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use http::StatusCode;
+use poem::web::Json;
+use poem::IntoResponse;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::clusters::ClusterDiscovery;
+use crate::sessions::SessionManager;
+
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+pub struct ReadinessStatus {
+    pub ready: bool,
+    // the reason why the instance is not ready
+    pub reason: Option<String>,
+}
+
+// GET /v1/readiness
+// check whether the instance can serve queries, used as the kubernetes readiness probe.
+// return 200 if the node is registered to the cluster, the metastore is reachable and
+// the instance is accepting queries, otherwise 503 with the reason.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn readiness_handler() -> poem::Result<impl IntoResponse> {
+    let status = match check_readiness().await {
+        Ok(()) => ReadinessStatus {
+            ready: true,
+            reason: None,
+        },
+        Err(reason) => ReadinessStatus {
+            ready: false,
+            reason: Some(reason),
+        },
+    };
+
+    let code = match status.ready {
+        true => StatusCode::OK,
+        false => StatusCode::SERVICE_UNAVAILABLE,
+    };
+    Ok(Json(status).with_status(code))
+}
+
+async fn check_readiness() -> Result<(), String> {
+    if !SessionManager::instance().is_ready() {
+        return Err("instance is not accepting queries".to_string());
+    }
+
+    match ClusterDiscovery::instance().is_registered().await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err("node is not registered to the cluster".to_string()),
+        Err(cause) => Err(format!("metastore is unreachable: {}", cause.message())),
+    }
+}
@@ -25,6 +25,7 @@ use databend_common_base::base::tokio::time::Duration;
 use databend_common_base::runtime::drop_guard;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_metrics::flight::metrics_inc_flight_rpc_errors;
 use fastrace::func_path;
 use fastrace::future::FutureExt;
 use fastrace::Span;
@@ -92,9 +93,19 @@ impl FlightClient {
             AsciiMetadataValue::from_str(&secret).unwrap(),
         );
 
-        let response = self.inner.do_action(request).await?;
+        let response = self.inner.do_action(request).await.inspect_err(|status| {
+            metrics_inc_flight_rpc_errors("do_action", &status.code().to_string())
+        })?;
+
+        let message = response
+            .into_inner()
+            .message()
+            .await
+            .inspect_err(|status| {
+                metrics_inc_flight_rpc_errors("do_action", &status.code().to_string())
+            })?;
 
-        match response.into_inner().message().await? {
+        match message {
             Some(response) => {
                 let mut deserializer = serde_json::Deserializer::from_slice(&response.body);
                 deserializer.disable_recursion_limit();
@@ -115,20 +126,20 @@ impl FlightClient {
     }
 
     #[async_backtrace::framed]
+    #[fastrace::trace]
     pub async fn request_server_exchange(
         &mut self,
         query_id: &str,
         target: &str,
     ) -> Result<FlightExchange> {
-        let streaming = self
-            .get_streaming(
-                RequestBuilder::create(Ticket::default())
-                    .with_metadata("x-type", "request_server_exchange")?
-                    .with_metadata("x-target", target)?
-                    .with_metadata("x-query-id", query_id)?
-                    .build(),
-            )
-            .await?;
+        let request = RequestBuilder::create(Ticket::default())
+            .with_metadata("x-type", "request_server_exchange")?
+            .with_metadata("x-target", target)?
+            .with_metadata("x-query-id", query_id)?
+            .build();
+        let request = databend_common_tracing::inject_span_to_tonic_request(request);
+
+        let streaming = self.get_streaming(request).await?;
 
         let (notify, rx) = Self::streaming_receiver(streaming);
         Ok(FlightExchange::create_receiver(notify, rx))
@@ -183,6 +194,10 @@ impl FlightClient {
                                     }
                                 }
                                 Err(status) => {
+                                    metrics_inc_flight_rpc_errors(
+                                        "do_get",
+                                        &status.code().to_string(),
+                                    );
                                     let _ = tx.send(Err(ErrorCode::from(status))).await;
                                     break;
                                 }
@@ -206,7 +221,10 @@ impl FlightClient {
     async fn get_streaming(&mut self, request: Request<Ticket>) -> Result<Streaming<FlightData>> {
         match self.inner.do_get(request).await {
             Ok(res) => Ok(res.into_inner()),
-            Err(status) => Err(ErrorCode::from(status).add_message_back("(while in query flight)")),
+            Err(status) => {
+                metrics_inc_flight_rpc_errors("do_get", &status.code().to_string());
+                Err(ErrorCode::from(status).add_message_back("(while in query flight)"))
+            }
         }
     }
 }
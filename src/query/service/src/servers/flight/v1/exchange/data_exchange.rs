@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_expression::RemoteExpr;
+use databend_common_storage::Datum;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum DataExchange {
@@ -35,17 +36,35 @@ impl DataExchange {
 pub struct ShuffleDataExchange {
     pub destination_ids: Vec<String>,
     pub shuffle_keys: Vec<RemoteExpr>,
+    pub partitioner: ShufflePartitioner,
 }
 
 impl ShuffleDataExchange {
-    pub fn create(destination_ids: Vec<String>, shuffle_keys: Vec<RemoteExpr>) -> DataExchange {
+    pub fn create(
+        destination_ids: Vec<String>,
+        shuffle_keys: Vec<RemoteExpr>,
+        partitioner: ShufflePartitioner,
+    ) -> DataExchange {
         DataExchange::ShuffleDataExchange(ShuffleDataExchange {
             destination_ids,
             shuffle_keys,
+            partitioner,
         })
     }
 }
 
+/// How the rows are distributed to the destinations of a shuffle.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum ShufflePartitioner {
+    /// By the hash of the shuffle keys.
+    Hash,
+    /// By the ranges of the only shuffle key, the destination `i` receives the keys
+    /// in `(bounds[i - 1], bounds[i]]`.
+    Range(Vec<Datum>),
+    /// Evenly, regardless of the values.
+    RoundRobin,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MergeExchange {
     pub destination_id: String,
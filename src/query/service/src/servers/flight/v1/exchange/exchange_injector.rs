@@ -26,9 +26,12 @@ use crate::servers::flight::v1::exchange::serde::TransformScatterExchangeSeriali
 use crate::servers::flight::v1::exchange::DataExchange;
 use crate::servers::flight::v1::exchange::ExchangeSorting;
 use crate::servers::flight::v1::exchange::ShuffleExchangeParams;
+use crate::servers::flight::v1::exchange::ShufflePartitioner;
 use crate::servers::flight::v1::scatter::BroadcastFlightScatter;
 use crate::servers::flight::v1::scatter::FlightScatter;
 use crate::servers::flight::v1::scatter::HashFlightScatter;
+use crate::servers::flight::v1::scatter::RangeFlightScatter;
+use crate::servers::flight::v1::scatter::RoundRobinFlightScatter;
 use crate::sessions::QueryContext;
 
 pub trait ExchangeInjector: Send + Sync + 'static {
@@ -93,12 +96,23 @@ impl ExchangeInjector for DefaultExchangeInjector {
                     .iter()
                     .position(|x| x == local_id)
                     .unwrap();
-                HashFlightScatter::try_create(
-                    ctx.get_function_context()?,
-                    exchange.shuffle_keys.clone(),
-                    exchange.destination_ids.len(),
-                    local_pos,
-                )?
+                match &exchange.partitioner {
+                    ShufflePartitioner::Hash => HashFlightScatter::try_create(
+                        ctx.get_function_context()?,
+                        exchange.shuffle_keys.clone(),
+                        exchange.destination_ids.len(),
+                        local_pos,
+                    )?,
+                    ShufflePartitioner::Range(bounds) => RangeFlightScatter::try_create(
+                        ctx.get_function_context()?,
+                        &exchange.shuffle_keys[0],
+                        bounds.clone(),
+                        exchange.destination_ids.len(),
+                    )?,
+                    ShufflePartitioner::RoundRobin => {
+                        RoundRobinFlightScatter::try_create(exchange.destination_ids.len())?
+                    }
+                }
             }
         }))
     }
@@ -67,6 +67,11 @@ impl StatisticsSender {
                             break;
                         }
                         Either::Right((Ok(Some(error_code)), _recv)) => {
+                            let error_code = error_code.with_context(format!(
+                                "executing query {} on node {}",
+                                query_id,
+                                ctx.get_cluster().local_id
+                            ));
                             let data = DataPacket::ErrorCode(error_code);
                             if let Err(error_code) = tx.send(data).await {
                                 warn!(
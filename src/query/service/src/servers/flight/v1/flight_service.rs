@@ -108,7 +108,9 @@ impl FlightService for DatabendQueryFlightService {
 
     #[async_backtrace::framed]
     async fn do_get(&self, request: Request<Ticket>) -> Response<Self::DoGetStream> {
-        let root = databend_common_tracing::start_trace_for_remote_request(func_path!(), &request);
+        let query_id = request.get_metadata("x-query-id").unwrap_or_default();
+        let root = databend_common_tracing::start_trace_for_remote_request(func_path!(), &request)
+            .with_property(|| ("query_id", query_id));
         let _guard = root.set_local_parent();
 
         match request.get_metadata("x-type")?.as_str() {
@@ -176,7 +178,13 @@ impl FlightService for DatabendQueryFlightService {
             .in_span(root)
             .await
         {
-            Err(cause) => Err(cause.into()),
+            Err(cause) => {
+                let cause: ErrorCode = cause.with_context(format!(
+                    "executing flight action {} on node {}",
+                    action.r#type, config.query.node_id
+                ));
+                Err(cause.into())
+            }
             Ok(body) => Ok(RawResponse::new(
                 Box::pin(tokio_stream::once(Ok(FlightResult { body: body.into() })))
                     as FlightStream<FlightResult>,
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_storage::Datum;
+
+use crate::servers::flight::v1::scatter::flight_scatter::FlightScatter;
+
+/// Distribute the rows by the ranges of the key, the destination `i` receives the keys
+/// in `(bounds[i - 1], bounds[i]]`, and the NULLs go to the first destination.
+pub struct RangeFlightScatter {
+    func_ctx: FunctionContext,
+    range_key: Expr,
+    bounds: Vec<Datum>,
+    scatter_size: usize,
+}
+
+impl RangeFlightScatter {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        range_key: &RemoteExpr,
+        bounds: Vec<Datum>,
+        scatter_size: usize,
+    ) -> Result<Box<dyn FlightScatter>> {
+        Ok(Box::new(RangeFlightScatter {
+            func_ctx,
+            range_key: range_key.as_expr(&BUILTIN_FUNCTIONS),
+            bounds,
+            scatter_size,
+        }))
+    }
+
+    fn partition(&self, key: Option<Datum>) -> u64 {
+        let Some(key) = key else {
+            return 0;
+        };
+        let partition = self
+            .bounds
+            .partition_point(|bound| matches!(bound.compare(&key), Ok(std::cmp::Ordering::Less)));
+        partition.min(self.scatter_size - 1) as u64
+    }
+}
+
+impl FlightScatter for RangeFlightScatter {
+    fn execute(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let num_rows = data_block.num_rows();
+        let keys = evaluator.run(&self.range_key)?;
+        let indices = (0..num_rows)
+            .map(|row| {
+                let key = keys.index(row).map(|key| key.to_owned());
+                self.partition(key.and_then(Datum::from_scalar))
+            })
+            .collect::<Vec<_>>();
+
+        let block_meta = data_block.get_meta();
+        let data_blocks = DataBlock::scatter(&data_block, &indices, self.scatter_size)?;
+
+        let mut res = Vec::with_capacity(data_blocks.len());
+        for data_block in data_blocks {
+            res.push(data_block.add_meta(block_meta.cloned())?);
+        }
+
+        Ok(res)
+    }
+}
@@ -15,7 +15,11 @@
 mod flight_scatter;
 mod flight_scatter_broadcast;
 mod flight_scatter_hash;
+mod flight_scatter_range;
+mod flight_scatter_round_robin;
 
 pub use flight_scatter::FlightScatter;
 pub use flight_scatter_broadcast::BroadcastFlightScatter;
 pub use flight_scatter_hash::HashFlightScatter;
+pub use flight_scatter_range::RangeFlightScatter;
+pub use flight_scatter_round_robin::RoundRobinFlightScatter;
@@ -50,6 +50,7 @@ use super::FlightSqlServiceImpl;
 use crate::interpreters::InterpreterFactory;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
+use crate::sessions::SessionManager;
 
 /// A app_metakey which indicates the data is a progress type
 static H_PROGRESS: u8 = 0x01;
@@ -119,6 +120,7 @@ impl FlightSqlServiceImpl {
             .create_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
+        SessionManager::instance().charge_user_query(session)?;
 
         let mut planner = Planner::new(context.clone());
         planner.plan_sql(query).await
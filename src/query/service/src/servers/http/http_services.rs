@@ -38,6 +38,7 @@ use crate::servers::http::middleware::json_response;
 use crate::servers::http::middleware::EndpointKind;
 use crate::servers::http::middleware::HTTPSessionMiddleware;
 use crate::servers::http::middleware::PanicHandler;
+use crate::servers::http::middleware::RateLimitMiddleware;
 use crate::servers::http::v1::clickhouse_router;
 use crate::servers::http::v1::query_route;
 use crate::servers::Server;
@@ -92,13 +93,20 @@ impl HttpHandler {
     #[allow(clippy::let_with_type_underscore)]
     #[async_backtrace::framed]
     async fn build_router(&self, sock: SocketAddr) -> impl Endpoint {
-        let ep_clickhouse =
-            Route::new()
-                .nest("/", clickhouse_router())
-                .with(HTTPSessionMiddleware::create(
-                    self.kind,
-                    EndpointKind::Clickhouse,
-                ));
+        let rate_limit_per_sec = GlobalConfig::instance()
+            .query
+            .http_handler_rate_limit_per_sec;
+
+        let ep_clickhouse = Route::new()
+            .nest("/", clickhouse_router())
+            .with(HTTPSessionMiddleware::create(
+                self.kind,
+                EndpointKind::Clickhouse,
+            ))
+            .with(RateLimitMiddleware::create(
+                "clickhouse",
+                rate_limit_per_sec,
+            ));
 
         let ep_usage = Route::new().at(
             "/",
@@ -112,7 +120,10 @@ impl HttpHandler {
             HttpHandlerKind::Query => Route::new()
                 .at("/", ep_usage)
                 .nest("/health", ep_health)
-                .nest("/v1", query_route())
+                .nest(
+                    "/v1",
+                    query_route().with(RateLimitMiddleware::create("query", rate_limit_per_sec)),
+                )
                 .nest("/clickhouse", ep_clickhouse),
             HttpHandlerKind::Clickhouse => Route::new()
                 .nest("/", ep_clickhouse)
@@ -129,11 +140,14 @@ impl HttpHandler {
             .cert_from_file(config.query.http_handler_tls_server_cert.as_str())
             .key_from_file(config.query.http_handler_tls_server_key.as_str());
 
-        // if Path::new(&config.query.http_handler_tls_server_root_ca_cert).exists() {
-        //     cfg = cfg.client_auth_required(std::fs::read(
-        //         config.query.http_handler_tls_server_root_ca_cert.as_str(),
-        //     )?);
-        // }
+        // The openssl listener of poem can not verify client certificates,
+        // refuse to start instead of accepting clients without a certificate.
+        if !config.query.http_handler_tls_server_root_ca_cert.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "client certificate verification is not supported by the http handler, unset http_handler_tls_server_root_ca_cert",
+            ));
+        }
         Ok(cfg)
     }
 
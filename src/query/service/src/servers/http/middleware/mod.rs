@@ -14,10 +14,15 @@
 
 mod metrics;
 mod panic_handler;
+mod rate_limit;
 mod session;
 
 pub(crate) use metrics::MetricsMiddleware;
 pub(crate) use panic_handler::PanicHandler;
+pub use rate_limit::RateLimitMiddleware;
+pub use rate_limit::RateLimiter;
+pub(crate) use session::get_client_ip;
+pub(crate) use session::get_peer_ip;
 pub use session::json_response;
 pub(crate) use session::sanitize_request_headers;
 pub use session::EndpointKind;
@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Instant;
+
+use databend_common_cache::Cache;
+use databend_common_cache::LruCache;
+use databend_common_cache::MemSized;
+use databend_common_metrics::http::metrics_incr_http_rate_limited_request_count;
+use http::header::RETRY_AFTER;
+use http::StatusCode;
+use parking_lot::Mutex;
+use poem::Endpoint;
+use poem::IntoResponse;
+use poem::Middleware;
+use poem::Request;
+use poem::Response;
+
+use crate::servers::http::middleware::get_peer_ip;
+
+// The least recently seen clients are dropped once tracking more clients than this,
+// a dropped client starts again with a full bucket, the same as a client idle for a second.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl MemSized for TokenBucket {
+    fn mem_bytes(&self) -> usize {
+        std::mem::size_of::<TokenBucket>()
+    }
+}
+
+/// Token buckets keyed by client ip, each refilled with `rate_per_sec` tokens per second
+/// and holding at most `rate_per_sec` tokens.
+pub struct RateLimiter {
+    rate_per_sec: f64,
+    buckets: Mutex<LruCache<IpAddr, TokenBucket>>,
+}
+
+impl RateLimiter {
+    pub fn create(rate_per_sec: u64) -> Self {
+        RateLimiter {
+            rate_per_sec: rate_per_sec as f64,
+            buckets: Mutex::new(LruCache::with_items_capacity(MAX_TRACKED_CLIENTS)),
+        }
+    }
+
+    pub fn try_acquire(&self, client: IpAddr, now: Instant) -> bool {
+        let mut buckets = self.buckets.lock();
+
+        let mut bucket = buckets.pop(&client).unwrap_or(TokenBucket {
+            tokens: self.rate_per_sec,
+            last_refill: now,
+        });
+
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate_per_sec).min(self.rate_per_sec);
+        bucket.last_refill = now;
+
+        let acquired = bucket.tokens >= 1.0;
+        if acquired {
+            bucket.tokens -= 1.0;
+        }
+        buckets.insert(client, bucket);
+        acquired
+    }
+}
+
+/// Reject the requests with 429 once a client ip exceeds the rate limit.
+/// Each middleware instance holds its own buckets, so the endpoint classes
+/// (e.g. query, clickhouse, admin) are limited separately.
+pub struct RateLimitMiddleware {
+    api: String,
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl RateLimitMiddleware {
+    /// `rate_per_sec` of 0 disables the rate limit.
+    pub fn create(api: impl Into<String>, rate_per_sec: u64) -> Self {
+        RateLimitMiddleware {
+            api: api.into(),
+            limiter: match rate_per_sec {
+                0 => None,
+                _ => Some(Arc::new(RateLimiter::create(rate_per_sec))),
+            },
+        }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for RateLimitMiddleware {
+    type Output = RateLimitEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        RateLimitEndpoint {
+            ep,
+            api: self.api.clone(),
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+pub struct RateLimitEndpoint<E> {
+    ep: E,
+    api: String,
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl<E: Endpoint> Endpoint for RateLimitEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::error::Result<Self::Output> {
+        if let Some(limiter) = &self.limiter {
+            // Keyed on the peer address, the forwarding headers are set by the client.
+            let client = get_peer_ip(&req).unwrap_or(IpAddr::from([0, 0, 0, 0]));
+            if !limiter.try_acquire(client, Instant::now()) {
+                metrics_incr_http_rate_limited_request_count(self.api.clone());
+                return Ok(Response::builder()
+                    .status(StatusCode::TOO_MANY_REQUESTS)
+                    .header(RETRY_AFTER, "1")
+                    .body(format!("too many requests from client {client}")));
+            }
+        }
+
+        let output = self.ep.call(req).await?;
+        Ok(output.into_response())
+    }
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
 use std::sync::Arc;
 
 use databend_common_base::headers::HEADER_DEDUPLICATE_LABEL;
@@ -30,6 +32,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::principal::user_token::TokenType;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::NodeInfo;
+use databend_common_users::check_client_ip_allowed;
 use fastrace::func_name;
 use headers::authorization::Basic;
 use headers::authorization::Bearer;
@@ -59,12 +62,14 @@ use uuid::Uuid;
 use crate::auth::AuthMgr;
 use crate::auth::Credential;
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::AuditLog;
 use crate::servers::http::error::HttpErrorCode;
 use crate::servers::http::error::JsonErrorOnly;
 use crate::servers::http::error::QueryError;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::servers::http::v1::SessionClaim;
 use crate::servers::HttpHandlerKind;
+use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
 const USER_AGENT: &str = "User-Agent";
@@ -80,6 +85,7 @@ pub enum EndpointKind {
     NoAuth,
     Verify,
     UploadToStage,
+    StreamingLoad,
     SystemInfo,
 }
 
@@ -102,7 +108,8 @@ impl EndpointKind {
             | EndpointKind::PollQuery
             | EndpointKind::Logout
             | EndpointKind::SystemInfo
-            | EndpointKind::UploadToStage => {
+            | EndpointKind::UploadToStage
+            | EndpointKind::StreamingLoad => {
                 if GlobalConfig::instance().query.management_mode {
                     Ok(None)
                 } else {
@@ -195,7 +202,7 @@ fn get_credential(
 /// not found, fallback to the remote address, which might be local proxy's ip address.
 /// please note that when it comes with network policy, we need make sure the incoming
 /// traffic comes from a trustworthy proxy instance.
-fn get_client_ip(req: &Request) -> Option<String> {
+pub(crate) fn get_client_ip(req: &Request) -> Option<String> {
     let headers = ["X-Real-IP", "X-Forwarded-For", "CF-Connecting-IP"];
     for &header in headers.iter() {
         if let Some(value) = req.headers().get(header) {
@@ -218,6 +225,16 @@ fn get_client_ip(req: &Request) -> Option<String> {
     client_ip
 }
 
+/// The ip of the connection's remote address, unlike [`get_client_ip`] it
+/// can't be forged by the headers of the request.
+pub(crate) fn get_peer_ip(req: &Request) -> Option<IpAddr> {
+    match req.remote_addr().0 {
+        Addr::SocketAddr(addr) => Some(addr.ip()),
+        Addr::Custom(..) => Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+        _ => None,
+    }
+}
+
 fn get_credential_from_header(
     std_auth_headers: &[&HeaderValue],
     client_ip: Option<String>,
@@ -314,6 +331,9 @@ pub struct HTTPSessionEndpoint<E> {
 impl<E> HTTPSessionEndpoint<E> {
     #[async_backtrace::framed]
     async fn auth(&self, req: &Request, query_id: String) -> Result<HttpQueryContext> {
+        let allowed_ip_list = &GlobalConfig::instance().query.http_handler_allowed_ip_list;
+        check_client_ip_allowed(allowed_ip_list, get_peer_ip(req))?;
+
         let credential = get_credential(req, self.kind, self.endpoint_kind)?;
 
         let session_manager = SessionManager::instance();
@@ -330,14 +350,20 @@ impl<E> HTTPSessionEndpoint<E> {
             .headers()
             .get(HEADER_SESSION_ID)
             .map(|v| v.to_str().unwrap().to_string());
-        let (user_name, authed_client_session_id) = self
+        let res = self
             .auth_manager
             .auth(
                 &mut session,
                 &credential,
                 self.endpoint_kind.need_user_info(),
             )
-            .await?;
+            .await;
+        // Only the login creates a client session, the other requests are
+        // authenticated again on each call and are not audited as logins.
+        if let EndpointKind::Login = self.endpoint_kind {
+            audit_login(&session, &credential, &res);
+        }
+        let (user_name, authed_client_session_id) = res?;
         let client_session_id = authed_client_session_id.or(header_client_session_id);
         if let Some(id) = client_session_id.clone() {
             session.set_client_session_id(id)
@@ -388,6 +414,25 @@ impl<E> HTTPSessionEndpoint<E> {
     }
 }
 
+fn audit_login(session: &Session, credential: &Credential, res: &Result<(String, Option<String>)>) {
+    let (user, client_ip) = match credential {
+        Credential::Password {
+            name, client_ip, ..
+        } => (name.clone(), client_ip),
+        Credential::Jwt { client_ip, .. } => match res {
+            Ok((name, _)) => (name.clone(), client_ip),
+            Err(_) => ("".to_string(), client_ip),
+        },
+        Credential::DatabendToken { .. } | Credential::NoNeed => return,
+    };
+    AuditLog::log_login(
+        &session.get_current_tenant(),
+        &user,
+        client_ip.as_deref().unwrap_or_default(),
+        res.as_ref().err(),
+    );
+}
+
 async fn forward_request(mut req: Request, node: Arc<NodeInfo>) -> PoemResult<Response> {
     let addr = node.http_address.clone();
     let config = GlobalConfig::instance();
@@ -480,7 +525,8 @@ impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
         let query_id = req
             .headers()
             .get(HEADER_QUERY_ID)
-            .map(|id| id.to_str().unwrap().to_string())
+            .and_then(|id| id.to_str().ok())
+            .map(|id| id.to_string())
             .unwrap_or_else(|| Uuid::new_v4().to_string());
 
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
@@ -56,6 +56,7 @@ use crate::servers::http::v1::logout_handler;
 use crate::servers::http::v1::query::string_block::StringBlock;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::refresh_handler;
+use crate::servers::http::v1::streaming_load;
 use crate::servers::http::v1::upload_to_stage;
 use crate::servers::http::v1::verify_handler;
 use crate::servers::http::v1::HttpQueryContext;
@@ -453,6 +454,11 @@ pub fn query_route() -> Route {
             put(upload_to_stage),
             EndpointKind::UploadToStage,
         ),
+        (
+            "/streaming_load",
+            put(streaming_load).post(streaming_load),
+            EndpointKind::StreamingLoad,
+        ),
         (
             "/suggested_background_tasks",
             get(list_suggestions),
@@ -17,6 +17,7 @@ mod http_query_handlers;
 mod query;
 mod session;
 mod stage;
+mod streaming_load;
 mod suggestions;
 mod verify;
 
@@ -43,6 +44,8 @@ pub use session::ClientSessionManager;
 pub(crate) use session::SessionClaim;
 pub use stage::upload_to_stage;
 pub use stage::UploadToStageResponse;
+pub use streaming_load::streaming_load;
+pub use streaming_load::StreamingLoadResponse;
 pub use suggestions::list_suggestions;
 pub use suggestions::SuggestionsResponse;
 pub use verify::verify_handler;
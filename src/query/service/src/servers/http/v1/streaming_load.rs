@@ -0,0 +1,397 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CopyIntoTableStmt;
+use databend_common_ast::ast::FileFormatOptions;
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableRef;
+use databend_common_ast::parser::parse_file_format_options;
+use databend_common_ast::parser::parse_table_name;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_base::runtime::spawn_blocking;
+use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::plan::RemoteDefaultExpr;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RemoteExpr;
+use databend_common_meta_app::principal::CopyOptions;
+use databend_common_meta_app::principal::FileFormatOptionsReader;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::OnErrorMode;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::executor::physical_plans::MutationKind;
+use databend_common_sql::field_default_value;
+use databend_common_sql::parse_sequence_default_expr;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_storage::StageFilesInfo;
+use databend_common_storages_stage::StageTable;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+use log::info;
+use poem::error::BadRequest;
+use poem::error::InternalServerError;
+use poem::error::Result as PoemResult;
+use poem::web::Json;
+use poem::Body;
+use poem::Request;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::HttpQueryContext;
+use crate::interpreters::Accessor;
+use crate::interpreters::HookOperator;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+// Bodies that can not be decoded as a stream are spooled under this directory of the user stage.
+const STREAMING_LOAD_DIR: &str = ".streaming_load";
+
+// Reported as the file name in the errors of malformed rows.
+const STREAMING_LOAD_FILE: &str = "request body";
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LoadStats {
+    pub rows: usize,
+    pub bytes: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StreamingLoadResponse {
+    pub id: String,
+    pub state: String,
+    pub stats: LoadStats,
+}
+
+#[derive(Debug)]
+struct StreamingLoadArgs {
+    catalog: Option<Identifier>,
+    database: Option<Identifier>,
+    table: Identifier,
+    file_format: FileFormatOptions,
+    on_error: OnErrorMode,
+}
+
+impl StreamingLoadArgs {
+    pub fn parse(req: &Request, ctx: &QueryContext) -> Result<StreamingLoadArgs> {
+        let dialect = ctx.get_settings().get_sql_dialect()?;
+
+        let table = Self::read_arg(req, "table").ok_or_else(|| {
+            ErrorCode::BadArguments("Parse table error, please set the header X-Databend-Table")
+        })?;
+        let (catalog, database, table) =
+            parse_table_name(&tokenize_sql(table)?, dialect).map_err(|e| {
+                ErrorCode::BadArguments(format!("Invalid table name in X-Databend-Table: {e}"))
+            })?;
+
+        // e.g. `type = CSV field_delimiter = ',' skip_header = 1 compression = GZIP`
+        let file_format = Self::read_arg(req, "file-format").unwrap_or("type = CSV");
+        let file_format =
+            parse_file_format_options(&tokenize_sql(file_format)?, dialect).map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "Invalid file format options in X-Databend-File-Format: {e}"
+                ))
+            })?;
+
+        // e.g. `continue` to skip the malformed rows, or `abort_10` to tolerate 9 of them
+        let on_error = match Self::read_arg(req, "on-error") {
+            Some(v) => OnErrorMode::from_str(v).map_err(ErrorCode::BadArguments)?,
+            None => OnErrorMode::default(),
+        };
+
+        Ok(StreamingLoadArgs {
+            catalog,
+            database,
+            table,
+            file_format,
+            on_error,
+        })
+    }
+
+    fn read_arg<'a>(req: &'a Request, name: &str) -> Option<&'a str> {
+        req.headers()
+            .get(format!("x-databend-{}", name).as_str())
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+    }
+
+    async fn file_format_params(&self, ctx: &QueryContext) -> Result<FileFormatParams> {
+        let reader = FileFormatOptionsReader::from_ast(&self.file_format);
+        match reader.options.get("format_name") {
+            Some(name) => ctx.get_file_format(name).await,
+            None => FileFormatParams::try_from_reader(reader, false),
+        }
+    }
+}
+
+/// Load the request body into a table.
+///
+/// CSV, TSV and NDJSON bodies are decoded into blocks while they are received and
+/// appended to the table in one commit. Formats which need the whole file to decode,
+/// e.g. Parquet, are written to the user stage first and loaded with `COPY INTO`.
+/// Malformed rows are handled as `X-Databend-On-Error`, which defaults to `abort`.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn streaming_load(
+    ctx: &HttpQueryContext,
+    req: &Request,
+    body: Body,
+) -> PoemResult<Json<StreamingLoadResponse>> {
+    let session = ctx.upgrade_session(SessionType::HTTPStreamingLoad)?;
+    let context = session
+        .create_query_context()
+        .await
+        .map_err(InternalServerError)?;
+    let args = StreamingLoadArgs::parse(req, &context).map_err(BadRequest)?;
+
+    let bytes = match args.file_format_params(&context).await {
+        Ok(
+            params @ (FileFormatParams::Csv(_)
+            | FileFormatParams::Tsv(_)
+            | FileFormatParams::NdJson(_)),
+        ) => load_stream(context.clone(), &args, params, body).await,
+        Ok(_) => load_spooled(context.clone(), &args, body).await,
+        Err(cause) => Err(cause),
+    }
+    .map_err(|cause| match cause.code() {
+        ErrorCode::INTERNAL => InternalServerError(cause),
+        _ => BadRequest(cause),
+    })?;
+
+    Ok(Json(StreamingLoadResponse {
+        id: context.get_id(),
+        state: "SUCCESS".to_string(),
+        stats: LoadStats {
+            rows: context.get_write_progress_value().rows,
+            bytes,
+        },
+    }))
+}
+
+/// Decode the body as it arrives and append the rows to the table.
+async fn load_stream(
+    ctx: Arc<QueryContext>,
+    args: &StreamingLoadArgs,
+    file_format_params: FileFormatParams,
+    body: Body,
+) -> Result<usize> {
+    // Bind the table as the target of an INSERT, which also checks the privilege.
+    let plan = Planner::new(ctx.clone())
+        .plan_stmt(&Statement::Insert(InsertStmt {
+            hints: None,
+            with: None,
+            catalog: args.catalog.clone(),
+            database: args.database.clone(),
+            table: args.table.clone(),
+            columns: vec![],
+            source: InsertSource::Values { rows: vec![] },
+            overwrite: false,
+        }))
+        .await?;
+    Accessor::create(ctx.clone()).check(&plan).await?;
+    let Plan::Insert(insert) = &plan else {
+        return Err(ErrorCode::Internal(
+            "Bug: binding the target of streaming load should return an insert plan.",
+        ));
+    };
+
+    let table = ctx
+        .get_table(&insert.catalog, &insert.database, &insert.table)
+        .await?;
+    table.check_mutable()?;
+    ctx.set_read_block_thresholds(table.get_block_thresholds());
+
+    let stage_table_info = stage_table_info(ctx.clone(), table.as_ref(), file_format_params, args)?;
+    let source_schema: DataSchemaRef = Arc::new(stage_table_info.schema.as_ref().into());
+    let stream = body
+        .into_bytes_stream()
+        .map_ok(|chunk| chunk.to_vec())
+        .map_err(ErrorCode::from)
+        .boxed();
+
+    let mut pipeline = Pipeline::create();
+    StageTable::read_stream(
+        ctx.clone(),
+        &stage_table_info,
+        stream,
+        STREAMING_LOAD_FILE.to_string(),
+        &mut pipeline,
+    )?;
+    PipelineBuilder::build_append2table_with_commit_pipeline(
+        ctx.clone(),
+        &mut pipeline,
+        table,
+        source_schema,
+        None,
+        vec![],
+        false,
+        unsafe { ctx.get_settings().get_deduplicate_label()? },
+    )?;
+    HookOperator::create(
+        ctx.clone(),
+        insert.catalog.clone(),
+        insert.database.clone(),
+        insert.table.clone(),
+        MutationKind::Insert,
+        LockTableOption::LockNoRetry,
+    )
+    .execute(&mut pipeline)
+    .await;
+
+    pipeline.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
+    let executor =
+        PipelineCompleteExecutor::try_create(pipeline, ExecutorSettings::try_create(ctx.clone())?)?;
+    ctx.set_executor(executor.get_inner())?;
+    // The body is polled by the pipeline, so the executor must not block the task of the request.
+    spawn_blocking(move || executor.execute())
+        .await
+        .map_err(|e| ErrorCode::TokioError(e.to_string()))??;
+
+    info!(
+        "streaming load into {}: {:?}",
+        insert.table,
+        ctx.get_write_progress_value()
+    );
+    Ok(ctx.get_scan_progress_value().bytes)
+}
+
+fn stage_table_info(
+    ctx: Arc<QueryContext>,
+    table: &dyn Table,
+    file_format_params: FileFormatParams,
+    args: &StreamingLoadArgs,
+) -> Result<StageTableInfo> {
+    let schema = table.schema().remove_computed_fields();
+    let default_values = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let sequence = match field.default_expr() {
+                Some(default_expr) => parse_sequence_default_expr(default_expr)?,
+                None => None,
+            };
+            Ok(match sequence {
+                Some(sequence) => RemoteDefaultExpr::Sequence(sequence),
+                None => RemoteDefaultExpr::RemoteExpr(RemoteExpr::Constant {
+                    span: None,
+                    scalar: field_default_value(ctx.clone(), field)?,
+                    data_type: field.data_type().into(),
+                }),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(StageTableInfo {
+        schema: Arc::new(schema),
+        default_values: Some(default_values),
+        files_info: StageFilesInfo {
+            path: STREAMING_LOAD_FILE.to_string(),
+            files: None,
+            pattern: None,
+        },
+        stage_info: StageInfo {
+            file_format_params,
+            copy_options: CopyOptions {
+                on_error: args.on_error.clone(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        files_to_copy: None,
+        duplicated_files_detected: vec![],
+        is_select: false,
+        copy_into_location_options: Default::default(),
+    })
+}
+
+/// Write the body to the user stage, then load it with `COPY INTO <table>`.
+async fn load_spooled(
+    ctx: Arc<QueryContext>,
+    args: &StreamingLoadArgs,
+    body: Body,
+) -> Result<usize> {
+    let user = ctx.get_current_user()?;
+    let stage = StageInfo::new_user_stage(user.name.as_str());
+    let op = StageTable::get_op(&stage)?;
+
+    let path = format!("{}/{}", STREAMING_LOAD_DIR, ctx.get_id());
+    let mut writer = op.writer(&path).await?;
+    let mut bytes = 0;
+    let mut body = body.into_bytes_stream();
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk?;
+        bytes += chunk.len();
+        writer.write(chunk).await?;
+    }
+    writer.close().await?;
+
+    let stmt = Statement::CopyIntoTable(CopyIntoTableStmt {
+        with: None,
+        src: CopyIntoTableSource::Location(FileLocation::Stage(format!("~/{path}"))),
+        dst: TableRef {
+            catalog: args.catalog.clone(),
+            database: args.database.clone(),
+            table: args.table.clone(),
+            with_options: None,
+        },
+        dst_columns: None,
+        hints: None,
+        file_format: args.file_format.clone(),
+        files: None,
+        pattern: None,
+        force: false,
+        validation_mode: String::new(),
+        size_limit: 0,
+        max_files: 0,
+        split_size: 0,
+        purge: true,
+        disable_variant_check: false,
+        return_failed_only: false,
+        on_error: args.on_error.to_string(),
+    });
+    info!("streaming load {} bytes: {}", bytes, stmt);
+
+    if let Err(cause) = execute_copy(ctx, &stmt).await {
+        // PURGE only removes the file after a successful load.
+        let _ = op.delete(&path).await;
+        return Err(cause);
+    }
+    Ok(bytes)
+}
+
+async fn execute_copy(ctx: Arc<QueryContext>, stmt: &Statement) -> Result<()> {
+    let plan = Planner::new(ctx.clone()).plan_stmt(stmt).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    let _ = stream.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
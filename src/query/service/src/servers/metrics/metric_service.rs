@@ -16,9 +16,11 @@ use std::net::SocketAddr;
 use std::time::Duration;
 
 use databend_common_base::runtime::metrics::GLOBAL_METRICS_REGISTRY;
+use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_exception::ErrorCode;
 use databend_common_http::HttpError;
 use databend_common_http::HttpShutdownHandler;
+use databend_common_metrics::system::set_system_memory_usage_bytes;
 use poem::IntoResponse;
 
 use crate::servers::Server;
@@ -31,6 +33,8 @@ pub struct MetricService {
 #[poem::handler]
 #[async_backtrace::framed]
 pub async fn metrics_handler() -> impl IntoResponse {
+    set_system_memory_usage_bytes(GLOBAL_MEM_STAT.get_memory_usage());
+
     GLOBAL_METRICS_REGISTRY
         .render_metrics()
         .unwrap_or_else(|e| e.message())
@@ -24,6 +24,7 @@ pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLFederated;
 pub use self::mysql::MySQLHandler;
 pub use self::mysql::MySQLTlsConfig;
+pub use self::mysql::QueryTags;
 
 pub mod admin;
 pub(crate) mod federated_helper;
@@ -16,6 +16,8 @@ mod mysql_federated;
 mod mysql_handler;
 mod mysql_interactive_worker;
 mod mysql_session;
+mod prepared_statement;
+mod query_tags;
 #[allow(clippy::unused_io_amount)]
 mod reject_connection;
 mod tls;
@@ -24,6 +26,7 @@ mod writers;
 pub use self::mysql_federated::MySQLFederated;
 pub use self::mysql_handler::MySQLHandler;
 pub use self::mysql_session::MySQLConnection;
+pub use self::query_tags::QueryTags;
 pub use self::tls::MySQLTlsConfig;
 
 const MYSQL_VERSION: &str = "8.0.26";
@@ -16,6 +16,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
+use databend_common_config::DATABEND_COMMIT_VERSION;
+use databend_common_exception::Result;
 use databend_common_expression::types::StringType;
 use databend_common_expression::utils::FromData;
 use databend_common_expression::DataBlock;
@@ -25,16 +27,45 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_settings::Settings;
+use log::info;
 use regex::Regex;
 
 use crate::servers::federated_helper::FederatedHelper;
-use crate::servers::federated_helper::LazyBlockFunc;
+use crate::servers::mysql::MYSQL_VERSION;
 
-pub struct MySQLFederated {}
+// MySQL system variables which have a Databend setting with a different name.
+const VARIABLE_SETTING_ALIASES: &[(&str, &str)] = &[("time_zone", "timezone")];
+
+pub struct MySQLFederated {
+    settings: Option<Arc<Settings>>,
+}
 
 impl MySQLFederated {
     pub fn create() -> Self {
-        MySQLFederated {}
+        MySQLFederated { settings: None }
+    }
+
+    // Variables are resolved against (and SET applied to) the session settings.
+    pub fn create_with_settings(settings: Arc<Settings>) -> Self {
+        MySQLFederated {
+            settings: Some(settings),
+        }
+    }
+
+    // Strip the scope prefix and map the MySQL variable name to the setting name.
+    fn variable_to_setting(var: &str) -> String {
+        let var = var.trim().to_lowercase();
+        let var = var
+            .strip_prefix("session.")
+            .or_else(|| var.strip_prefix("local."))
+            .unwrap_or(&var);
+
+        VARIABLE_SETTING_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == var)
+            .map(|(_, setting)| setting.to_string())
+            .unwrap_or_else(|| var.to_string())
     }
 
     // Build block for select function.
@@ -66,8 +97,12 @@ impl MySQLFederated {
 
     // SELECT @@aa, @@bb as cc, @dd...
     // Block is built by the variables.
-    fn select_variable_data_block(query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+    fn select_variable_data_block(&self, query: &str) -> (TableSchemaRef, DataBlock) {
         let mut default_map = HashMap::new();
+        let version_comment = format!("Databend Query {}", *DATABEND_COMMIT_VERSION);
+        let version = format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION);
+        default_map.insert("version_comment", version_comment.as_str());
+        default_map.insert("version", version.as_str());
         // DBeaver.
         default_map.insert("tx_isolation", "REPEATABLE-READ");
         default_map.insert("session.tx_isolation", "REPEATABLE-READ");
@@ -82,6 +117,21 @@ impl MySQLFederated {
         default_map.insert("wait_timeout", "31536000");
         default_map.insert("net_write_timeout", "31536000");
 
+        // Known variables are read from the session settings.
+        let setting_values: HashMap<String, String> = match &self.settings {
+            None => HashMap::new(),
+            Some(settings) => settings
+                .into_iter()
+                .map(|item| (item.name, item.user_value.as_string()))
+                .collect(),
+        };
+        let lookup = |var: &str| -> String {
+            match setting_values.get(&Self::variable_to_setting(var)) {
+                Some(value) => value.clone(),
+                None => default_map.get(var).unwrap_or(&"0").to_string(),
+            }
+        };
+
         let mut fields = vec![];
         let mut values = vec![];
 
@@ -91,7 +141,7 @@ impl MySQLFederated {
         if vars.len() > 1 {
             vars.remove(0);
             for var in vars {
-                let var = var.trim_end_matches([' ', ',']);
+                let var = var.trim_end_matches([' ', ',', ';']);
                 let vars_as: Vec<&str> = var.split(" as ").collect();
                 if vars_as.len() == 2 {
                     // @@cc as yy:
@@ -101,44 +151,110 @@ impl MySQLFederated {
 
                     // var is 'cc'.
                     let var = vars_as[0];
-                    let value = default_map.get(var).unwrap_or(&"0").to_string();
-                    values.push(StringType::from_data(vec![value]));
+                    values.push(StringType::from_data(vec![lookup(var)]));
                 } else {
-                    // @@aa
+                    // @@aa [limit 1]
                     // var is 'aa'
+                    let var = var.split_whitespace().next().unwrap_or_default();
                     fields.push(TableField::new(
                         &format!("@@{}", var),
                         TableDataType::String,
                     ));
 
-                    let value = default_map.get(var).unwrap_or(&"0").to_string();
-                    values.push(StringType::from_data(vec![value]));
+                    values.push(StringType::from_data(vec![lookup(var)]));
                 }
             }
         }
 
         let schema = TableSchemaRefExt::create(fields);
         let block = DataBlock::new_from_columns(values);
-        Some((schema, block))
+        (schema, block)
     }
 
     // Check SELECT @@variable, @@variable
     fn federated_select_variable_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
-        static SELECT_VARIABLES_LAZY_RULES: LazyLock<Vec<(Regex, LazyBlockFunc)>> =
-            LazyLock::new(|| {
-                vec![
-                    (
-                        Regex::new("(?i)^(SELECT @@(.*))").unwrap(),
-                        MySQLFederated::select_variable_data_block,
-                    ),
-                    (
-                        Regex::new("(?i)^(/\\* mysql-connector-java(.*))").unwrap(),
-                        MySQLFederated::select_variable_data_block,
-                    ),
-                ]
-            });
+        static SELECT_VARIABLES_RULES: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+            vec![
+                Regex::new("(?i)^(SELECT @@(.*))").unwrap(),
+                Regex::new("(?i)^(/\\* mysql-connector-java(.*))").unwrap(),
+            ]
+        });
+
+        SELECT_VARIABLES_RULES
+            .iter()
+            .any(|regex| regex.is_match(query))
+            .then(|| self.select_variable_data_block(query))
+    }
+
+    // Split `a = 1, b = 'x,y'` into assignments, keeping commas inside quotes.
+    fn split_assignments(assignments: &str) -> Vec<(String, String)> {
+        let mut parts = vec![];
+        let mut current = String::new();
+        let mut quote = None;
+        for c in assignments.chars() {
+            match (quote, c) {
+                (None, '\'' | '"') => {
+                    quote = Some(c);
+                    current.push(c);
+                }
+                (Some(q), _) if q == c => {
+                    quote = None;
+                    current.push(c);
+                }
+                (None, ',') => parts.push(std::mem::take(&mut current)),
+                _ => current.push(c),
+            }
+        }
+        parts.push(current);
+
+        parts
+            .iter()
+            .filter_map(|part| {
+                let (var, value) = part.split_once('=')?;
+                let var = var.trim().trim_start_matches("@@");
+                let value = value.trim().trim_matches(|c| c == '\'' || c == '"');
+                Some((var.to_string(), value.to_string()))
+            })
+            .collect()
+    }
+
+    // Check SET SESSION var = value / SET @@var = value.
+    // Variables known to the settings registry are applied to the session, unknown ones
+    // are accepted and ignored so that client handshakes can complete.
+    fn federated_set_variable_check(
+        &self,
+        query: &str,
+    ) -> Result<Option<(TableSchemaRef, DataBlock)>> {
+        static SET_VARIABLE_RULE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(
+                "(?is)^SET\\s+(SESSION\\s+|LOCAL\\s+)?(@@.*)$|^SET\\s+(SESSION|LOCAL)\\s+(.*)$",
+            )
+            .unwrap()
+        });
+
+        let Some(captures) = SET_VARIABLE_RULE.captures(query.trim().trim_end_matches(';')) else {
+            return Ok(None);
+        };
+        let empty_block = Some((TableSchemaRefExt::create(vec![]), DataBlock::empty()));
+        let Some(settings) = &self.settings else {
+            return Ok(empty_block);
+        };
 
-        FederatedHelper::lazy_block_match_rule(query, &SELECT_VARIABLES_LAZY_RULES)
+        let assignments = captures
+            .get(2)
+            .or_else(|| captures.get(4))
+            .map(|m| m.as_str())
+            .unwrap_or_default();
+        for (var, value) in Self::split_assignments(assignments) {
+            let setting = Self::variable_to_setting(&var);
+            if settings.has_setting(&setting)? {
+                settings.set_setting(setting, value)?;
+            } else {
+                info!("Ignore unknown MySQL session variable: {}", var);
+            }
+        }
+
+        Ok(empty_block)
     }
 
     // Check SHOW VARIABLES LIKE.
@@ -190,7 +306,6 @@ impl MySQLFederated {
             (Regex::new("(?i)^(SET SQL_LOG_BIN(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET sql_mode(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET SQL_SELECT_LIMIT(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET @@(.*))").unwrap(), None),
             // Now databend not support charset and collation
             // https://github.com/datafuselabs/databend/issues/5853
             (Regex::new("(?i)^(SHOW COLLATION)").unwrap(), None),
@@ -201,7 +316,6 @@ impl MySQLFederated {
                 MySQLFederated::select_function_block("TIMEDIFF(NOW(), UTC_TIMESTAMP())", "00:00:00"),
             ),
             // mysqldump.
-            (Regex::new("(?i)^(SET SESSION(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET SQL_QUOTE_SHOW_CREATE(.*))").unwrap(), None),
             (Regex::new("(?i)^(LOCK TABLES(.*))").unwrap(), None),
             (Regex::new("(?i)^(UNLOCK TABLES(.*))").unwrap(), None),
@@ -251,13 +365,21 @@ impl MySQLFederated {
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
-    pub fn check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
-        // First to check the select @@variables.
+    pub fn check(&self, query: &str) -> Result<Option<(DataSchemaRef, DataBlock)>> {
+        // First to apply the set session variables.
+        let set_variable = self
+            .federated_set_variable_check(query)?
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
+        if set_variable.is_some() {
+            return Ok(set_variable);
+        }
+
+        // Then to check the select @@variables.
         let select_variable = self
             .federated_select_variable_check(query)
             .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
         if select_variable.is_some() {
-            return select_variable;
+            return Ok(select_variable);
         }
 
         // Then to check the show variables like ''.
@@ -265,11 +387,12 @@ impl MySQLFederated {
             .federated_show_variables_check(query)
             .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
         if show_variables.is_some() {
-            return show_variables;
+            return Ok(show_variables);
         }
 
         // Last check.
-        self.federated_mixed_check(query)
-            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk))
+        Ok(self
+            .federated_mixed_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk)))
     }
 }
@@ -21,8 +21,10 @@ use databend_common_base::base::tokio::net::TcpStream;
 use databend_common_base::base::tokio::task::JoinHandle;
 use databend_common_base::runtime::Runtime;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_users::check_client_ip_allowed;
 use futures::future::AbortHandle;
 use futures::future::AbortRegistration;
 use futures::future::Abortable;
@@ -110,6 +112,13 @@ impl MySQLHandler {
         tls: Option<Arc<ServerConfig>>,
     ) {
         executor.spawn(async move {
+            let client_ip = socket.peer_addr().ok().map(|addr| addr.ip());
+            let allowed_ip_list = &GlobalConfig::instance().query.mysql_handler_allowed_ip_list;
+            if let Err(error) = check_client_ip_allowed(allowed_ip_list, client_ip) {
+                warn!("reject MySQL connection, {:?}", error);
+                return Self::reject_session(socket, error).await;
+            }
+
             match session_manager.create_session(SessionType::MySQL).await {
                 Err(error) => {
                     warn!("create session failed, {:?}", error);
@@ -144,6 +153,7 @@ impl MySQLHandler {
     async fn reject_session(stream: TcpStream, error: ErrorCode) {
         let (kind, message) = match error.code() {
             41 => (ErrorKind::ER_TOO_MANY_USER_CONNECTIONS, error.message()),
+            ErrorCode::AUTHENTICATE_FAILURE => (ErrorKind::ER_HOST_NOT_PRIVILEGED, error.message()),
             _ => (ErrorKind::ER_INTERNAL_ERROR, error.message()),
         };
 
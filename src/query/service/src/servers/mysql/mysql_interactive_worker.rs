@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -39,6 +40,9 @@ use futures_util::StreamExt;
 use log::error;
 use log::info;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
@@ -48,21 +52,30 @@ use rand::RngCore;
 use uuid::Uuid;
 
 use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::AuditLog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
+use crate::servers::mysql::prepared_statement::PreparedStatement;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
 use crate::servers::mysql::writers::QueryResult;
 use crate::servers::mysql::MySQLFederated;
+use crate::servers::mysql::QueryTags;
 use crate::servers::mysql::MYSQL_VERSION;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
 use crate::sessions::TableContext;
 use crate::stream::DataBlockStream;
 
+// The prepared statements kept by a connection, the same as the default
+// `max_prepared_stmt_count` of MySQL.
+const MAX_PREPARED_STATEMENTS: usize = 16382;
+
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    statements: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
 }
 
 pub struct InteractiveWorker {
@@ -117,8 +130,13 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         let info = CertifiedInfo::create(&username, auth_data, &client_addr);
 
         let authenticate = self.base.authenticate(salt, info);
+        let tenant = self.base.session.get_current_tenant();
         match authenticate.await {
-            Ok(res) => res,
+            Ok(res) => {
+                let err = (!res).then(|| ErrorCode::AuthenticateFailure("wrong password"));
+                AuditLog::log_login(&tenant, &username, &client_addr, err.as_ref());
+                res
+            }
             Err(failure) => {
                 error!(
                     "MySQL handler authenticate failed, \
@@ -127,6 +145,7 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
                         failure_cause: {}",
                     username, client_addr, failure
                 );
+                AuditLog::log_login(&tenant, &username, &client_addr, Some(&failure));
                 false
             }
         }
@@ -174,7 +193,16 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
             ));
         }
 
-        self.base.do_execute(id, param, writer).await
+        let query = match self.base.do_execute(id, param) {
+            Ok(query) => query,
+            Err(error) => {
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+                    .await?;
+                return Ok(());
+            }
+        };
+        self.run_query(&query, writer, true).await
     }
 
     /// https://dev.mysql.com/doc/internals/en/com-stmt-close.html
@@ -190,55 +218,7 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         query: &'a str,
         writer: QueryResultWriter<'a, W>,
     ) -> Result<()> {
-        let query_id = Uuid::new_v4().to_string();
-        let root = Span::root(func_path!(), SpanContext::random())
-            .with_properties(|| self.base.session.to_fastrace_properties());
-
-        let mut tracking_payload = ThreadTracker::new_tracking_payload();
-        tracking_payload.query_id = Some(query_id.clone());
-        let _guard = ThreadTracker::tracking(tracking_payload);
-
-        ThreadTracker::tracking_future(async {
-            if self.base.session.is_aborting() {
-                writer
-                    .error(
-                        ErrorKind::ER_ABORTING_CONNECTION,
-                        "Aborting this connection. because we are try aborting server.".as_bytes(),
-                    )
-                    .await?;
-
-                return Err(ErrorCode::AbortedSession(
-                    "Aborting this connection. because we are try aborting server.",
-                ));
-            }
-
-            let mut writer = DFQueryResultWriter::create(writer, self.base.session.clone());
-            if !self.keep_alive_task_started {
-                self.start_keep_alive().await
-            }
-
-            let instant = Instant::now();
-            let query_result = self
-                .base
-                .do_query(query_id, query)
-                .await
-                .map_err(|err| err.display_with_sql(query));
-
-            let format = self.base.session.get_format_settings();
-
-            let mut write_result = writer.write(query_result, &format).await;
-
-            if let Err(cause) = write_result {
-                self.base.session.txn_mgr().lock().set_fail();
-                let suffix = format!("(while in query {})", query);
-                write_result = Err(cause.add_message_back(suffix));
-            }
-            observe_mysql_process_request_duration(instant.elapsed());
-
-            write_result
-        })
-        .in_span(root)
-        .await
+        self.run_query(query, writer, false).await
     }
 
     #[async_backtrace::framed]
@@ -297,40 +277,72 @@ impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn do_prepare<W: AsyncWrite + Unpin>(
         &mut self,
-        _: &str,
+        query: &str,
         writer: StatementMetaWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+        if self.statements.len() >= MAX_PREPARED_STATEMENTS {
+            let message = format!(
+                "Can't create more than {} prepared statements in a connection",
+                MAX_PREPARED_STATEMENTS
+            );
+            writer
+                .error(
+                    ErrorKind::ER_MAX_PREPARED_STMT_COUNT_REACHED,
+                    message.as_bytes(),
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let statement = match PreparedStatement::try_create(query) {
+            Ok(statement) => statement,
+            Err(error) => {
+                writer
+                    .error(ErrorKind::ER_PARSE_ERROR, error.to_string().as_bytes())
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        // The parameters are sent as strings, and the columns are described
+        // by the result set of the execution.
+        let params = (0..statement.num_params())
+            .map(|_| Column {
+                table: "".to_string(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            })
+            .collect::<Vec<_>>();
+
+        self.next_statement_id += 1;
+        let id = self.next_statement_id;
+        self.statements.insert(id, statement);
+        writer.reply(id, &params, &[]).await?;
         Ok(())
     }
 
-    #[async_backtrace::framed]
-    async fn do_execute<W: AsyncWrite + Unpin>(
-        &mut self,
-        _: u32,
-        _: ParamParser<'_>,
-        writer: QueryResultWriter<'_, W>,
-    ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+    fn do_execute(&mut self, id: u32, params: ParamParser<'_>) -> Result<String> {
+        match self.statements.get(&id) {
+            Some(statement) => statement.bind(params),
+            None => Err(ErrorCode::BadArguments(format!(
+                "Unknown prepared statement id {}",
+                id
+            ))),
+        }
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
-    fn federated_server_command_check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+    fn federated_server_command_check(
+        &self,
+        query: &str,
+    ) -> Result<Option<(DataSchemaRef, DataBlock)>> {
         // INSERT don't need MySQL federated check
         // Ensure the query is start with ASCII chars so we won't
         // panic when we slice the query string.
@@ -338,9 +350,9 @@ impl InteractiveWorkerBase {
             && query.char_indices().take(6).all(|(_, c)| c.is_ascii())
             && query[..6].eq_ignore_ascii_case("INSERT")
         {
-            return None;
+            return Ok(None);
         }
-        let federated = MySQLFederated::create();
+        let federated = MySQLFederated::create_with_settings(self.session.get_settings());
         federated.check(query)
     }
 
@@ -351,7 +363,7 @@ impl InteractiveWorkerBase {
         query_id: String,
         query: &str,
     ) -> Result<(QueryResult, Option<FormatSettings>)> {
-        match self.federated_server_command_check(query) {
+        match self.federated_server_command_check(query)? {
             Some((schema, data_block)) => {
                 info!("Federated query: {}", query);
                 if data_block.num_rows() > 0 {
@@ -472,7 +484,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                statements: HashMap::new(),
+                next_statement_id: 0,
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
@@ -480,6 +496,71 @@ impl InteractiveWorker {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn run_query<W: AsyncWrite + Send + Sync + Unpin>(
+        &mut self,
+        query: &str,
+        writer: QueryResultWriter<'_, W>,
+        binary: bool,
+    ) -> Result<()> {
+        // The client may supply the query id and the trace parent in a comment.
+        let tags = QueryTags::parse(query);
+        let query_id = tags.query_id.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let span_context = tags
+            .traceparent
+            .as_deref()
+            .and_then(SpanContext::decode_w3c_traceparent)
+            .unwrap_or_else(SpanContext::random);
+        let root = Span::root(func_path!(), span_context)
+            .with_properties(|| self.base.session.to_fastrace_properties());
+
+        let mut tracking_payload = ThreadTracker::new_tracking_payload();
+        tracking_payload.query_id = Some(query_id.clone());
+        let _guard = ThreadTracker::tracking(tracking_payload);
+
+        ThreadTracker::tracking_future(async {
+            if self.base.session.is_aborting() {
+                writer
+                    .error(
+                        ErrorKind::ER_ABORTING_CONNECTION,
+                        "Aborting this connection. because we are try aborting server.".as_bytes(),
+                    )
+                    .await?;
+
+                return Err(ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                ));
+            }
+
+            let mut writer = DFQueryResultWriter::create(writer, self.base.session.clone(), binary);
+            if !self.keep_alive_task_started {
+                self.start_keep_alive().await
+            }
+
+            let instant = Instant::now();
+            let query_result = self
+                .base
+                .do_query(query_id, query)
+                .await
+                .map_err(|err| err.display_with_sql(query));
+
+            let format = self.base.session.get_format_settings();
+
+            let mut write_result = writer.write(query_result, &format).await;
+
+            if let Err(cause) = write_result {
+                self.base.session.txn_mgr().lock().set_fail();
+                let suffix = format!("(while in query {})", query);
+                write_result = Err(cause.add_message_back(suffix));
+            }
+            observe_mysql_process_request_duration(instant.elapsed());
+
+            write_result
+        })
+        .in_span(root)
+        .await
+    }
+
     async fn start_keep_alive(&mut self) {
         let session = &self.base.session;
         let tenant = session.get_current_tenant();
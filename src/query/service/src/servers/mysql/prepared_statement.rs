@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::token::Tokenizer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use opensrv_mysql::ParamParser;
+use opensrv_mysql::ValueInner;
+
+/// A statement prepared by `COM_STMT_PREPARE`.
+///
+/// Databend has no server side prepared plan, the `?` placeholders of the query are
+/// replaced with the literals of the parameters on `COM_STMT_EXECUTE`.
+pub struct PreparedStatement {
+    /// The query split at the placeholders.
+    fragments: Vec<String>,
+}
+
+impl PreparedStatement {
+    pub fn try_create(query: &str) -> Result<PreparedStatement> {
+        let mut fragments = vec![];
+        let mut start = 0;
+        for token in Tokenizer::new(query) {
+            let token = token?;
+            if token.kind == TokenKind::Placeholder {
+                fragments.push(query[start..token.span.start()].to_string());
+                start = token.span.end();
+            }
+        }
+        fragments.push(query[start..].to_string());
+        Ok(PreparedStatement { fragments })
+    }
+
+    pub fn num_params(&self) -> usize {
+        self.fragments.len() - 1
+    }
+
+    /// Build the query to execute with the parameters.
+    pub fn bind(&self, params: ParamParser<'_>) -> Result<String> {
+        let literals = params
+            .into_iter()
+            .map(|param| param_to_literal(param.value.into_inner()))
+            .collect::<Result<Vec<_>>>()?;
+        if literals.len() != self.num_params() {
+            return Err(ErrorCode::BadArguments(format!(
+                "The prepared statement expects {} parameters, but got {}",
+                self.num_params(),
+                literals.len()
+            )));
+        }
+
+        let mut query = self.fragments[0].clone();
+        for (literal, fragment) in literals.iter().zip(self.fragments[1..].iter()) {
+            query.push_str(literal);
+            query.push_str(fragment);
+        }
+        Ok(query)
+    }
+}
+
+fn param_to_literal(value: ValueInner<'_>) -> Result<String> {
+    Ok(match value {
+        ValueInner::NULL => "NULL".to_string(),
+        ValueInner::Int(v) => v.to_string(),
+        ValueInner::UInt(v) => v.to_string(),
+        ValueInner::Double(v) if v.is_finite() => format!("{:?}", v),
+        ValueInner::Double(v) => format!("'{}'::DOUBLE", v),
+        ValueInner::Bytes(v) => match std::str::from_utf8(v) {
+            Ok(s) => format!("'{}'", s.replace('\\', "\\\\").replace('\'', "''")),
+            Err(_) => format!("FROM_HEX('{}')", hex::encode(v)),
+        },
+        ValueInner::Date(v) => {
+            let (year, month, day) = date_fields(v);
+            format!("'{:04}-{:02}-{:02}'", year, month, day)
+        }
+        ValueInner::Datetime(v) => {
+            let (year, month, day) = date_fields(v);
+            let (hour, minute, second, micros) = time_fields(v.get(4..).unwrap_or_default());
+            format!(
+                "'{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06}'",
+                year, month, day, hour, minute, second, micros
+            )
+        }
+        ValueInner::Time(v) => {
+            // is_negative(1) days(4) hour(1) minute(1) second(1) micro_second(4)
+            let negative = v.first().copied().unwrap_or_default() == 1;
+            let days = v
+                .get(1..5)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .unwrap_or_default();
+            let (hour, minute, second, micros) = time_fields(v.get(5..).unwrap_or_default());
+            let hours = days
+                .checked_mul(24)
+                .and_then(|h| h.checked_add(hour as u32))
+                .ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "The time parameter of {} days is out of range",
+                        days
+                    ))
+                })?;
+            format!(
+                "'{}{:02}:{:02}:{:02}.{:06}'",
+                if negative { "-" } else { "" },
+                hours,
+                minute,
+                second,
+                micros
+            )
+        }
+    })
+}
+
+/// year(2) month(1) day(1), the fields absent are zeros.
+fn date_fields(v: &[u8]) -> (u16, u8, u8) {
+    let byte = |i: usize| v.get(i).copied().unwrap_or_default();
+    (u16::from_le_bytes([byte(0), byte(1)]), byte(2), byte(3))
+}
+
+/// hour(1) minute(1) second(1) micro_second(4), the fields absent are zeros.
+fn time_fields(v: &[u8]) -> (u8, u8, u8, u32) {
+    let byte = |i: usize| v.get(i).copied().unwrap_or_default();
+    (
+        byte(0),
+        byte(1),
+        byte(2),
+        u32::from_le_bytes([byte(3), byte(4), byte(5), byte(6)]),
+    )
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+const MAX_QUERY_ID_LEN: usize = 128;
+
+/// The tags a MySQL client attaches to a query in a `sqlcommenter` style comment,
+/// to correlate the query with its own logs and traces, e.g.
+///
+/// `SELECT * FROM t /*query_id='my-query-1',traceparent='00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01'*/`
+///
+/// Unknown tags and hints (`/*+ ... */`) are ignored.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct QueryTags {
+    pub query_id: Option<String>,
+    pub traceparent: Option<String>,
+}
+
+impl QueryTags {
+    pub fn parse(query: &str) -> QueryTags {
+        let mut tags = QueryTags::default();
+        let mut rest = query;
+        while let Some(start) = rest.find("/*") {
+            let Some(len) = rest[start + 2..].find("*/") else {
+                break;
+            };
+            let comment = &rest[start + 2..start + 2 + len];
+            rest = &rest[start + 2 + len + 2..];
+
+            if comment.starts_with('+') {
+                continue;
+            }
+            for (key, value) in comment.split(',').filter_map(parse_tag) {
+                match key {
+                    "query_id" if is_valid_query_id(value) => {
+                        tags.query_id = Some(value.to_string())
+                    }
+                    "traceparent" => tags.traceparent = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+        }
+        tags
+    }
+}
+
+/// Parse `key='value'`.
+fn parse_tag(tag: &str) -> Option<(&str, &str)> {
+    let (key, value) = tag.split_once('=')?;
+    let value = value.trim().strip_prefix('\'')?.strip_suffix('\'')?;
+    Some((key.trim(), value))
+}
+
+/// A query id supplied by the client ends up in logs, headers and system tables,
+/// only accept the characters of a UUID or an identifier.
+fn is_valid_query_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_QUERY_ID_LEN
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
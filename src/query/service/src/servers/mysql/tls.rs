@@ -14,10 +14,13 @@
 
 use std::fs::File;
 use std::io::BufReader;
+use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use itertools::Itertools;
+use rustls::server::WebPkiClientVerifier;
+use rustls::RootCertStore;
 use rustls::ServerConfig;
 use rustls_pemfile::certs;
 use rustls_pemfile::pkcs8_private_keys;
@@ -28,6 +31,7 @@ use rustls_pki_types::PrivateKeyDer;
 pub struct MySQLTlsConfig {
     cert_path: String,
     key_path: String,
+    root_ca_path: String,
 }
 
 impl MySQLTlsConfig {
@@ -35,9 +39,16 @@ impl MySQLTlsConfig {
         Self {
             cert_path,
             key_path,
+            root_ca_path: String::new(),
         }
     }
 
+    /// Require clients to present a certificate signed by this CA.
+    pub fn with_root_ca(mut self, root_ca_path: String) -> Self {
+        self.root_ca_path = root_ca_path;
+        self
+    }
+
     fn enabled(&self) -> bool {
         !self.cert_path.is_empty() && !self.key_path.is_empty()
     }
@@ -73,8 +84,24 @@ impl MySQLTlsConfig {
             }
         };
 
-        let config = ServerConfig::builder()
-            .with_no_client_auth()
+        let builder = ServerConfig::builder();
+        let builder = if self.root_ca_path.is_empty() {
+            builder.with_no_client_auth()
+        } else {
+            let mut roots = RootCertStore::empty();
+            for ca in certs(&mut BufReader::new(File::open(&self.root_ca_path)?)) {
+                let ca = ca.map_err(|err| ErrorCode::TLSConfigurationFailure(err.to_string()))?;
+                roots
+                    .add(ca)
+                    .map_err(|err| ErrorCode::TLSConfigurationFailure(err.to_string()))?;
+            }
+            let verifier = WebPkiClientVerifier::builder(Arc::new(roots))
+                .build()
+                .map_err(|err| ErrorCode::TLSConfigurationFailure(err.to_string()))?;
+            builder.with_client_cert_verifier(verifier)
+        };
+
+        let config = builder
             .with_single_cert(cert, key)
             .map_err(|err| ErrorCode::TLSConfigurationFailure(err.to_string()))?;
 
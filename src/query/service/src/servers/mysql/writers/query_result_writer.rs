@@ -17,6 +17,7 @@ use std::sync::Arc;
 use databend_common_base::base::tokio::io::AsyncWrite;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::date_helper::DateConverter;
 use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
@@ -71,6 +72,8 @@ impl QueryResult {
 pub struct DFQueryResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<QueryResultWriter<'a, W>>,
     session: Arc<Session>,
+    /// Whether the rows are written in the binary protocol, for the prepared statements.
+    binary: bool,
 }
 
 fn write_field<W: AsyncWrite + Unpin>(
@@ -86,14 +89,83 @@ fn write_field<W: AsyncWrite + Unpin>(
     Ok(())
 }
 
+fn convert_field_type(field: &DataField) -> Result<ColumnType> {
+    match field.data_type().remove_nullable() {
+        DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
+        DataType::EmptyArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::EmptyMap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_TINY),
+        DataType::Binary => Ok(ColumnType::MYSQL_TYPE_BLOB),
+        DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 => Ok(ColumnType::MYSQL_TYPE_TINY),
+            NumberDataType::Int16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
+            NumberDataType::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+            NumberDataType::Int64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
+            NumberDataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_TINY),
+            NumberDataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
+            NumberDataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+            NumberDataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
+            NumberDataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+            NumberDataType::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
+        },
+        DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
+        DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+        DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Geometry => Ok(ColumnType::MYSQL_TYPE_GEOMETRY),
+        DataType::Geography => Ok(ColumnType::MYSQL_TYPE_GEOMETRY),
+        DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
+        _ => Err(ErrorCode::Unimplemented(format!(
+            "Unsupported column type:{:?}",
+            field.data_type()
+        ))),
+    }
+}
+
+fn convert_field_flags(field: &DataField) -> ColumnFlags {
+    let mut flags = ColumnFlags::empty();
+    if !field.data_type().is_nullable_or_null() {
+        flags |= ColumnFlags::NOT_NULL_FLAG;
+    }
+    match field.data_type().remove_nullable() {
+        DataType::Number(num_ty) if !num_ty.is_signed() => {
+            flags |= ColumnFlags::UNSIGNED_FLAG;
+        }
+        DataType::Binary => {
+            flags |= ColumnFlags::BINARY_FLAG | ColumnFlags::BLOB_FLAG;
+        }
+        _ => {}
+    }
+    flags
+}
+
+fn make_column_from_field(field: &DataField) -> Result<Column> {
+    convert_field_type(field).map(|column_type| Column {
+        table: "".to_string(),
+        column: field.name().to_string(),
+        coltype: column_type,
+        colflags: convert_field_flags(field),
+    })
+}
+
+fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
+    schema.fields().iter().map(make_column_from_field).collect()
+}
+
 impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
     pub fn create(
         inner: QueryResultWriter<'a, W>,
         session: Arc<Session>,
+        binary: bool,
     ) -> DFQueryResultWriter<'a, W> {
         DFQueryResultWriter::<'a, W> {
             inner: Some(inner),
             session,
+            binary,
         }
     }
 
@@ -161,57 +233,6 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
             return Ok(());
         }
 
-        fn convert_field_type(field: &DataField) -> Result<ColumnType> {
-            match field.data_type().remove_nullable() {
-                DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
-                DataType::EmptyArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::EmptyMap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                DataType::Binary => Ok(ColumnType::MYSQL_TYPE_BLOB),
-                DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Number(num_ty) => match num_ty {
-                    NumberDataType::Int8 => Ok(ColumnType::MYSQL_TYPE_TINY),
-                    NumberDataType::Int16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                    NumberDataType::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                    NumberDataType::Int64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
-                    NumberDataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_TINY),
-                    NumberDataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                    NumberDataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                    NumberDataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
-                    NumberDataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                    NumberDataType::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
-                },
-                DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
-                DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
-                DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Geometry => Ok(ColumnType::MYSQL_TYPE_GEOMETRY),
-                DataType::Geography => Ok(ColumnType::MYSQL_TYPE_GEOMETRY),
-                DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
-                _ => Err(ErrorCode::Unimplemented(format!(
-                    "Unsupported column type:{:?}",
-                    field.data_type()
-                ))),
-            }
-        }
-
-        fn make_column_from_field(field: &DataField) -> Result<Column> {
-            convert_field_type(field).map(|column_type| Column {
-                table: "".to_string(),
-                column: field.name().to_string(),
-                coltype: column_type,
-                colflags: ColumnFlags::empty(),
-            })
-        }
-
-        fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
-            schema.fields().iter().map(make_column_from_field).collect()
-        }
-
-        let _tz = format.timezone;
         match convert_schema(&query_result.schema) {
             Err(error) => self.err(&error, dataset_writer).await,
             Ok(columns) => {
@@ -256,7 +277,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                     row_writer.write_col(None::<u8>)?;
                                 }
                                 ScalarRef::Boolean(v) => {
-                                    row_writer.write_col(v as u8)?;
+                                    row_writer.write_col(v as i8)?;
                                 }
                                 ScalarRef::Number(number) => match number {
                                     NumberScalar::UInt8(v) => {
@@ -283,6 +304,12 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                     NumberScalar::Int64(v) => {
                                         row_writer.write_col(v)?;
                                     }
+                                    NumberScalar::Float32(v) if self.binary => {
+                                        row_writer.write_col(v.0)?;
+                                    }
+                                    NumberScalar::Float64(v) if self.binary => {
+                                        row_writer.write_col(v.0)?;
+                                    }
                                     _ => {
                                         write_field(
                                             &mut row_writer,
@@ -293,6 +320,14 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                         )?;
                                     }
                                 },
+                                // The binary protocol encodes the date and time by fields instead of text.
+                                ScalarRef::Date(v) if self.binary => {
+                                    row_writer.write_col(v.to_date(format.timezone))?;
+                                }
+                                ScalarRef::Timestamp(v) if self.binary => {
+                                    let ts = v.to_timestamp(format.timezone);
+                                    row_writer.write_col(ts.naive_local())?;
+                                }
                                 ScalarRef::Bitmap(_) => {
                                     let bitmap_result = "<bitmap binary>".as_bytes();
                                     row_writer.write_col(bitmap_result)?;
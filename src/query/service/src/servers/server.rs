@@ -68,13 +68,18 @@ impl ShutdownHandle {
         futures::future::join_all(shutdown_jobs).await;
     }
 
+    /// Stop accepting new sessions, leave the cluster, wait up to `timeout` for the
+    /// running queries to finish (then abort them), and close the services.
     #[async_backtrace::framed]
     pub async fn shutdown(&mut self, mut signal: SignalStream, timeout: Option<Duration>) {
+        self.sessions.set_ready(false);
         self.shutdown_services(true).await;
         ClusterDiscovery::instance()
             .unregister_to_metastore(&mut signal)
             .await;
         self.sessions.graceful_shutdown(signal, timeout).await;
+        // Flush the logs of the drained queries before the listeners are closed.
+        log::logger().flush();
         self.shutdown_services(false).await;
     }
 
@@ -36,6 +36,7 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::MemStat;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_base::JoinHandle;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
@@ -64,6 +65,7 @@ use databend_common_expression::BlockThresholds;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
+use databend_common_expression::NumericOverflowMode;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
@@ -103,9 +105,15 @@ use databend_common_storages_delta::DeltaTable;
 use databend_common_storages_fuse::TableContext;
 use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_orc::OrcTable;
+use databend_common_storages_parquet::ParquetExternalTable;
 use databend_common_storages_parquet::ParquetRSTable;
+use databend_common_storages_parquet::PARQUET_ENGINE;
 use databend_common_storages_result_cache::ResultScan;
 use databend_common_storages_stage::StageTable;
+use databend_common_storages_stage::TextExternalTable;
+use databend_common_storages_stage::CSV_ENGINE;
+use databend_common_storages_stage::NDJSON_ENGINE;
+use databend_common_storages_stage::TSV_ENGINE;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_users::GrantObjectVisibilityChecker;
 use databend_common_users::UserApiProvider;
@@ -121,6 +129,7 @@ use xorf::BinaryFuse16;
 
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
+use crate::interpreters::AuditEventType;
 use crate::locks::LockManager;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
@@ -306,6 +315,22 @@ impl QueryContext {
         self.shared.set_affect(affect)
     }
 
+    pub fn get_audit_event_type(&self) -> Option<AuditEventType> {
+        self.shared.get_audit_event_type()
+    }
+
+    pub fn set_audit_event_type(&self, event_type: AuditEventType) {
+        self.shared.set_audit_event_type(event_type)
+    }
+
+    pub fn get_query_plan(&self) -> Option<String> {
+        self.shared.get_query_plan()
+    }
+
+    pub fn set_query_plan(&self, plan: String) {
+        self.shared.set_query_plan(plan)
+    }
+
     pub fn set_id(&self, id: String) {
         *self.shared.init_query_id.write() = id;
     }
@@ -351,6 +376,25 @@ impl QueryContext {
         self.shared.clear_tables_cache()
     }
 
+    fn get_parquet_read_options(&self) -> Result<ParquetReadOptions> {
+        let settings = self.get_settings();
+        let mut read_options = ParquetReadOptions::default();
+
+        if !settings.get_enable_parquet_page_index()? {
+            read_options = read_options.with_prune_pages(false);
+        }
+
+        if !settings.get_enable_parquet_rowgroup_pruning()? {
+            read_options = read_options.with_prune_row_groups(false);
+        }
+
+        if !settings.get_enable_parquet_prewhere()? {
+            read_options = read_options.with_do_prewhere(false);
+        }
+
+        Ok(read_options)
+    }
+
     #[async_backtrace::framed]
     async fn get_table_from_shared(
         &self,
@@ -378,6 +422,21 @@ impl QueryContext {
                 info.meta.storage_params = Some(sp);
                 DeltaTable::try_create(info.to_owned())?.into()
             }
+            PARQUET_ENGINE => {
+                let sp = get_storage_params_from_options(self, table.options()).await?;
+                ParquetExternalTable::load(
+                    table.get_table_info(),
+                    sp,
+                    self.get_parquet_read_options()?,
+                    self.get_settings(),
+                    self.get_query_kind(),
+                )
+                .await?
+            }
+            CSV_ENGINE | TSV_ENGINE | NDJSON_ENGINE => {
+                let sp = get_storage_params_from_options(self, table.options()).await?;
+                TextExternalTable::load(table.get_table_info(), sp)?
+            }
             _ => table,
         };
         Ok(table)
@@ -757,6 +816,11 @@ impl TableContext for QueryContext {
         let now = Utc::now();
         let numeric_cast_option = settings.get_numeric_cast_option()?;
         let rounding_mode = numeric_cast_option.as_str() == "rounding";
+        let numeric_overflow_mode = match settings.get_numeric_overflow_mode()?.as_str() {
+            "saturate" => NumericOverflowMode::Saturate,
+            "error" => NumericOverflowMode::Error,
+            _ => NumericOverflowMode::Wrap,
+        };
         let disable_variant_check = settings.get_disable_variant_check()?;
         let geometry_output_format = settings.get_geometry_output_format()?;
         let parse_datetime_ignore_remainder = settings.get_parse_datetime_ignore_remainder()?;
@@ -769,6 +833,7 @@ impl TableContext for QueryContext {
             tz,
             now,
             rounding_mode,
+            numeric_overflow_mode,
             disable_variant_check,
 
             openai_api_key: query_config.openai_api_key.clone(),
@@ -928,8 +993,11 @@ impl TableContext for QueryContext {
     #[async_backtrace::framed]
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams> {
         match StageFileFormatType::from_str(name) {
-            Ok(typ) => FileFormatParams::default_by_type(typ),
-            Err(_) => {
+            // The output only formats are not file formats, leave the name to the user ones.
+            Ok(typ) if typ != StageFileFormatType::ArrowStream => {
+                FileFormatParams::default_by_type(typ)
+            }
+            _ => {
                 let user_mgr = UserApiProvider::instance();
                 let tenant = self.get_tenant();
                 Ok(user_mgr
@@ -1167,6 +1235,10 @@ impl TableContext for QueryContext {
         SessionManager::instance().get_queries_profiles()
     }
 
+    fn get_query_mem_stat(&self) -> Arc<MemStat> {
+        self.shared.get_mem_stat()
+    }
+
     fn set_merge_into_join(&self, join: MergeIntoJoin) {
         let mut merge_into_join = self.shared.merge_into_join.write();
         *merge_into_join = join;
@@ -1288,6 +1360,10 @@ impl TableContext for QueryContext {
                 let table = DeltaTable::load(sp).await?;
                 DeltaTable::get_meta(&table).await
             }
+            "parquet" => {
+                let schema = ParquetExternalTable::infer_schema(sp).await?;
+                Ok((schema, String::new()))
+            }
             // TODO: iceberg doesn't support load from storage directly.
             _ => Err(ErrorCode::Internal("unsupported datalake type {}")),
         }
@@ -1302,19 +1378,7 @@ impl TableContext for QueryContext {
     ) -> Result<Arc<dyn Table>> {
         match stage_info.file_format_params {
             FileFormatParams::Parquet(..) => {
-                let mut read_options = ParquetReadOptions::default();
-
-                if !self.get_settings().get_enable_parquet_page_index()? {
-                    read_options = read_options.with_prune_pages(false);
-                }
-
-                if !self.get_settings().get_enable_parquet_rowgroup_pruning()? {
-                    read_options = read_options.with_prune_row_groups(false);
-                }
-
-                if !self.get_settings().get_enable_parquet_prewhere()? {
-                    read_options = read_options.with_do_prewhere(false);
-                }
+                let read_options = self.get_parquet_read_options()?;
 
                 ParquetRSTable::create(
                     stage_info.clone(),
@@ -25,6 +25,7 @@ use dashmap::DashMap;
 use databend_common_base::base::short_sql;
 use databend_common_base::base::Progress;
 use databend_common_base::runtime::drop_guard;
+use databend_common_base::runtime::MemStat;
 use databend_common_base::runtime::Runtime;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
@@ -58,6 +59,7 @@ use parking_lot::RwLock;
 use uuid::Uuid;
 
 use crate::clusters::Cluster;
+use crate::interpreters::AuditEventType;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
@@ -98,6 +100,9 @@ pub struct QueryContextShared {
     pub(in crate::sessions) aborting: Arc<AtomicBool>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(in crate::sessions) affect: Arc<Mutex<Option<QueryAffect>>>,
+    pub(in crate::sessions) audit_event_type: Arc<RwLock<Option<AuditEventType>>>,
+    /// The formatted physical plan of the query, kept for the slow query log.
+    pub(in crate::sessions) query_plan: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) catalog_manager: Arc<CatalogManager>,
     pub(in crate::sessions) data_operator: DataOperator,
     pub(in crate::sessions) executor: Arc<RwLock<Weak<PipelineExecutor>>>,
@@ -138,6 +143,9 @@ pub struct QueryContextShared {
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
 
     pub(in crate::sessions) query_queued_duration: Arc<RwLock<Duration>>,
+
+    // The memory tracker of the query, the parent of the memory trackers of its executors.
+    pub(in crate::sessions) mem_stat: Arc<MemStat>,
 }
 
 impl QueryContextShared {
@@ -166,6 +174,8 @@ impl QueryContextShared {
             aborting: Arc::new(AtomicBool::new(false)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             affect: Arc::new(Mutex::new(None)),
+            audit_event_type: Arc::new(RwLock::new(None)),
+            query_plan: Arc::new(RwLock::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
@@ -192,6 +202,7 @@ impl QueryContextShared {
             merge_into_join: Default::default(),
             multi_table_insert_status: Default::default(),
             query_queued_duration: Arc::new(RwLock::new(Duration::from_secs(0))),
+            mem_stat: MemStat::create(String::from("QueryMemStat")),
         }))
     }
 
@@ -487,6 +498,10 @@ impl QueryContextShared {
         (*query_runtime).clone()
     }
 
+    pub fn get_mem_stat(&self) -> Arc<MemStat> {
+        self.mem_stat.clone()
+    }
+
     pub fn attach_query_str(&self, kind: QueryKind, query: String) {
         {
             let mut running_query = self.running_query.write();
@@ -560,6 +575,22 @@ impl QueryContextShared {
         *guard = Some(affect);
     }
 
+    pub fn get_audit_event_type(&self) -> Option<AuditEventType> {
+        *self.audit_event_type.read()
+    }
+
+    pub fn set_audit_event_type(&self, event_type: AuditEventType) {
+        *self.audit_event_type.write() = Some(event_type);
+    }
+
+    pub fn get_query_plan(&self) -> Option<String> {
+        self.query_plan.read().clone()
+    }
+
+    pub fn set_query_plan(&self, plan: String) {
+        *self.query_plan.write() = Some(plan);
+    }
+
     pub fn set_executor(&self, executor: Arc<PipelineExecutor>) -> Result<()> {
         let mut guard = self.executor.write();
         match self.check_aborting() {
@@ -156,11 +156,14 @@ impl Session {
         self: &Arc<Self>,
         cluster: Arc<Cluster>,
     ) -> Result<Arc<QueryContext>> {
-        let session = self.clone();
-        let shared = QueryContextShared::try_create(session, cluster)?;
-
-        self.session_ctx
-            .set_query_context_shared(Arc::downgrade(&shared));
+        let shared = SessionManager::instance().reserve_running_query(self, || {
+            let session = self.clone();
+            let shared = QueryContextShared::try_create(session, cluster)?;
+
+            self.session_ctx
+                .set_query_context_shared(Arc::downgrade(&shared));
+            Ok(shared)
+        })?;
         Ok(QueryContext::create_from_shared(shared))
     }
 
@@ -318,9 +321,14 @@ impl Session {
         self.session_ctx.get_settings()
     }
 
+    /// The peak memory usage of the current query.
     pub fn get_memory_usage(&self) -> usize {
-        // TODO(winter): use thread memory tracker
-        0
+        match self.session_ctx.get_query_context_shared() {
+            Some(shared) => {
+                std::cmp::max(shared.get_mem_stat().get_peak_memory_usage(), 0) as usize
+            }
+            None => 0,
+        }
     }
 
     pub fn get_status(&self) -> Arc<RwLock<SessionStatus>> {
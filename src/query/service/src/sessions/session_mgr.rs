@@ -15,59 +15,139 @@
 use std::collections::HashMap;
 use std::future::Future;
 use std::ops::DerefMut;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicU32;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 use std::time::Duration;
+use std::time::Instant;
 
 use databend_common_base::base::tokio;
 use databend_common_base::base::GlobalInstance;
 use databend_common_base::base::SignalStream;
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::table_context::ProcessInfoState;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::session::*;
 use databend_common_pipeline_core::PlanProfile;
 use databend_common_settings::Settings;
+use databend_common_users::UserQuotaManager;
 use futures::future::Either;
 use futures::StreamExt;
 use log::info;
+use parking_lot::Mutex;
 use parking_lot::RwLock;
 
 use crate::sessions::session::Session;
 use crate::sessions::ProcessInfo;
+use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
 
+const IDLE_SESSION_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
 pub struct SessionManager {
-    pub(in crate::sessions) max_sessions: usize,
+    pub(in crate::sessions) max_sessions: AtomicUsize,
+    pub(in crate::sessions) max_running_queries_per_user: AtomicUsize,
+    // Serializes the check of the running queries of a user with the creation of the query context.
+    running_queries_lock: Mutex<()>,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Weak<Session>>>>,
     pub status: Arc<RwLock<SessionManagerStatus>>,
 
     // When typ is MySQL, insert into this map, key is id, val is MySQL connection id.
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
+
+    // Whether the instance is ready to accept queries, false during startup and drain.
+    ready: AtomicBool,
 }
 
 impl SessionManager {
     pub fn init(conf: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(Self::create(conf));
+        let mgr = Self::create(conf);
+        GlobalInstance::set(mgr.clone());
+
+        // Hold a weak reference so the reaper stops once the manager is dropped.
+        let weak_mgr = Arc::downgrade(&mgr);
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                tokio::time::sleep(IDLE_SESSION_CHECK_INTERVAL).await;
+                let Some(mgr) = weak_mgr.upgrade() else {
+                    break;
+                };
+                let reaped = mgr.reap_idle_sessions();
+                if reaped > 0 {
+                    info!("Closed {} idle sessions", reaped);
+                }
+            }
+        });
 
         Ok(())
     }
 
+    /// Close the MySQL sessions which have not run any query within their `idle_session_timeout_secs`.
+    pub fn reap_idle_sessions(&self) -> usize {
+        let now = Instant::now();
+        let mut reaped = 0;
+        for session in self
+            .active_sessions_snapshot()
+            .into_iter()
+            .filter_map(|weak_ptr| weak_ptr.upgrade())
+        {
+            if session.get_type() != SessionType::MySQL
+                || session.session_ctx.get_query_context_shared().is_some()
+            {
+                continue;
+            }
+
+            let timeout_secs = session
+                .get_settings()
+                .get_idle_session_timeout_secs()
+                .unwrap_or(0);
+            if timeout_secs == 0 {
+                continue;
+            }
+
+            let last_access = session.get_status().read().last_access();
+            if now.duration_since(last_access) < Duration::from_secs(timeout_secs) {
+                continue;
+            }
+
+            info!(
+                "Close idle session {}, idle timeout {} seconds exceeded",
+                session.get_id(),
+                timeout_secs
+            );
+            session.session_ctx.set_abort(true);
+            if let Some(shutdown_fun) = session.session_ctx.take_io_shutdown_tx() {
+                shutdown_fun();
+            }
+            incr_session_idle_reaped_numbers();
+            reaped += 1;
+        }
+        reaped
+    }
+
     pub fn create(conf: &InnerConfig) -> Arc<SessionManager> {
         let max_sessions = conf.query.max_active_sessions as usize;
         Arc::new(SessionManager {
-            max_sessions,
+            max_sessions: AtomicUsize::new(max_sessions),
+            max_running_queries_per_user: AtomicUsize::new(
+                conf.query.max_running_queries_per_user as usize,
+            ),
+            running_queries_lock: Mutex::new(()),
             mysql_basic_conn_id: AtomicU32::new(9_u32.to_le()),
             status: Arc::new(RwLock::new(SessionManagerStatus::default())),
             mysql_conn_map: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
+            ready: AtomicBool::new(false),
         })
     }
 
@@ -75,6 +155,14 @@ impl SessionManager {
         GlobalInstance::get()
     }
 
+    pub fn set_ready(&self, ready: bool) {
+        self.ready.store(ready, Ordering::Release);
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Acquire)
+    }
+
     #[async_backtrace::framed]
     pub async fn create_session(&self, typ: SessionType) -> Result<Session> {
         if !matches!(typ, SessionType::Dummy | SessionType::FlightRPC) {
@@ -225,7 +313,7 @@ impl SessionManager {
         async move {
             if let Some(mut timeout) = timeout {
                 info!(
-                    "Waiting {:?} for connections to close. You can press Ctrl + C again to force shutdown.",
+                    "Waiting {:?} for running queries to finish and connections to close. You can press Ctrl + C again to force shutdown.",
                     timeout
                 );
 
@@ -250,7 +338,7 @@ impl SessionManager {
                 }
             }
 
-            info!("Will shutdown forcefully.");
+            info!("Will shutdown forcefully, the running queries are aborted.");
 
             // During the destroy session, we need to get active_sessions write locks,
             // so we can only get active_sessions snapshots.
@@ -270,15 +358,22 @@ impl SessionManager {
             .collect::<Vec<_>>()
     }
 
+    /// Kill the idle sessions and let the running queries finish, returns true
+    /// if all the sessions are closed.
     fn destroy_idle_sessions(sessions: &Arc<RwLock<HashMap<String, Weak<Session>>>>) -> bool {
         // Read lock does not support reentrant
         // https://github.com/Amanieu/parking_lot::/blob/lock_api-0.4.4/lock_api/src/rwlock.rs#L422
         let mut active_sessions_read_guard = sessions.write();
 
-        // First try to kill the idle session
+        // First try to kill the idle session, the sessions running a query are
+        // killed once the query is finished.
+        let mut running_queries = 0;
         active_sessions_read_guard.retain(|_id, weak_ptr| -> bool {
             weak_ptr.upgrade().is_some_and(|session| {
-                session.kill();
+                match session.session_ctx.get_current_query_id() {
+                    Some(_) => running_queries += 1,
+                    None => session.kill(),
+                }
                 true
             })
         });
@@ -289,22 +384,108 @@ impl SessionManager {
         match active_sessions {
             0 => true,
             _ => {
-                info!("Waiting for {} connections to close.", active_sessions);
+                info!(
+                    "Waiting for {} connections to close, {} of them are running queries.",
+                    active_sessions, running_queries
+                );
                 false
             }
         }
     }
 
+    /// Apply the reloaded session limits of the config.
+    pub fn set_limits(&self, max_sessions: usize, max_running_queries_per_user: usize) {
+        self.max_sessions.store(max_sessions, Ordering::Relaxed);
+        self.max_running_queries_per_user
+            .store(max_running_queries_per_user, Ordering::Relaxed);
+    }
+
     fn validate_max_active_sessions(&self, count: usize, reason: &str) -> Result<()> {
-        if count >= self.max_sessions {
+        let max_sessions = self.max_sessions.load(Ordering::Relaxed);
+        if count >= max_sessions {
             return Err(ErrorCode::TooManyUserConnections(format!(
                 "Current {} ({}) has exceeded the max_active_sessions limit ({})",
-                reason, count, self.max_sessions
+                reason, count, max_sessions
             )));
         }
         Ok(())
     }
 
+    /// Check the running queries and the concurrent queries quota of the session's user,
+    /// and create the query context with `create` if they are not exceeded.
+    ///
+    /// The check and the creation are done under `running_queries_lock`, so the concurrent
+    /// queries of a user can not all pass the check before any of them is counted.
+    pub fn reserve_running_query<T>(
+        &self,
+        session: &Session,
+        create: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        let Some(user) = session.session_ctx.get_current_user() else {
+            return create();
+        };
+
+        let max_running_queries_per_user =
+            self.max_running_queries_per_user.load(Ordering::Relaxed);
+        let max_concurrent_queries = user
+            .option
+            .max_concurrent_queries()
+            .copied()
+            .unwrap_or_default() as usize;
+        if max_running_queries_per_user == 0 && max_concurrent_queries == 0 {
+            return create();
+        }
+
+        let _guard = self.running_queries_lock.lock();
+        let running = self.running_queries_of_user(session, &user.identity());
+
+        if max_running_queries_per_user != 0 && running >= max_running_queries_per_user {
+            return Err(ErrorCode::TooManyRunningQueriesPerUser(format!(
+                "Current running queries ({}) of user {} has exceeded the max_running_queries_per_user limit ({})",
+                running,
+                user.identity().display(),
+                max_running_queries_per_user
+            )));
+        }
+
+        if max_concurrent_queries != 0 && running >= max_concurrent_queries {
+            return Err(ErrorCode::UserQuotaExceeded(format!(
+                "Current running queries ({}) of user {} has exceeded the max_concurrent_queries quota ({})",
+                running,
+                user.identity().display(),
+                max_concurrent_queries
+            )));
+        }
+
+        create()
+    }
+
+    /// Count a query of the session's user in its `MAX_QUERIES_PER_HOUR` quota.
+    ///
+    /// Only called when a query of a client starts, the query contexts created
+    /// for a login or an upload and the statements of a script are not counted.
+    pub fn charge_user_query(&self, session: &Session) -> Result<()> {
+        match session.session_ctx.get_current_user() {
+            Some(user) => UserQuotaManager::instance().try_start_query(&user),
+            None => Ok(()),
+        }
+    }
+
+    fn running_queries_of_user(&self, session: &Session, user: &UserIdentity) -> usize {
+        self.active_sessions_snapshot()
+            .into_iter()
+            .filter_map(|weak_ptr| weak_ptr.upgrade())
+            .filter(|other| other.id != session.id)
+            .filter(|other| other.session_ctx.get_query_context_shared().is_some())
+            .filter(|other| {
+                other
+                    .session_ctx
+                    .get_current_user()
+                    .is_some_and(|other_user| &other_user.identity() == user)
+            })
+            .count()
+    }
+
     pub fn get_current_session_status(&self) -> SessionManagerStatus {
         let mut status_t = self.status.read().clone();
 
@@ -380,6 +561,32 @@ impl SessionManager {
         )))
     }
 
+    /// Cancel the running or queued query on this node.
+    pub fn kill_query(&self, query_id: &str) -> Result<()> {
+        if QueriesQueueManager::instance().remove(query_id.to_string()) {
+            return Ok(());
+        }
+
+        for weak_ptr in self.active_sessions_snapshot() {
+            let Some(arc_session) = weak_ptr.upgrade() else {
+                continue;
+            };
+
+            if arc_session.get_current_query_id().as_deref() == Some(query_id) {
+                arc_session.force_kill_query(ErrorCode::AbortedQuery(format!(
+                    "Aborted query {}, because it was killed through the admin api",
+                    query_id
+                )));
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::UnknownQuery(format!(
+            "Unknown query {}",
+            query_id
+        )))
+    }
+
     fn active_sessions_snapshot(&self) -> Vec<Weak<Session>> {
         // Here the situation is the same of method `graceful_shutdown`:
         //
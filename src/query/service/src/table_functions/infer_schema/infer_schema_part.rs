@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_storage::StageFileInfo;
+
+/// The first file of the location and its file format, resolved by `read_partitions`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq)]
+pub(crate) struct InferSchemaPartInfo {
+    pub(crate) stage_info: StageInfo,
+    pub(crate) first_file: StageFileInfo,
+    pub(crate) file_format_params: FileFormatParams,
+}
+
+#[typetag::serde(name = "infer_schema")]
+impl PartInfo for InferSchemaPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<InferSchemaPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        0
+    }
+}
+
+impl InferSchemaPartInfo {
+    pub(crate) fn create(
+        stage_info: StageInfo,
+        first_file: StageFileInfo,
+        file_format_params: FileFormatParams,
+    ) -> PartInfoPtr {
+        Arc::new(Box::new(InferSchemaPartInfo {
+            stage_info,
+            first_file,
+            file_format_params,
+        }))
+    }
+
+    pub(crate) fn from_part(info: &PartInfoPtr) -> Result<&InferSchemaPartInfo> {
+        info.as_any()
+            .downcast_ref::<InferSchemaPartInfo>()
+            .ok_or_else(|| {
+                ErrorCode::Internal("Cannot downcast from PartInfo to InferSchemaPartInfo.")
+            })
+    }
+}
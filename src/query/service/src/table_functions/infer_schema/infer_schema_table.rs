@@ -13,27 +13,45 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::UriLocation;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::binder::resolve_file_location;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFilesInfo;
+use opendal::Scheme;
 
 use super::parquet::ParquetInferSchemaSource;
+use super::text::TextInferSchemaSource;
 use crate::sessions::TableContext;
+use crate::table_functions::infer_schema::infer_schema_part::InferSchemaPartInfo;
 use crate::table_functions::infer_schema::table_args::InferSchemaArgsParsed;
 use crate::table_functions::TableFunction;
 
@@ -82,6 +100,30 @@ impl InferSchemaTable {
     }
 }
 
+/// The result of infer_schema, one row for each column of the inferred schema.
+pub(crate) fn infer_schema_block(schema: &TableSchema) -> DataBlock {
+    let mut names: Vec<String> = vec![];
+    let mut types: Vec<String> = vec![];
+    let mut nulls: Vec<bool> = vec![];
+
+    for field in schema.fields().iter() {
+        names.push(field.name().to_string());
+
+        let non_null_type = field.data_type().remove_recursive_nullable();
+        types.push(non_null_type.sql_name());
+        nulls.push(field.is_nullable());
+    }
+
+    let order_ids = (0..schema.fields().len() as u64).collect::<Vec<_>>();
+
+    DataBlock::new_from_columns(vec![
+        StringType::from_data(names),
+        StringType::from_data(types),
+        BooleanType::from_data(nulls),
+        UInt64Type::from_data(order_ids),
+    ])
+}
+
 #[async_trait::async_trait]
 impl Table for InferSchemaTable {
     fn as_any(&self) -> &dyn Any {
@@ -92,14 +134,86 @@ impl Table for InferSchemaTable {
         &self.table_info
     }
 
+    /// Resolve the first file of the location and its file format,
+    /// the schema is inferred from it by the source of the format.
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
+        ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
         _dry_run: bool,
     ) -> Result<(PartStatistics, Partitions)> {
-        Ok((PartStatistics::default(), Partitions::default()))
+        let file_location = if let Some(location) =
+            self.args_parsed.location.clone().strip_prefix('@')
+        {
+            FileLocation::Stage(location.to_string())
+        } else if let Some(connection_name) = &self.args_parsed.connection_name {
+            let conn = ctx.get_connection(connection_name).await?;
+            let uri = UriLocation::from_uri(
+                self.args_parsed.location.clone(),
+                "".to_string(),
+                conn.storage_params,
+            )?;
+            let proto = conn.storage_type.parse::<Scheme>()?;
+            if proto != uri.protocol.parse::<Scheme>()? {
+                return Err(ErrorCode::BadArguments(format!(
+                    "protocol from connection_name={connection_name} ({proto}) not match with uri protocol ({0}).",
+                    uri.protocol
+                )));
+            }
+            FileLocation::Uri(uri)
+        } else {
+            let uri = UriLocation::from_uri(
+                self.args_parsed.location.clone(),
+                "".to_string(),
+                BTreeMap::default(),
+            )?;
+            FileLocation::Uri(uri)
+        };
+        let (stage_info, path) = resolve_file_location(ctx.as_ref(), &file_location).await?;
+        let enable_experimental_rbac_check =
+            ctx.get_settings().get_enable_experimental_rbac_check()?;
+        if enable_experimental_rbac_check {
+            let visibility_checker = ctx.get_visibility_checker().await?;
+            if !(stage_info.is_temporary
+                || visibility_checker.check_stage_read_visibility(&stage_info.stage_name)
+                || stage_info.stage_type == StageType::User
+                    && stage_info.stage_name == ctx.get_current_user()?.name)
+            {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: privilege READ is required on stage {} for user {}",
+                    stage_info.stage_name.clone(),
+                    &ctx.get_current_user()?.identity().display(),
+                )));
+            }
+        }
+        let files_info = StageFilesInfo {
+            path: path.clone(),
+            ..self.args_parsed.files_info.clone()
+        };
+        let operator = init_stage_operator(&stage_info)?;
+
+        let first_file = files_info.first_file(&operator).await?;
+        let file_format_params = match &self.args_parsed.file_format {
+            Some(f) => ctx.get_file_format(f).await?,
+            None => stage_info.file_format_params.clone(),
+        };
+        match file_format_params.get_type() {
+            StageFileFormatType::Parquet
+            | StageFileFormatType::Csv
+            | StageFileFormatType::NdJson => {}
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "infer_schema is currently limited to format Parquet, CSV and NDJSON",
+                ));
+            }
+        }
+
+        let part = InferSchemaPartInfo::create(stage_info, first_file, file_format_params);
+        Ok((
+            PartStatistics::default(),
+            Partitions::create(PartitionsShuffleKind::Seq, vec![part]),
+        ))
     }
 
     fn table_args(&self) -> Option<TableArgs> {
@@ -109,17 +223,24 @@ impl Table for InferSchemaTable {
     fn read_data(
         &self,
         ctx: Arc<dyn TableContext>,
-        _plan: &DataSourcePlan,
+        plan: &DataSourcePlan,
         pipeline: &mut Pipeline,
         _put_cache: bool,
     ) -> Result<()> {
-        pipeline.add_source(
-            |output| {
-                ParquetInferSchemaSource::create(ctx.clone(), output, self.args_parsed.clone())
-            },
-            1,
-        )?;
-        Ok(())
+        let part = plan.parts.partitions.first().ok_or_else(|| {
+            ErrorCode::Internal("infer_schema expects the partition of the first file")
+        })?;
+        let part = InferSchemaPartInfo::from_part(part)?.clone();
+        match part.file_format_params.get_type() {
+            StageFileFormatType::Parquet => pipeline.add_source(
+                |output| ParquetInferSchemaSource::create(ctx.clone(), output, part.clone()),
+                1,
+            ),
+            _ => pipeline.add_source(
+                |output| TextInferSchemaSource::create(ctx.clone(), output, part.clone()),
+                1,
+            ),
+        }
     }
 }
 
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod infer_schema_part;
 mod infer_schema_table;
 mod parquet;
 mod table_args;
+mod text;
 
 pub use infer_schema_table::InferSchemaTable;
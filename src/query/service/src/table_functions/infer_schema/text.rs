@@ -0,0 +1,369 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_formats::RecordDelimiter;
+use databend_common_meta_app::principal::CsvFileFormatParams;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storage::init_stage_operator;
+use serde_json::Value;
+
+use crate::table_functions::infer_schema::infer_schema_part::InferSchemaPartInfo;
+use crate::table_functions::infer_schema::infer_schema_table::infer_schema_block;
+use crate::table_functions::infer_schema::infer_schema_table::INFER_SCHEMA;
+
+/// The max bytes read from the first file to infer the schema of CSV and NDJSON.
+const MAX_SAMPLE_BYTES: u64 = 1024 * 1024;
+
+const MAX_CSV_COLUMNS: usize = 1000;
+
+/// Infer the schema of CSV and NDJSON from a sample of the first file.
+pub(crate) struct TextInferSchemaSource {
+    is_finished: bool,
+    part: InferSchemaPartInfo,
+}
+
+impl TextInferSchemaSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        part: InferSchemaPartInfo,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx, output, TextInferSchemaSource {
+            is_finished: false,
+            part,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TextInferSchemaSource {
+    const NAME: &'static str = INFER_SCHEMA;
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.is_finished {
+            return Ok(None);
+        }
+        self.is_finished = true;
+
+        let file_format_params = &self.part.file_format_params;
+        if file_format_params.compression() != StageFileCompression::None {
+            return Err(ErrorCode::BadArguments(
+                "infer_schema does not support compressed CSV or NDJSON files",
+            ));
+        }
+
+        let operator = init_stage_operator(&self.part.stage_info)?;
+        let first_file = &self.part.first_file;
+        let size = first_file.size.min(MAX_SAMPLE_BYTES);
+        let data = operator
+            .read_with(&first_file.path)
+            .range(0..size)
+            .await?
+            .to_vec();
+        let is_eof = size == first_file.size;
+        let schema = match file_format_params {
+            FileFormatParams::Csv(params) => infer_csv_schema(&data, is_eof, params)?,
+            _ => infer_ndjson_schema(&data, is_eof)?,
+        };
+        Ok(Some(infer_schema_block(&schema)))
+    }
+}
+
+/// The type of a column inferred from the sampled values, from the narrowest to the widest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InferredType {
+    Null,
+    Boolean,
+    Int64,
+    Float64,
+    Date,
+    Timestamp,
+    String,
+    Variant,
+}
+
+impl InferredType {
+    fn merge(self, other: InferredType) -> InferredType {
+        use InferredType::*;
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Null, t) | (t, Null) => t,
+            (Int64, Float64) | (Float64, Int64) => Float64,
+            (Date, Timestamp) | (Timestamp, Date) => Timestamp,
+            (Variant, _) | (_, Variant) => Variant,
+            _ => String,
+        }
+    }
+
+    fn data_type(self) -> TableDataType {
+        match self {
+            // All the values are NULL, fallback to String.
+            InferredType::Null | InferredType::String => TableDataType::String,
+            InferredType::Boolean => TableDataType::Boolean,
+            InferredType::Int64 => TableDataType::Number(NumberDataType::Int64),
+            InferredType::Float64 => TableDataType::Number(NumberDataType::Float64),
+            InferredType::Date => TableDataType::Date,
+            InferredType::Timestamp => TableDataType::Timestamp,
+            InferredType::Variant => TableDataType::Variant,
+        }
+    }
+}
+
+/// Infer the type of a text value, numbers are only accepted if `parse_number`.
+fn infer_text(v: &str, parse_number: bool) -> InferredType {
+    if parse_number {
+        if v.eq_ignore_ascii_case("true") || v.eq_ignore_ascii_case("false") {
+            return InferredType::Boolean;
+        }
+        if v.parse::<i64>().is_ok() {
+            return InferredType::Int64;
+        }
+        if v.parse::<f64>().is_ok() {
+            return InferredType::Float64;
+        }
+    }
+    if v.len() == 10 && NaiveDate::parse_from_str(v, "%Y-%m-%d").is_ok() {
+        return InferredType::Date;
+    }
+    if ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+        .iter()
+        .any(|f| NaiveDateTime::parse_from_str(v, f).is_ok())
+    {
+        return InferredType::Timestamp;
+    }
+    InferredType::String
+}
+
+/// The columns seen so far, in the order they first appear.
+#[derive(Default)]
+struct Columns {
+    names: Vec<String>,
+    types: Vec<InferredType>,
+    nullable: Vec<bool>,
+    index: HashMap<String, usize>,
+}
+
+impl Columns {
+    fn update(&mut self, name: &str, ty: InferredType, is_null: bool) {
+        let i = match self.index.get(name) {
+            Some(i) => *i,
+            None => {
+                self.index.insert(name.to_string(), self.names.len());
+                self.names.push(name.to_string());
+                self.types.push(InferredType::Null);
+                self.nullable.push(false);
+                self.names.len() - 1
+            }
+        };
+        self.types[i] = self.types[i].merge(ty);
+        self.nullable[i] |= is_null;
+    }
+
+    fn into_schema(self) -> TableSchema {
+        let fields = self
+            .names
+            .into_iter()
+            .zip(self.types)
+            .zip(self.nullable)
+            .map(|((name, ty), nullable)| {
+                let data_type = ty.data_type();
+                if nullable || ty == InferredType::Null {
+                    TableField::new(&name, data_type.wrap_nullable())
+                } else {
+                    TableField::new(&name, data_type)
+                }
+            })
+            .collect();
+        TableSchema::new(fields)
+    }
+}
+
+/// Infer the schema from the leading bytes of a CSV file, the last row is ignored
+/// if the file is not read to the end, because it may be truncated.
+pub(crate) fn infer_csv_schema(
+    data: &[u8],
+    is_eof: bool,
+    params: &CsvFileFormatParams,
+) -> Result<TableSchema> {
+    let escape = params.escape.as_bytes().first().copied();
+    let mut reader = csv_core::ReaderBuilder::new()
+        .delimiter(params.field_delimiter.as_bytes()[0])
+        .quote(params.quote.as_bytes()[0])
+        .escape(escape)
+        .terminator(match params.record_delimiter.as_str().try_into()? {
+            RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
+            RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
+        })
+        .build();
+
+    let mut rows: Vec<Vec<String>> = vec![];
+    let mut input = data;
+    let mut output = vec![0u8; data.len() + 1];
+    let mut ends = vec![0usize; MAX_CSV_COLUMNS];
+    // The output and field ends of the current record, which may span several reads.
+    let (mut out_pos, mut end_pos) = (0, 0);
+    loop {
+        let (result, n_in, n_out, n_end) =
+            reader.read_record(input, &mut output[out_pos..], &mut ends[end_pos..]);
+        input = &input[n_in..];
+        for end in &mut ends[end_pos..end_pos + n_end] {
+            *end += out_pos;
+        }
+        out_pos += n_out;
+        end_pos += n_end;
+        match result {
+            csv_core::ReadRecordResult::Record => {
+                let mut start = 0;
+                let mut row = Vec::with_capacity(end_pos);
+                for end in &ends[..end_pos] {
+                    row.push(String::from_utf8_lossy(&output[start..*end]).to_string());
+                    start = *end;
+                }
+                rows.push(row);
+                (out_pos, end_pos) = (0, 0);
+            }
+            // The last row may be truncated if the file is not read to the end.
+            csv_core::ReadRecordResult::InputEmpty if !is_eof => break,
+            csv_core::ReadRecordResult::InputEmpty => {}
+            csv_core::ReadRecordResult::End => break,
+            csv_core::ReadRecordResult::OutputFull | csv_core::ReadRecordResult::OutputEndsFull => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "too many columns, infer_schema supports at most {} columns for CSV",
+                    MAX_CSV_COLUMNS
+                )));
+            }
+        }
+    }
+
+    let header = if params.headers > 0 && !rows.is_empty() {
+        Some(rows.remove(0))
+    } else {
+        None
+    };
+    let num_columns = rows.iter().map(|r| r.len()).max().unwrap_or_default();
+    let num_columns = num_columns.max(header.as_ref().map(|h| h.len()).unwrap_or_default());
+    let names = (0..num_columns)
+        .map(|i| match header.as_ref().and_then(|h| h.get(i)) {
+            Some(name) if !name.is_empty() => name.clone(),
+            _ => format!("c{}", i + 1),
+        })
+        .collect::<Vec<_>>();
+    let names = dedup_names(names);
+
+    let mut columns = Columns::default();
+    for name in &names {
+        columns.update(name, InferredType::Null, false);
+    }
+    for row in &rows {
+        for (i, name) in names.iter().enumerate() {
+            match row.get(i) {
+                Some(v) if !v.is_empty() && v != &params.null_display => {
+                    columns.update(name, infer_text(v, true), false)
+                }
+                _ => columns.update(name, InferredType::Null, true),
+            }
+        }
+    }
+    Ok(columns.into_schema())
+}
+
+/// Keep the columns with duplicate names distinct, the later ones are suffixed
+/// with `_2`, `_3` and so on, e.g. `a, a, b` becomes `a, a_2, b`.
+fn dedup_names(names: Vec<String>) -> Vec<String> {
+    let mut seen = names.iter().cloned().collect::<HashSet<_>>();
+    let mut used = HashSet::with_capacity(names.len());
+    names
+        .into_iter()
+        .map(|name| {
+            if used.insert(name.clone()) {
+                return name;
+            }
+            let mut n = 2;
+            loop {
+                let candidate = format!("{}_{}", name, n);
+                if !seen.contains(&candidate) {
+                    seen.insert(candidate.clone());
+                    used.insert(candidate.clone());
+                    return candidate;
+                }
+                n += 1;
+            }
+        })
+        .collect()
+}
+
+/// Infer the schema from the leading bytes of a NDJSON file, the last line is ignored
+/// if the file is not read to the end, because it may be truncated.
+pub(crate) fn infer_ndjson_schema(data: &[u8], is_eof: bool) -> Result<TableSchema> {
+    let mut lines = data.split(|b| *b == b'\n').collect::<Vec<_>>();
+    if !is_eof {
+        lines.pop();
+    }
+
+    let mut columns = Columns::default();
+    let mut num_rows = 0;
+    for line in lines {
+        let line = line.trim_ascii();
+        if line.is_empty() {
+            continue;
+        }
+        let value: Value = serde_json::from_slice(line)?;
+        let Value::Object(obj) = value else {
+            return Err(ErrorCode::BadBytes(
+                "infer_schema expects each line of NDJSON to be an object",
+            ));
+        };
+        // Columns missing in some rows are nullable.
+        for name in columns.names.clone() {
+            if !obj.contains_key(&name) {
+                columns.update(&name, InferredType::Null, true);
+            }
+        }
+        for (name, v) in obj.iter() {
+            // Columns first seen after the first row are missing in the previous rows.
+            let is_missing = num_rows > 0 && !columns.index.contains_key(name);
+            let ty = match v {
+                Value::Null => InferredType::Null,
+                Value::Bool(_) => InferredType::Boolean,
+                Value::Number(n) if n.is_i64() => InferredType::Int64,
+                Value::Number(_) => InferredType::Float64,
+                Value::String(s) => infer_text(s, false),
+                Value::Array(_) | Value::Object(_) => InferredType::Variant,
+            };
+            columns.update(name, ty, v.is_null() || is_missing);
+        }
+        num_rows += 1;
+    }
+    Ok(columns.into_schema())
+}
@@ -26,6 +26,7 @@ mod srf;
 mod sync_crash_me;
 mod table_function;
 mod table_function_factory;
+mod tpch;
 
 pub use numbers::generate_numbers_parts;
 pub use numbers::NumbersPartInfo;
@@ -38,3 +39,4 @@ pub use others::SuggestedBackgroundTasksTable;
 pub use others::TenantQuotaTable;
 pub use table_function::TableFunction;
 pub use table_function_factory::TableFunctionFactory;
+pub use tpch::TpchTable;
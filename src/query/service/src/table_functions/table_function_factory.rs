@@ -54,6 +54,7 @@ use crate::table_functions::show_grants::ShowGrants;
 use crate::table_functions::show_variables::ShowVariables;
 use crate::table_functions::srf::RangeTable;
 use crate::table_functions::sync_crash_me::SyncCrashMeTable;
+use crate::table_functions::tpch::TpchTable;
 use crate::table_functions::GPT2SQLTable;
 use crate::table_functions::TableFunction;
 
@@ -304,6 +305,19 @@ impl TableFunctionFactory {
             ),
         );
 
+        for name in [
+            "tpch_region",
+            "tpch_nation",
+            "tpch_part",
+            "tpch_supplier",
+            "tpch_partsupp",
+            "tpch_customer",
+            "tpch_orders",
+            "tpch_lineitem",
+        ] {
+            creators.insert(name.to_string(), (next_id(), Arc::new(TpchTable::create)));
+        }
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
@@ -0,0 +1,883 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::DateType;
+use databend_common_expression::types::Decimal128Type;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use rand::distributions::Alphanumeric;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand::SeedableRng;
+
+// Days since 1970-01-01.
+const START_DATE: i32 = 8035; // 1992-01-01
+const CURRENT_DATE: i32 = 9298; // 1995-06-17
+const END_DATE: i32 = 10591; // 1998-12-31
+
+const PRICE_SIZE: DecimalSize = DecimalSize {
+    precision: 15,
+    scale: 2,
+};
+
+const REGIONS: [&str; 5] = ["AFRICA", "AMERICA", "ASIA", "EUROPE", "MIDDLE EAST"];
+
+const NATIONS: [(&str, i32); 25] = [
+    ("ALGERIA", 0),
+    ("ARGENTINA", 1),
+    ("BRAZIL", 1),
+    ("CANADA", 1),
+    ("EGYPT", 4),
+    ("ETHIOPIA", 0),
+    ("FRANCE", 3),
+    ("GERMANY", 3),
+    ("INDIA", 2),
+    ("INDONESIA", 2),
+    ("IRAN", 4),
+    ("IRAQ", 4),
+    ("JAPAN", 2),
+    ("JORDAN", 4),
+    ("KENYA", 0),
+    ("MOROCCO", 0),
+    ("MOZAMBIQUE", 0),
+    ("PERU", 1),
+    ("CHINA", 2),
+    ("ROMANIA", 3),
+    ("SAUDI ARABIA", 4),
+    ("VIETNAM", 2),
+    ("RUSSIA", 3),
+    ("UNITED KINGDOM", 3),
+    ("UNITED STATES", 1),
+];
+
+const COLORS: [&str; 92] = [
+    "almond",
+    "antique",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanched",
+    "blue",
+    "blush",
+    "brown",
+    "burlywood",
+    "burnished",
+    "chartreuse",
+    "chiffon",
+    "chocolate",
+    "coral",
+    "cornflower",
+    "cornsilk",
+    "cream",
+    "cyan",
+    "dark",
+    "deep",
+    "dim",
+    "dodger",
+    "drab",
+    "firebrick",
+    "floral",
+    "forest",
+    "frosted",
+    "gainsboro",
+    "ghost",
+    "goldenrod",
+    "green",
+    "grey",
+    "honeydew",
+    "hot",
+    "indian",
+    "ivory",
+    "khaki",
+    "lace",
+    "lavender",
+    "lawn",
+    "lemon",
+    "light",
+    "lime",
+    "linen",
+    "magenta",
+    "maroon",
+    "medium",
+    "metallic",
+    "midnight",
+    "mint",
+    "misty",
+    "moccasin",
+    "navajo",
+    "navy",
+    "olive",
+    "orange",
+    "orchid",
+    "pale",
+    "papaya",
+    "peach",
+    "peru",
+    "pink",
+    "plum",
+    "powder",
+    "puff",
+    "purple",
+    "red",
+    "rose",
+    "rosy",
+    "royal",
+    "saddle",
+    "salmon",
+    "sandy",
+    "seashell",
+    "sienna",
+    "sky",
+    "slate",
+    "smoke",
+    "snow",
+    "spring",
+    "steel",
+    "tan",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "yellow",
+];
+
+const TYPE_SYLLABLES_1: [&str; 6] = ["STANDARD", "SMALL", "MEDIUM", "LARGE", "ECONOMY", "PROMO"];
+const TYPE_SYLLABLES_2: [&str; 5] = ["ANODIZED", "BURNISHED", "PLATED", "POLISHED", "BRUSHED"];
+const TYPE_SYLLABLES_3: [&str; 5] = ["TIN", "NICKEL", "BRASS", "STEEL", "COPPER"];
+const CONTAINER_SYLLABLES_1: [&str; 5] = ["SM", "LG", "MED", "JUMBO", "WRAP"];
+const CONTAINER_SYLLABLES_2: [&str; 8] =
+    ["CASE", "BOX", "BAG", "JAR", "PKG", "PACK", "CAN", "DRUM"];
+const SEGMENTS: [&str; 5] = [
+    "AUTOMOBILE",
+    "BUILDING",
+    "FURNITURE",
+    "MACHINERY",
+    "HOUSEHOLD",
+];
+const PRIORITIES: [&str; 5] = ["1-URGENT", "2-HIGH", "3-MEDIUM", "4-NOT SPECIFIED", "5-LOW"];
+const INSTRUCTIONS: [&str; 4] = [
+    "DELIVER IN PERSON",
+    "COLLECT COD",
+    "NONE",
+    "TAKE BACK RETURN",
+];
+const MODES: [&str; 7] = ["REG AIR", "AIR", "RAIL", "SHIP", "TRUCK", "MAIL", "FOB"];
+
+const WORDS: [&str; 40] = [
+    "furiously",
+    "quickly",
+    "carefully",
+    "blithely",
+    "slyly",
+    "final",
+    "regular",
+    "special",
+    "pending",
+    "express",
+    "ironic",
+    "bold",
+    "even",
+    "silent",
+    "unusual",
+    "deposits",
+    "requests",
+    "packages",
+    "accounts",
+    "instructions",
+    "theodolites",
+    "foxes",
+    "pinto",
+    "beans",
+    "asymptotes",
+    "ideas",
+    "dependencies",
+    "excuses",
+    "platelets",
+    "sleep",
+    "wake",
+    "haggle",
+    "nag",
+    "cajole",
+    "boost",
+    "detect",
+    "integrate",
+    "among",
+    "above",
+    "across",
+];
+
+/// The tables of TPC-H, generated by the `tpch_<table>(scale)` table functions.
+///
+/// The data follows the cardinalities, key relations and value distributions of the TPC-H
+/// specification, so that the 22 queries select and join the same shape of data as on `dbgen`
+/// output. It is not byte-identical to `dbgen`: the text columns are drawn from a smaller
+/// vocabulary and the answers don't match the reference answer set.
+///
+/// Every row is generated from a RNG seeded by its key, so that any range of rows can be
+/// generated independently and the result doesn't depend on the partitioning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TpchTableKind {
+    Region,
+    Nation,
+    Part,
+    Supplier,
+    PartSupp,
+    Customer,
+    Orders,
+    LineItem,
+}
+
+impl TpchTableKind {
+    pub fn from_func_name(name: &str) -> Option<TpchTableKind> {
+        match name {
+            "tpch_region" => Some(TpchTableKind::Region),
+            "tpch_nation" => Some(TpchTableKind::Nation),
+            "tpch_part" => Some(TpchTableKind::Part),
+            "tpch_supplier" => Some(TpchTableKind::Supplier),
+            "tpch_partsupp" => Some(TpchTableKind::PartSupp),
+            "tpch_customer" => Some(TpchTableKind::Customer),
+            "tpch_orders" => Some(TpchTableKind::Orders),
+            "tpch_lineitem" => Some(TpchTableKind::LineItem),
+            _ => None,
+        }
+    }
+
+    pub fn engine(&self) -> &'static str {
+        match self {
+            TpchTableKind::Region => "TpchRegion",
+            TpchTableKind::Nation => "TpchNation",
+            TpchTableKind::Part => "TpchPart",
+            TpchTableKind::Supplier => "TpchSupplier",
+            TpchTableKind::PartSupp => "TpchPartSupp",
+            TpchTableKind::Customer => "TpchCustomer",
+            TpchTableKind::Orders => "TpchOrders",
+            TpchTableKind::LineItem => "TpchLineItem",
+        }
+    }
+
+    pub fn schema(&self) -> TableSchemaRef {
+        let int32 = || TableDataType::Number(NumberDataType::Int32);
+        let int64 = || TableDataType::Number(NumberDataType::Int64);
+        let string = || TableDataType::String;
+        let price = || TableDataType::Decimal(DecimalDataType::Decimal128(PRICE_SIZE));
+        let date = || TableDataType::Date;
+
+        let fields = match self {
+            TpchTableKind::Region => vec![
+                ("r_regionkey", int32()),
+                ("r_name", string()),
+                ("r_comment", string()),
+            ],
+            TpchTableKind::Nation => vec![
+                ("n_nationkey", int32()),
+                ("n_name", string()),
+                ("n_regionkey", int32()),
+                ("n_comment", string()),
+            ],
+            TpchTableKind::Part => vec![
+                ("p_partkey", int64()),
+                ("p_name", string()),
+                ("p_mfgr", string()),
+                ("p_brand", string()),
+                ("p_type", string()),
+                ("p_size", int32()),
+                ("p_container", string()),
+                ("p_retailprice", price()),
+                ("p_comment", string()),
+            ],
+            TpchTableKind::Supplier => vec![
+                ("s_suppkey", int64()),
+                ("s_name", string()),
+                ("s_address", string()),
+                ("s_nationkey", int32()),
+                ("s_phone", string()),
+                ("s_acctbal", price()),
+                ("s_comment", string()),
+            ],
+            TpchTableKind::PartSupp => vec![
+                ("ps_partkey", int64()),
+                ("ps_suppkey", int64()),
+                ("ps_availqty", int64()),
+                ("ps_supplycost", price()),
+                ("ps_comment", string()),
+            ],
+            TpchTableKind::Customer => vec![
+                ("c_custkey", int64()),
+                ("c_name", string()),
+                ("c_address", string()),
+                ("c_nationkey", int32()),
+                ("c_phone", string()),
+                ("c_acctbal", price()),
+                ("c_mktsegment", string()),
+                ("c_comment", string()),
+            ],
+            TpchTableKind::Orders => vec![
+                ("o_orderkey", int64()),
+                ("o_custkey", int64()),
+                ("o_orderstatus", string()),
+                ("o_totalprice", price()),
+                ("o_orderdate", date()),
+                ("o_orderpriority", string()),
+                ("o_clerk", string()),
+                ("o_shippriority", int32()),
+                ("o_comment", string()),
+            ],
+            TpchTableKind::LineItem => vec![
+                ("l_orderkey", int64()),
+                ("l_partkey", int64()),
+                ("l_suppkey", int64()),
+                ("l_linenumber", int64()),
+                ("l_quantity", price()),
+                ("l_extendedprice", price()),
+                ("l_discount", price()),
+                ("l_tax", price()),
+                ("l_returnflag", string()),
+                ("l_linestatus", string()),
+                ("l_shipdate", date()),
+                ("l_commitdate", date()),
+                ("l_receiptdate", date()),
+                ("l_shipinstruct", string()),
+                ("l_shipmode", string()),
+                ("l_comment", string()),
+            ],
+        };
+        TableSchemaRefExt::create(
+            fields
+                .into_iter()
+                .map(|(name, ty)| TableField::new(name, ty))
+                .collect(),
+        )
+    }
+
+    /// The number of generation units of the table: a unit is a row, except for `partsupp`
+    /// and `lineitem` whose rows are generated by part and by order.
+    pub fn num_units(&self, scale: f64) -> u64 {
+        match self {
+            TpchTableKind::Region => REGIONS.len() as u64,
+            TpchTableKind::Nation => NATIONS.len() as u64,
+            TpchTableKind::Part | TpchTableKind::PartSupp => num_parts(scale),
+            TpchTableKind::Supplier => num_suppliers(scale),
+            TpchTableKind::Customer => num_customers(scale),
+            TpchTableKind::Orders | TpchTableKind::LineItem => num_orders(scale),
+        }
+    }
+
+    /// The average number of rows generated by a unit.
+    pub fn rows_per_unit(&self) -> u64 {
+        match self {
+            TpchTableKind::PartSupp | TpchTableKind::LineItem => 4,
+            _ => 1,
+        }
+    }
+
+    /// The exact number of rows of the table, `None` for `lineitem`
+    /// whose orders have a random number of line items.
+    pub fn num_rows(&self, scale: f64) -> Option<u64> {
+        match self {
+            TpchTableKind::LineItem => None,
+            _ => self.num_units(scale).checked_mul(self.rows_per_unit()),
+        }
+    }
+
+    /// Generate the rows of the units `[begin, end)`.
+    pub fn generate(&self, scale: f64, begin: u64, end: u64) -> DataBlock {
+        match self {
+            TpchTableKind::Region => generate_region(begin, end),
+            TpchTableKind::Nation => generate_nation(begin, end),
+            TpchTableKind::Part => generate_part(begin, end),
+            TpchTableKind::Supplier => generate_supplier(begin, end),
+            TpchTableKind::PartSupp => generate_partsupp(scale, begin, end),
+            TpchTableKind::Customer => generate_customer(begin, end),
+            TpchTableKind::Orders => generate_orders(scale, begin, end),
+            TpchTableKind::LineItem => generate_lineitem(scale, begin, end),
+        }
+    }
+}
+
+/// The largest scale factor defined by TPC-H, also keeps the row counts and keys far from overflowing.
+pub const MAX_SCALE: f64 = 100_000.0;
+
+fn scaled(base: u64, scale: f64) -> u64 {
+    ((base as f64 * scale) as u64).max(1)
+}
+
+fn num_parts(scale: f64) -> u64 {
+    scaled(200_000, scale)
+}
+
+fn num_suppliers(scale: f64) -> u64 {
+    scaled(10_000, scale)
+}
+
+fn num_customers(scale: f64) -> u64 {
+    scaled(150_000, scale)
+}
+
+fn num_orders(scale: f64) -> u64 {
+    scaled(1_500_000, scale)
+}
+
+fn row_rng(kind: TpchTableKind, key: u64) -> SmallRng {
+    SmallRng::seed_from_u64(((kind as u64) << 56) ^ key)
+}
+
+fn text(rng: &mut SmallRng, min_words: usize, max_words: usize) -> String {
+    let n = rng.gen_range(min_words..=max_words);
+    (0..n)
+        .map(|_| *WORDS.choose(rng).unwrap())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn address(rng: &mut SmallRng) -> String {
+    let len = rng.gen_range(10..=40);
+    rng.sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
+
+fn phone(rng: &mut SmallRng, nation_key: i32) -> String {
+    format!(
+        "{}-{}-{}-{}",
+        nation_key + 10,
+        rng.gen_range(100..=999),
+        rng.gen_range(100..=999),
+        rng.gen_range(1000..=9999)
+    )
+}
+
+fn acctbal(rng: &mut SmallRng) -> i128 {
+    rng.gen_range(-99_999..=999_999)
+}
+
+/// The retail price of a part in cents, as defined by the specification.
+fn retail_price(part_key: u64) -> i128 {
+    (90_000 + ((part_key / 10) % 20_001) + 100 * (part_key % 1_000)) as i128
+}
+
+/// The `i`-th of the 4 suppliers of a part, as defined by the specification.
+fn part_supplier(part_key: u64, i: u64, num_suppliers: u64) -> u64 {
+    (part_key + i * (num_suppliers / 4 + (part_key - 1) / num_suppliers)) % num_suppliers + 1
+}
+
+fn generate_region(begin: u64, end: u64) -> DataBlock {
+    let mut keys = vec![];
+    let mut names = vec![];
+    let mut comments = vec![];
+    for key in begin..end {
+        let mut rng = row_rng(TpchTableKind::Region, key);
+        keys.push(key as i32);
+        names.push(REGIONS[key as usize].to_string());
+        comments.push(text(&mut rng, 5, 15));
+    }
+    DataBlock::new_from_columns(vec![
+        Int32Type::from_data(keys),
+        StringType::from_data(names),
+        StringType::from_data(comments),
+    ])
+}
+
+fn generate_nation(begin: u64, end: u64) -> DataBlock {
+    let mut keys = vec![];
+    let mut names = vec![];
+    let mut region_keys = vec![];
+    let mut comments = vec![];
+    for key in begin..end {
+        let mut rng = row_rng(TpchTableKind::Nation, key);
+        let (name, region_key) = NATIONS[key as usize];
+        keys.push(key as i32);
+        names.push(name.to_string());
+        region_keys.push(region_key);
+        comments.push(text(&mut rng, 5, 15));
+    }
+    DataBlock::new_from_columns(vec![
+        Int32Type::from_data(keys),
+        StringType::from_data(names),
+        Int32Type::from_data(region_keys),
+        StringType::from_data(comments),
+    ])
+}
+
+fn generate_part(begin: u64, end: u64) -> DataBlock {
+    let len = (end - begin) as usize;
+    let mut keys = Vec::with_capacity(len);
+    let mut names = Vec::with_capacity(len);
+    let mut mfgrs = Vec::with_capacity(len);
+    let mut brands = Vec::with_capacity(len);
+    let mut types = Vec::with_capacity(len);
+    let mut sizes = Vec::with_capacity(len);
+    let mut containers = Vec::with_capacity(len);
+    let mut prices = Vec::with_capacity(len);
+    let mut comments = Vec::with_capacity(len);
+    for unit in begin..end {
+        let key = unit + 1;
+        let mut rng = row_rng(TpchTableKind::Part, key);
+        let mfgr = rng.gen_range(1..=5);
+        keys.push(key as i64);
+        names.push(
+            COLORS
+                .choose_multiple(&mut rng, 5)
+                .copied()
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        mfgrs.push(format!("Manufacturer#{mfgr}"));
+        brands.push(format!("Brand#{mfgr}{}", rng.gen_range(1..=5)));
+        types.push(format!(
+            "{} {} {}",
+            TYPE_SYLLABLES_1.choose(&mut rng).unwrap(),
+            TYPE_SYLLABLES_2.choose(&mut rng).unwrap(),
+            TYPE_SYLLABLES_3.choose(&mut rng).unwrap()
+        ));
+        sizes.push(rng.gen_range(1..=50));
+        containers.push(format!(
+            "{} {}",
+            CONTAINER_SYLLABLES_1.choose(&mut rng).unwrap(),
+            CONTAINER_SYLLABLES_2.choose(&mut rng).unwrap()
+        ));
+        prices.push(retail_price(key));
+        comments.push(text(&mut rng, 1, 4));
+    }
+    DataBlock::new_from_columns(vec![
+        Int64Type::from_data(keys),
+        StringType::from_data(names),
+        StringType::from_data(mfgrs),
+        StringType::from_data(brands),
+        StringType::from_data(types),
+        Int32Type::from_data(sizes),
+        StringType::from_data(containers),
+        Decimal128Type::from_data_with_size(prices, PRICE_SIZE),
+        StringType::from_data(comments),
+    ])
+}
+
+fn generate_supplier(begin: u64, end: u64) -> DataBlock {
+    let len = (end - begin) as usize;
+    let mut keys = Vec::with_capacity(len);
+    let mut names = Vec::with_capacity(len);
+    let mut addresses = Vec::with_capacity(len);
+    let mut nation_keys = Vec::with_capacity(len);
+    let mut phones = Vec::with_capacity(len);
+    let mut acctbals = Vec::with_capacity(len);
+    let mut comments = Vec::with_capacity(len);
+    for unit in begin..end {
+        let key = unit + 1;
+        let mut rng = row_rng(TpchTableKind::Supplier, key);
+        let nation_key = rng.gen_range(0..NATIONS.len() as i32);
+        let mut comment = text(&mut rng, 5, 15);
+        // Q16 excludes the suppliers with complaints, about 5 in 10000.
+        if rng.gen_ratio(5, 10_000) {
+            comment = format!("Customer {comment} Complaints");
+        }
+        keys.push(key as i64);
+        names.push(format!("Supplier#{key:09}"));
+        addresses.push(address(&mut rng));
+        nation_keys.push(nation_key);
+        phones.push(phone(&mut rng, nation_key));
+        acctbals.push(acctbal(&mut rng));
+        comments.push(comment);
+    }
+    DataBlock::new_from_columns(vec![
+        Int64Type::from_data(keys),
+        StringType::from_data(names),
+        StringType::from_data(addresses),
+        Int32Type::from_data(nation_keys),
+        StringType::from_data(phones),
+        Decimal128Type::from_data_with_size(acctbals, PRICE_SIZE),
+        StringType::from_data(comments),
+    ])
+}
+
+fn generate_partsupp(scale: f64, begin: u64, end: u64) -> DataBlock {
+    let num_suppliers = num_suppliers(scale);
+    let len = (end - begin) as usize * 4;
+    let mut part_keys = Vec::with_capacity(len);
+    let mut supp_keys = Vec::with_capacity(len);
+    let mut availqtys = Vec::with_capacity(len);
+    let mut supplycosts = Vec::with_capacity(len);
+    let mut comments = Vec::with_capacity(len);
+    for unit in begin..end {
+        let part_key = unit + 1;
+        let mut rng = row_rng(TpchTableKind::PartSupp, part_key);
+        for i in 0..4 {
+            part_keys.push(part_key as i64);
+            supp_keys.push(part_supplier(part_key, i, num_suppliers) as i64);
+            availqtys.push(rng.gen_range(1..=9_999));
+            supplycosts.push(rng.gen_range(100..=100_000));
+            comments.push(text(&mut rng, 10, 30));
+        }
+    }
+    DataBlock::new_from_columns(vec![
+        Int64Type::from_data(part_keys),
+        Int64Type::from_data(supp_keys),
+        Int64Type::from_data(availqtys),
+        Decimal128Type::from_data_with_size(supplycosts, PRICE_SIZE),
+        StringType::from_data(comments),
+    ])
+}
+
+fn generate_customer(begin: u64, end: u64) -> DataBlock {
+    let len = (end - begin) as usize;
+    let mut keys = Vec::with_capacity(len);
+    let mut names = Vec::with_capacity(len);
+    let mut addresses = Vec::with_capacity(len);
+    let mut nation_keys = Vec::with_capacity(len);
+    let mut phones = Vec::with_capacity(len);
+    let mut acctbals = Vec::with_capacity(len);
+    let mut segments = Vec::with_capacity(len);
+    let mut comments = Vec::with_capacity(len);
+    for unit in begin..end {
+        let key = unit + 1;
+        let mut rng = row_rng(TpchTableKind::Customer, key);
+        let nation_key = rng.gen_range(0..NATIONS.len() as i32);
+        keys.push(key as i64);
+        names.push(format!("Customer#{key:09}"));
+        addresses.push(address(&mut rng));
+        nation_keys.push(nation_key);
+        phones.push(phone(&mut rng, nation_key));
+        acctbals.push(acctbal(&mut rng));
+        segments.push(SEGMENTS.choose(&mut rng).unwrap().to_string());
+        comments.push(text(&mut rng, 5, 15));
+    }
+    DataBlock::new_from_columns(vec![
+        Int64Type::from_data(keys),
+        StringType::from_data(names),
+        StringType::from_data(addresses),
+        Int32Type::from_data(nation_keys),
+        StringType::from_data(phones),
+        Decimal128Type::from_data_with_size(acctbals, PRICE_SIZE),
+        StringType::from_data(segments),
+        StringType::from_data(comments),
+    ])
+}
+
+struct Order {
+    key: u64,
+    cust_key: u64,
+    status: &'static str,
+    total_price: i128,
+    date: i32,
+    priority: &'static str,
+    clerk: String,
+    comment: String,
+    lines: Vec<LineItem>,
+}
+
+struct LineItem {
+    part_key: u64,
+    supp_key: u64,
+    quantity: i128,
+    extended_price: i128,
+    discount: i128,
+    tax: i128,
+    return_flag: &'static str,
+    line_status: &'static str,
+    ship_date: i32,
+    commit_date: i32,
+    receipt_date: i32,
+    ship_instruct: &'static str,
+    ship_mode: &'static str,
+    comment: String,
+}
+
+/// Generate an order with its line items, which share the RNG of the order so that
+/// `o_totalprice` and `o_orderstatus` are consistent with `lineitem`.
+fn generate_order(scale: f64, key: u64) -> Order {
+    let num_parts = num_parts(scale);
+    let num_suppliers = num_suppliers(scale);
+    let num_customers = num_customers(scale);
+    let num_clerks = scaled(1_000, scale);
+    let mut rng = row_rng(TpchTableKind::Orders, key);
+
+    // A third of the customers never place an order.
+    let mut cust_key = rng.gen_range(1..=num_customers);
+    if cust_key % 3 == 0 {
+        cust_key -= 1;
+    }
+    let date = rng.gen_range(START_DATE..=END_DATE - 151);
+    let priority = *PRIORITIES.choose(&mut rng).unwrap();
+    let clerk = format!("Clerk#{:09}", rng.gen_range(1..=num_clerks));
+    let comment = text(&mut rng, 5, 15);
+
+    let num_lines = rng.gen_range(1..=7);
+    let mut lines = Vec::with_capacity(num_lines);
+    for _ in 0..num_lines {
+        let part_key = rng.gen_range(1..=num_parts);
+        let supp_key = part_supplier(part_key, rng.gen_range(0..4), num_suppliers);
+        let quantity = rng.gen_range(1..=50);
+        let ship_date = date + rng.gen_range(1..=121);
+        let receipt_date = ship_date + rng.gen_range(1..=30);
+        let return_flag = if receipt_date <= CURRENT_DATE {
+            if rng.gen_bool(0.5) {
+                "R"
+            } else {
+                "A"
+            }
+        } else {
+            "N"
+        };
+        lines.push(LineItem {
+            part_key,
+            supp_key,
+            quantity: quantity * 100,
+            extended_price: quantity * retail_price(part_key),
+            discount: rng.gen_range(0..=10),
+            tax: rng.gen_range(0..=8),
+            return_flag,
+            line_status: if ship_date > CURRENT_DATE { "O" } else { "F" },
+            ship_date,
+            commit_date: date + rng.gen_range(30..=90),
+            receipt_date,
+            ship_instruct: *INSTRUCTIONS.choose(&mut rng).unwrap(),
+            ship_mode: *MODES.choose(&mut rng).unwrap(),
+            comment: text(&mut rng, 2, 6),
+        });
+    }
+
+    // sum(l_extendedprice * (1 + l_tax) * (1 - l_discount)), rounded to cents.
+    let total_price = lines
+        .iter()
+        .map(|l| l.extended_price * (100 + l.tax) * (100 - l.discount) / 10_000)
+        .sum();
+    let status = if lines.iter().all(|l| l.line_status == "F") {
+        "F"
+    } else if lines.iter().all(|l| l.line_status == "O") {
+        "O"
+    } else {
+        "P"
+    };
+
+    Order {
+        key,
+        cust_key,
+        status,
+        total_price,
+        date,
+        priority,
+        clerk,
+        comment,
+        lines,
+    }
+}
+
+fn generate_orders(scale: f64, begin: u64, end: u64) -> DataBlock {
+    let len = (end - begin) as usize;
+    let mut keys = Vec::with_capacity(len);
+    let mut cust_keys = Vec::with_capacity(len);
+    let mut statuses = Vec::with_capacity(len);
+    let mut total_prices = Vec::with_capacity(len);
+    let mut dates = Vec::with_capacity(len);
+    let mut priorities = Vec::with_capacity(len);
+    let mut clerks = Vec::with_capacity(len);
+    let mut ship_priorities = Vec::with_capacity(len);
+    let mut comments = Vec::with_capacity(len);
+    for unit in begin..end {
+        let order = generate_order(scale, unit + 1);
+        keys.push(order.key as i64);
+        cust_keys.push(order.cust_key as i64);
+        statuses.push(order.status);
+        total_prices.push(order.total_price);
+        dates.push(order.date);
+        priorities.push(order.priority);
+        clerks.push(order.clerk);
+        ship_priorities.push(0);
+        comments.push(order.comment);
+    }
+    DataBlock::new_from_columns(vec![
+        Int64Type::from_data(keys),
+        Int64Type::from_data(cust_keys),
+        StringType::from_data(statuses),
+        Decimal128Type::from_data_with_size(total_prices, PRICE_SIZE),
+        DateType::from_data(dates),
+        StringType::from_data(priorities),
+        StringType::from_data(clerks),
+        Int32Type::from_data(ship_priorities),
+        StringType::from_data(comments),
+    ])
+}
+
+fn generate_lineitem(scale: f64, begin: u64, end: u64) -> DataBlock {
+    let len = (end - begin) as usize * 4;
+    let mut order_keys = Vec::with_capacity(len);
+    let mut part_keys = Vec::with_capacity(len);
+    let mut supp_keys = Vec::with_capacity(len);
+    let mut line_numbers = Vec::with_capacity(len);
+    let mut quantities = Vec::with_capacity(len);
+    let mut extended_prices = Vec::with_capacity(len);
+    let mut discounts = Vec::with_capacity(len);
+    let mut taxes = Vec::with_capacity(len);
+    let mut return_flags = Vec::with_capacity(len);
+    let mut line_statuses = Vec::with_capacity(len);
+    let mut ship_dates = Vec::with_capacity(len);
+    let mut commit_dates = Vec::with_capacity(len);
+    let mut receipt_dates = Vec::with_capacity(len);
+    let mut ship_instructs = Vec::with_capacity(len);
+    let mut ship_modes = Vec::with_capacity(len);
+    let mut comments = Vec::with_capacity(len);
+    for unit in begin..end {
+        let order = generate_order(scale, unit + 1);
+        for (i, line) in order.lines.into_iter().enumerate() {
+            order_keys.push(order.key as i64);
+            part_keys.push(line.part_key as i64);
+            supp_keys.push(line.supp_key as i64);
+            line_numbers.push(i as i64 + 1);
+            quantities.push(line.quantity);
+            extended_prices.push(line.extended_price);
+            discounts.push(line.discount);
+            taxes.push(line.tax);
+            return_flags.push(line.return_flag);
+            line_statuses.push(line.line_status);
+            ship_dates.push(line.ship_date);
+            commit_dates.push(line.commit_date);
+            receipt_dates.push(line.receipt_date);
+            ship_instructs.push(line.ship_instruct);
+            ship_modes.push(line.ship_mode);
+            comments.push(line.comment);
+        }
+    }
+    DataBlock::new_from_columns(vec![
+        Int64Type::from_data(order_keys),
+        Int64Type::from_data(part_keys),
+        Int64Type::from_data(supp_keys),
+        Int64Type::from_data(line_numbers),
+        Decimal128Type::from_data_with_size(quantities, PRICE_SIZE),
+        Decimal128Type::from_data_with_size(extended_prices, PRICE_SIZE),
+        Decimal128Type::from_data_with_size(discounts, PRICE_SIZE),
+        Decimal128Type::from_data_with_size(taxes, PRICE_SIZE),
+        StringType::from_data(return_flags),
+        StringType::from_data(line_statuses),
+        DateType::from_data(ship_dates),
+        DateType::from_data(commit_dates),
+        DateType::from_data(receipt_dates),
+        StringType::from_data(ship_instructs),
+        StringType::from_data(ship_modes),
+        StringType::from_data(comments),
+    ])
+}
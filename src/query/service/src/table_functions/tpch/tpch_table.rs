@@ -0,0 +1,265 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::TableStatistics;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_number;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::F64;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_core::SourcePipeBuilder;
+use databend_common_pipeline_sources::EmptySource;
+use databend_common_pipeline_sources::SyncSource;
+use databend_common_pipeline_sources::SyncSourcer;
+use databend_storages_common_table_meta::table::ChangeType;
+
+use super::tpch_generator::TpchTableKind;
+use super::tpch_generator::MAX_SCALE;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::ProcessorPtr;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+use crate::table_functions::generate_numbers_parts;
+use crate::table_functions::NumbersPartInfo;
+use crate::table_functions::TableFunction;
+
+/// `tpch_region(scale)`, `tpch_nation(scale)`, ..., `tpch_lineitem(scale)` generate the
+/// tables of TPC-H at the given scale factor on the fly, e.g.
+/// `INSERT INTO lineitem SELECT * FROM tpch_lineitem(1)`.
+pub struct TpchTable {
+    table_info: TableInfo,
+    kind: TpchTableKind,
+    scale: f64,
+}
+
+impl TpchTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(table_func_name, Some(1))?;
+        let scale = check_number::<_, F64>(
+            None,
+            &FunctionContext::default(),
+            &Expr::<usize>::Constant {
+                span: None,
+                scalar: args[0].clone(),
+                data_type: args[0].as_ref().infer_data_type(),
+            },
+            &BUILTIN_FUNCTIONS,
+        )?
+        .into_inner();
+        if !scale.is_finite() || scale <= 0.0 || scale > MAX_SCALE {
+            return Err(ErrorCode::BadArguments(format!(
+                "The scale factor of {} must be in (0, {}], but got {}",
+                table_func_name, MAX_SCALE, scale
+            )));
+        }
+        let kind = TpchTableKind::from_func_name(table_func_name).unwrap();
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: kind.schema(),
+                engine: kind.engine().to_string(),
+                created_on: DateTime::from_timestamp(0, 0).unwrap(),
+                updated_on: DateTime::from_timestamp(0, 0).unwrap(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(TpchTable {
+            table_info,
+            kind,
+            scale,
+        }))
+    }
+
+    /// The number of units generated by a block, about `max_block_size` rows.
+    fn units_per_block(&self, max_block_size: u64) -> u64 {
+        (max_block_size / self.kind.rows_per_unit()).max(1)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for TpchTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let units = self.kind.num_units(self.scale);
+        let rows = units
+            .checked_mul(self.kind.rows_per_unit())
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "The scale factor {} of {} is too large",
+                    self.scale, self.table_info.name
+                ))
+            })?;
+        let units_per_block = self.units_per_block(ctx.get_settings().get_max_block_size()?);
+
+        let blocks = units / units_per_block + 1;
+        let statistics =
+            PartStatistics::new_estimated(None, rows as usize, 0, blocks as usize, blocks as usize);
+
+        let cluster = ctx.get_cluster();
+        let mut worker_num = ctx.get_settings().get_max_threads()?;
+        worker_num = match worker_num > blocks {
+            true => blocks,
+            false => worker_num * cluster.nodes.len() as u64,
+        };
+
+        let parts = generate_numbers_parts(0, worker_num, units);
+        Ok((statistics, parts))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![Scalar::Number(
+            NumberScalar::Float64(self.scale.into()),
+        )]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        if plan.parts.partitions.is_empty() {
+            pipeline.add_source(EmptySource::create, 1)?;
+            return Ok(());
+        }
+
+        let units_per_block = self.units_per_block(ctx.get_settings().get_max_block_size()?);
+        let mut source_builder = SourcePipeBuilder::create();
+        for part in &plan.parts.partitions {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                TpchSource::create(
+                    output,
+                    ctx.clone(),
+                    part,
+                    self.kind,
+                    self.scale,
+                    units_per_block,
+                )?,
+            );
+        }
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
+    async fn table_statistics(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _require_fresh: bool,
+        _change_type: Option<ChangeType>,
+    ) -> Result<Option<TableStatistics>> {
+        Ok(Some(TableStatistics {
+            num_rows: self.kind.num_rows(self.scale),
+            ..Default::default()
+        }))
+    }
+}
+
+struct TpchSource {
+    kind: TpchTableKind,
+    scale: f64,
+    begin: u64,
+    end: u64,
+    step: u64,
+}
+
+impl TpchSource {
+    pub fn create(
+        output: Arc<OutputPort>,
+        ctx: Arc<dyn TableContext>,
+        part: &PartInfoPtr,
+        kind: TpchTableKind,
+        scale: f64,
+        step: u64,
+    ) -> Result<ProcessorPtr> {
+        let part = NumbersPartInfo::from_part(part)?;
+        SyncSourcer::create(ctx, output, TpchSource {
+            kind,
+            scale,
+            begin: part.part_start,
+            end: part.part_end,
+            step,
+        })
+    }
+}
+
+impl SyncSource for TpchSource {
+    const NAME: &'static str = "TpchSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.begin >= self.end {
+            return Ok(None);
+        }
+        let end = std::cmp::min(self.end, self.begin + self.step);
+        let block = self.kind.generate(self.scale, self.begin, end);
+        self.begin = end;
+        Ok(Some(block))
+    }
+}
+
+impl TableFunction for TpchTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -92,6 +92,26 @@ ADDRESS = 'https://databend.com';"
         self
     }
 
+    pub fn admin_api_enable_debug(mut self, value: bool) -> ConfigBuilder {
+        self.conf.query.admin_api_enable_debug = value;
+        self
+    }
+
+    pub fn admin_api_auth_token(mut self, value: impl Into<String>) -> ConfigBuilder {
+        self.conf.query.admin_api_auth_token = value.into();
+        self
+    }
+
+    pub fn admin_api_read_only_token(mut self, value: impl Into<String>) -> ConfigBuilder {
+        self.conf.query.admin_api_read_only_token = value.into();
+        self
+    }
+
+    pub fn max_running_queries_per_user(mut self, value: u64) -> ConfigBuilder {
+        self.conf.query.max_running_queries_per_user = value;
+        self
+    }
+
     pub fn parquet_fast_read_bytes(mut self, value: u64) -> ConfigBuilder {
         self.conf.query.parquet_fast_read_bytes = Some(value);
         self
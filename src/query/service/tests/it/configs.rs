@@ -993,6 +993,40 @@ fn test_env_config_obsoleted() -> Result<()> {
     Ok(())
 }
 
+/// Unknown keys in the config file are rejected with the name of the key.
+#[test]
+fn test_file_config_unknown_key() -> Result<()> {
+    let file_path = temp_dir().join("databend_test_file_config_unknown_key.toml");
+
+    let mut f = fs::File::create(&file_path)?;
+    f.write_all(
+        r#"
+[log.file]
+levle = "INFO"
+"#
+        .as_bytes(),
+    )?;
+
+    // Make sure all data flushed.
+    f.flush()?;
+
+    temp_env::with_vars(
+        vec![("CONFIG_FILE", Some(file_path.to_string_lossy().as_ref()))],
+        || {
+            let r = InnerConfig::load_for_test();
+            assert!(r.is_err(), "expecting `Err`, but got `Ok`");
+            let err = r.unwrap_err();
+            assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+            assert!(err.message().contains("levle"), "{}", err.message());
+        },
+    );
+
+    // remove temp file
+    fs::remove_file(file_path)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_env_cache_config_and_defaults() -> Result<()> {
     // test if one of the cache config option is overridden by environment variable
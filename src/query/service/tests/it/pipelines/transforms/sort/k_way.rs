@@ -57,6 +57,7 @@ fn create_pipeline(
         enable_queries_executor: false,
         max_threads: 8,
         executor_node_id: "".to_string(),
+        mem_stat: None,
     };
     let executor = QueryPipelineExecutor::create(pipeline, settings)?;
     Ok((executor, rx))
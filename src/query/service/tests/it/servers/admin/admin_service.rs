@@ -148,3 +148,91 @@ async fn test_http_service_tls_server_mutual_tls_failed() -> Result<()> {
     assert!(resp.is_err());
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_http_service_debug_endpoints() -> Result<()> {
+    for enable_debug in [true, false] {
+        let address_str = format!("127.0.0.1:{}", get_free_tcp_port());
+        let mut srv = AdminService::create(
+            &ConfigBuilder::create()
+                .admin_api_enable_debug(enable_debug)
+                .build(),
+        );
+        let listening = srv.start(address_str.parse()?).await?;
+
+        let url = format!("http://127.0.0.1:{}/debug/mem", listening.port());
+        let resp = reqwest::get(url).await.unwrap();
+        assert_eq!(resp.status().is_success(), enable_debug);
+
+        srv.shutdown(true).await;
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_http_service_auth_token() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let address_str = format!("127.0.0.1:{}", get_free_tcp_port());
+    let mut srv = AdminService::create(
+        &ConfigBuilder::create()
+            .admin_api_auth_token("admin-token")
+            .admin_api_read_only_token("read-only-token")
+            .build(),
+    );
+    let listening = srv.start(address_str.parse()?).await?;
+
+    let base = format!("http://127.0.0.1:{}", listening.port());
+    let client = reqwest::Client::new();
+
+    // probes do not need the token
+    let resp = client
+        .get(format!("{base}/v1/health"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+
+    let resp = client
+        .get(format!("{base}/v1/config"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let resp = client
+        .get(format!("{base}/v1/config"))
+        .bearer_auth("wrong-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let resp = client
+        .get(format!("{base}/v1/config"))
+        .bearer_auth("read-only-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+
+    let resp = client
+        .delete(format!("{base}/v1/query/unknown"))
+        .bearer_auth("read-only-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::FORBIDDEN);
+
+    let resp = client
+        .delete(format!("{base}/v1/query/unknown"))
+        .bearer_auth("admin-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::NOT_FOUND);
+
+    srv.shutdown(true).await;
+    Ok(())
+}
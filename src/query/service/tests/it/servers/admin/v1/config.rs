@@ -13,7 +13,12 @@
 // limitations under the License.
 
 use databend_common_base::base::tokio;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
 use databend_query::servers::admin::v1::config::config_handler;
+use databend_query::servers::admin::v1::config::reload_config;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
 use databend_query::test_kits::*;
 use http::Method;
 use http::StatusCode;
@@ -44,3 +49,48 @@ async fn test_config() -> databend_common_exception::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_config_reload() -> databend_common_exception::Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let active_sessions = SessionManager::instance().processes_info().len() as u64;
+    let mut new_config = GlobalConfig::instance().as_ref().clone();
+    new_config.query.max_active_sessions = active_sessions + 1;
+    let reloaded = reload_config(&new_config)?;
+    assert_eq!(reloaded, vec!["query.max_active_sessions".to_string()]);
+    assert_eq!(
+        GlobalConfig::instance().query.max_active_sessions,
+        active_sessions + 1
+    );
+
+    // Reloading the applied config again changes nothing.
+    let reloaded = reload_config(&new_config)?;
+    assert!(reloaded.is_empty());
+
+    // The reloaded max_active_sessions takes effect.
+    let _session = fixture.new_session_with_type(SessionType::MySQL).await?;
+    let res = fixture.new_session_with_type(SessionType::MySQL).await;
+    assert_eq!(
+        res.err().unwrap().code(),
+        ErrorCode::TOO_MANY_USER_CONNECTIONS
+    );
+
+    // The levels of the file and stderr loggers are reloaded separately.
+    let running_level = new_config.log.stderr.level.clone();
+    new_config.log.stderr.level = "WARN".to_string();
+    let reloaded = reload_config(&new_config)?;
+    assert_eq!(reloaded, vec!["log.stderr.level".to_string()]);
+    assert_eq!(GlobalConfig::instance().log.stderr.level, "WARN");
+
+    new_config.log.stderr.level = running_level;
+    let reloaded = reload_config(&new_config)?;
+    assert_eq!(reloaded, vec!["log.stderr.level".to_string()]);
+
+    // The non-reloadable fields are rejected.
+    new_config.query.http_handler_port += 1;
+    let res = reload_config(&new_config);
+    assert_eq!(res.unwrap_err().code(), ErrorCode::INVALID_CONFIG);
+
+    Ok(())
+}
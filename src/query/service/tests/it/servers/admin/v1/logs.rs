@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::SystemTime;
+
+use bytes::Bytes;
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_tracing::set_log_level_override;
+use databend_query::servers::admin::v1::logs::get_log_level_handler;
+use databend_query::servers::admin::v1::logs::logs_handler;
+use databend_query::servers::admin::v1::logs::set_log_level_handler;
+use databend_query::servers::admin::v1::logs::LogLevel;
+use databend_query::test_kits::*;
+use futures::Stream;
+use futures::StreamExt;
+use http::header;
+use http::Method;
+use http::StatusCode;
+use http::Uri;
+use poem::get;
+use poem::Endpoint;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_logs_tail() -> Result<()> {
+    let log_dir = tempfile::tempdir()?;
+    std::fs::write(log_dir.path().join("databend-query.log"), "a\nb\nc\n")?;
+
+    let mut config = ConfigBuilder::create().build();
+    config.log.file.dir = log_dir.path().to_str().unwrap().to_string();
+    let _fixture = TestFixture::setup_with_config(&config).await?;
+
+    let ep = Route::new().at("/v1/logs", get(logs_handler));
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/v1/logs?lines=2"))
+                .method(Method::GET)
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    assert_eq!(body, "b\nc\n");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_logs_follow_rotated_file() -> Result<()> {
+    let log_dir = tempfile::tempdir()?;
+    std::fs::write(log_dir.path().join("databend-query.log.1"), "a\n")?;
+
+    let mut config = ConfigBuilder::create().build();
+    config.log.file.dir = log_dir.path().to_str().unwrap().to_string();
+    let _fixture = TestFixture::setup_with_config(&config).await?;
+
+    let ep = Route::new().at("/v1/logs", get(logs_handler));
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/v1/logs?lines=1&follow=true"))
+                .method(Method::GET)
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut body = Box::pin(response.into_body().into_bytes_stream());
+    let mut received = String::new();
+    wait_for_line(&mut body, &mut received, "data: a").await;
+
+    // The logger rolls over to a newer file.
+    let rotated = log_dir.path().join("databend-query.log.2");
+    std::fs::write(&rotated, "b\n")?;
+    std::fs::File::options()
+        .write(true)
+        .open(&rotated)?
+        .set_modified(SystemTime::now() + Duration::from_secs(60))?;
+    wait_for_line(&mut body, &mut received, "data: b").await;
+
+    Ok(())
+}
+
+async fn wait_for_line(
+    body: &mut (impl Stream<Item = std::io::Result<Bytes>> + Unpin),
+    received: &mut String,
+    line: &str,
+) {
+    while !received.contains(line) {
+        let chunk = tokio::time::timeout(Duration::from_secs(10), body.next())
+            .await
+            .expect("timeout waiting for the log line")
+            .unwrap()
+            .unwrap();
+        received.push_str(&String::from_utf8_lossy(&chunk));
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_log_level() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = Route::new().at(
+        "/v1/logs/level",
+        get(get_log_level_handler).put(set_log_level_handler),
+    );
+
+    let set_level = |body: &'static str| {
+        Request::builder()
+            .uri(Uri::from_static("/v1/logs/level"))
+            .header(header::CONTENT_TYPE, "application/json")
+            .method(Method::PUT)
+            .body(body)
+    };
+
+    let response = ep.call(set_level(r#"{"level": "debug"}"#)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/v1/logs/level"))
+                .method(Method::GET)
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_vec().await.unwrap();
+    let level = serde_json::from_slice::<LogLevel>(&body).unwrap();
+    assert_eq!(level, LogLevel {
+        level: Some("DEBUG".to_string())
+    });
+
+    let response = ep.call(set_level(r#"{"level": "verbose"}"#)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = ep.call(set_level(r#"{"level": null}"#)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    set_log_level_override(None);
+
+    Ok(())
+}
@@ -14,4 +14,8 @@
 
 mod cluster;
 mod config;
+mod logs;
+mod processes;
+mod query_profiling;
+mod readiness;
 mod status;
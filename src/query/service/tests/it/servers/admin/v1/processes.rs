@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_query::servers::admin::v1::processes::kill_query_handler;
+use databend_query::servers::admin::v1::processes::processlist_handler;
+use databend_query::servers::admin::v1::processes::ProcessInfo;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::*;
+use http::Method;
+use http::StatusCode;
+use http::Uri;
+use poem::delete;
+use poem::get;
+use poem::Endpoint;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_processlist_and_kill_query() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let ep = Route::new()
+        .at("/v1/processlist", get(processlist_handler))
+        .at("/v1/query/:query_id", delete(kill_query_handler));
+
+    let session = fixture
+        .new_session_with_type(SessionType::HTTPQuery)
+        .await?;
+    let query_ctx = session.create_query_context().await?;
+    let query_id = query_ctx.get_id();
+
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/v1/processlist"))
+                .method(Method::GET)
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_vec().await.unwrap();
+    let processes = serde_json::from_slice::<Vec<ProcessInfo>>(&body).unwrap();
+    assert!(processes
+        .iter()
+        .any(|process| process.current_query_id.as_deref() == Some(query_id.as_str())));
+
+    // Kill the running query.
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(format!("/v1/query/{}", query_id).parse().unwrap())
+                .method(Method::DELETE)
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let err = query_ctx.check_aborting().unwrap_err();
+    assert!(err.message().contains("killed through the admin api"));
+
+    // Unknown query.
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/v1/query/unknown"))
+                .method(Method::DELETE)
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_base::runtime::profile::get_statistics_desc;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_pipeline_core::PlanProfile;
+use databend_query::servers::admin::v1::query_profiling::build_profile_tree;
+use pretty_assertions::assert_eq;
+
+fn plan_profile(id: u32, parent_id: Option<u32>, name: &str, output_rows: usize) -> PlanProfile {
+    let mut statistics = std::array::from_fn(|_| 0);
+    let desc = get_statistics_desc();
+    statistics[desc[&ProfileStatisticsName::OutputRows].index] = output_rows;
+
+    PlanProfile {
+        id: Some(id),
+        name: Some(name.to_string()),
+        parent_id,
+        title: Arc::new(name.to_string()),
+        labels: Arc::new(vec![]),
+        statistics,
+        metrics: BTreeMap::new(),
+        errors: vec![],
+    }
+}
+
+#[test]
+fn test_build_profile_tree() {
+    let profiles = vec![
+        plan_profile(2, Some(0), "TableScan", 100),
+        plan_profile(0, None, "EvalScalar", 10),
+        plan_profile(1, Some(0), "TableScan", 200),
+        plan_profile(3, Some(1), "Filter", 50),
+        // The parent is executed on another node.
+        plan_profile(5, Some(4), "Exchange", 0),
+    ];
+
+    let tree = build_profile_tree(&profiles);
+    assert_eq!(tree.len(), 2);
+
+    let root = &tree[0];
+    assert_eq!(root.id, Some(0));
+    assert_eq!(root.statistics["OutputRows"], 10);
+    assert_eq!(root.statistics["CpuTime"], 0);
+
+    let children = root.children.iter().map(|c| c.id).collect::<Vec<_>>();
+    assert_eq!(children, vec![Some(1), Some(2)]);
+    assert_eq!(root.children[0].children.len(), 1);
+    assert_eq!(root.children[0].children[0].id, Some(3));
+    assert_eq!(root.children[0].children[0].statistics["OutputRows"], 50);
+
+    assert_eq!(tree[1].id, Some(5));
+    assert!(tree[1].children.is_empty());
+}
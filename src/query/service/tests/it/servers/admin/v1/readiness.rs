@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_query::servers::admin::v1::readiness::readiness_handler;
+use databend_query::servers::admin::v1::readiness::ReadinessStatus;
+use databend_query::sessions::SessionManager;
+use databend_query::test_kits::*;
+use http::Method;
+use http::StatusCode;
+use http::Uri;
+use poem::get;
+use poem::Endpoint;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+async fn get_readiness(ep: &Route) -> (StatusCode, ReadinessStatus) {
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/v1/readiness"))
+                .method(Method::GET)
+                .finish(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().into_vec().await.unwrap();
+    let readiness =
+        serde_json::from_str::<ReadinessStatus>(&String::from_utf8_lossy(&body)).unwrap();
+    (status, readiness)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_readiness() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = Route::new().at("/v1/readiness", get(readiness_handler));
+
+    // Not ready before the startup finished.
+    let (status, readiness) = get_readiness(&ep).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert!(!readiness.ready);
+
+    SessionManager::instance().set_ready(true);
+    let (status, readiness) = get_readiness(&ep).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(readiness, ReadinessStatus {
+        ready: true,
+        reason: None
+    });
+
+    // Not ready while draining.
+    SessionManager::instance().set_ready(false);
+    let (status, readiness) = get_readiness(&ep).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert!(!readiness.ready);
+
+    Ok(())
+}
@@ -84,6 +84,9 @@ async fn test_status() -> Result<()> {
         (0, false, false),
         "before running"
     );
+    assert!(!status.cluster_id.is_empty());
+    assert!(!status.node_id.is_empty());
+    assert!(!status.version.is_empty());
 
     let http_session = fixture
         .new_session_with_type(SessionType::HTTPQuery)
@@ -37,6 +37,7 @@ use databend_query::servers::http::v1::query_route;
 use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSessionConf;
 use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::StreamingLoadResponse;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::sessions::QueryAffect;
@@ -731,6 +732,113 @@ async fn test_insert() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_streaming_load() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let route = create_endpoint()?;
+    let (status, result) =
+        post_sql_to_endpoint(&route, "create table t(a int, b string)", 3).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/streaming_load".parse().unwrap())
+        .method(Method::PUT)
+        .typed_header(basic)
+        .header("X-Databend-Table", "t")
+        .header("X-Databend-File-Format", "type = CSV skip_header = 1")
+        .body("a,b\n1,x\n2,y\n3,z\n");
+    let response = route
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    let resp: StreamingLoadResponse = serde_json::from_str(&body)?;
+    assert_eq!(resp.state, "SUCCESS", "{:?}", resp);
+    assert_eq!(resp.stats.rows, 3, "{:?}", resp);
+
+    let (status, result) = post_sql_to_endpoint(&route, "select a, b from t order by a", 3).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    let data = unwrap_data(&result.data, "");
+    assert_eq!(data, [["1", "x"], ["2", "y"], ["3", "z"]]);
+
+    // skip the malformed rows
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/streaming_load".parse().unwrap())
+        .method(Method::PUT)
+        .typed_header(basic)
+        .header("X-Databend-Table", "t")
+        .header("X-Databend-On-Error", "continue")
+        .body("4,w\nx,v\n");
+    let response = route
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    let resp: StreamingLoadResponse = serde_json::from_str(&body)?;
+    assert_eq!(resp.stats.rows, 1, "{:?}", resp);
+
+    // invalid on error mode
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/streaming_load".parse().unwrap())
+        .method(Method::PUT)
+        .typed_header(basic)
+        .header("X-Databend-Table", "t")
+        .header("X-Databend-On-Error", "ignore")
+        .body("5,u\n");
+    let response = route
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // missing target table
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/streaming_load".parse().unwrap())
+        .method(Method::PUT)
+        .typed_header(basic)
+        .body("1,x\n");
+    let response = route
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // the headers are parsed, not pasted into SQL
+    for (table, file_format) in [
+        ("t FROM @~ PURGE = TRUE; DROP TABLE t", "type = CSV"),
+        ("t", "type = CSV) FILES = ('x'"),
+    ] {
+        let basic = headers::Authorization::basic("root", "");
+        let req = Request::builder()
+            .uri("/v1/streaming_load".parse().unwrap())
+            .method(Method::PUT)
+            .typed_header(basic)
+            .header("X-Databend-Table", table)
+            .header("X-Databend-File-Format", file_format)
+            .body("6,s\n");
+        let response = route
+            .call(req)
+            .await
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    let (status, result) = post_sql_to_endpoint(&route, "select count(*) from t", 3).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    let data = unwrap_data(&result.data, "");
+    assert_eq!(data, [["4"]]);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_query_log() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -1312,6 +1420,27 @@ async fn test_http_service_tls_server_mutual_tls_failed() -> Result<()> {
     Ok(())
 }
 
+// client certificates can not be verified, so the handler refuses to start
+#[tokio::test(flavor = "current_thread")]
+async fn test_http_service_tls_server_root_ca_refused() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .http_handler_tls_server_key(TEST_TLS_SERVER_KEY)
+        .http_handler_tls_server_cert(TEST_TLS_SERVER_CERT)
+        .http_handler_tls_server_root_ca_cert(TEST_TLS_CA_CERT)
+        .build();
+    let _fixture = TestFixture::setup_with_config(&config).await?;
+
+    let address_str = format!("127.0.0.1:{}", get_free_tcp_port());
+    let mut srv = HttpHandler::create(HttpHandlerKind::Query);
+    let res = srv.start(address_str.parse()?).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::TLS_CONFIGURATION_FAILURE
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_func_object_keys() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
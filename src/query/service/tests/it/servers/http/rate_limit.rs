@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio;
+use databend_query::servers::http::middleware::RateLimitMiddleware;
+use databend_query::servers::http::middleware::RateLimiter;
+use http::StatusCode;
+use poem::get;
+use poem::handler;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+
+#[test]
+fn test_rate_limiter_token_bucket() {
+    let limiter = RateLimiter::create(2);
+    let now = Instant::now();
+    let client1: IpAddr = "1.1.1.1".parse().unwrap();
+    let client2: IpAddr = "2.2.2.2".parse().unwrap();
+
+    assert!(limiter.try_acquire(client1, now));
+    assert!(limiter.try_acquire(client1, now));
+    assert!(!limiter.try_acquire(client1, now));
+
+    // other clients have their own buckets
+    assert!(limiter.try_acquire(client2, now));
+
+    // refilled with 2 tokens per second
+    let later = now + Duration::from_millis(500);
+    assert!(limiter.try_acquire(client1, later));
+    assert!(!limiter.try_acquire(client1, later));
+
+    // never holds more than 2 tokens
+    let much_later = now + Duration::from_secs(60);
+    assert!(limiter.try_acquire(client1, much_later));
+    assert!(limiter.try_acquire(client1, much_later));
+    assert!(!limiter.try_acquire(client1, much_later));
+}
+
+#[test]
+fn test_rate_limiter_bounded_clients() {
+    let limiter = RateLimiter::create(1);
+    let now = Instant::now();
+    for i in 0..20_000u32 {
+        assert!(limiter.try_acquire(IpAddr::from(i.to_be_bytes()), now));
+    }
+
+    // the recently seen clients are still tracked
+    assert!(!limiter.try_acquire(IpAddr::from(19_999u32.to_be_bytes()), now));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_rate_limit_middleware() {
+    #[handler]
+    fn ok() -> &'static str {
+        "ok"
+    }
+
+    let request = |ip: &str| {
+        Request::builder()
+            .uri_str("/")
+            .header("X-Real-IP", ip)
+            .finish()
+    };
+
+    let ep = Route::new()
+        .at("/", get(ok))
+        .with(RateLimitMiddleware::create("test", 1));
+    let resp = ep.call(request("1.1.1.1")).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let resp = ep.call(request("1.1.1.1")).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(resp.headers().get("Retry-After").unwrap(), "1");
+    // the forwarding headers are set by the client, they don't bypass the limit of the peer
+    let resp = ep.call(request("2.2.2.2")).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    // 0 means unlimited
+    let ep = Route::new()
+        .at("/", get(ok))
+        .with(RateLimitMiddleware::create("test", 0));
+    for _ in 0..10 {
+        let resp = ep.call(request("1.1.1.1")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
@@ -14,6 +14,8 @@
 
 use databend_common_exception::Result;
 use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_settings::Settings;
 use databend_query::servers::MySQLFederated;
 
 #[test]
@@ -23,14 +25,14 @@ fn test_mysql_federated() -> Result<()> {
     //
     {
         let query = "select 1";
-        let result = federated.check(query);
+        let result = federated.check(query)?;
         assert!(result.is_none());
     }
 
     // variables
     {
         let query = "select @@tx_isolation, @@session.tx_isolation";
-        let result = federated.check(query);
+        let result = federated.check(query)?;
         assert!(result.is_some());
 
         if let Some((_, block)) = result {
@@ -49,7 +51,7 @@ fn test_mysql_federated() -> Result<()> {
     // complex variables
     {
         let query = "/* mysql-connector-java-8.0.17 (Revision: 16a712ddb3f826a1933ab42b0039f7fb9eebc6ec) */SELECT  @@session.auto_increment_increment AS auto_increment_increment, @@character_set_client AS character_set_client, @@character_set_connection AS character_set_connection, @@character_set_results AS character_set_results, @@character_set_server AS character_set_server, @@collation_server AS collation_server, @@collation_connection AS collation_connection, @@init_connect AS init_connect, @@interactive_timeout AS interactive_timeout, @@license AS license, @@lower_case_table_names AS lower_case_table_names, @@max_allowed_packet AS max_allowed_packet, @@net_write_timeout AS net_write_timeout, @@performance_schema AS performance_schema, @@sql_mode AS sql_mode, @@system_time_zone AS system_time_zone, @@time_zone AS time_zone, @@transaction_isolation AS transaction_isolation, @@wait_timeout AS wait_timeout;";
-        let result = federated.check(query);
+        let result = federated.check(query)?;
         assert!(result.is_some());
 
         if let Some((_, block)) = result {
@@ -67,3 +69,63 @@ fn test_mysql_federated() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_mysql_federated_session_variables() -> Result<()> {
+    let settings = Settings::create(Tenant::new_literal("test"));
+    let federated = MySQLFederated::create_with_settings(settings.clone());
+
+    // known variables are applied to the session settings
+    {
+        let query = "SET SESSION max_threads = 3, time_zone = 'Asia/Shanghai'";
+        let result = federated.check(query)?;
+        assert!(result.is_some());
+        assert_eq!(settings.get_max_threads()?, 3);
+        assert_eq!(settings.get_timezone()?, "Asia/Shanghai");
+    }
+
+    // unknown variables are tolerated
+    {
+        let query = "SET @@session.foreign_key_checks = 0";
+        let result = federated.check(query)?;
+        assert!(result.is_some());
+    }
+
+    // bad values for known variables are rejected
+    {
+        let query = "SET @@max_threads = 'abc'";
+        let result = federated.check(query);
+        assert!(result.is_err());
+    }
+
+    // known variables are read from the session settings
+    {
+        let query = "select @@max_threads, @@session.time_zone, @@wait_timeout";
+        let result = federated.check(query)?;
+        assert!(result.is_some());
+
+        if let Some((_, block)) = result {
+            let expect = vec![
+                "+----------+-----------------+------------+",
+                "| Column 0 | Column 1        | Column 2   |",
+                "+----------+-----------------+------------+",
+                "| '3'      | 'Asia/Shanghai' | '31536000' |",
+                "+----------+-----------------+------------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // version comment
+    {
+        let query = "select @@version_comment limit 1";
+        let result = federated.check(query)?;
+        assert!(result.is_some());
+        if let Some((_, block)) = result {
+            assert_eq!(block.num_rows(), 1);
+        }
+    }
+
+    Ok(())
+}
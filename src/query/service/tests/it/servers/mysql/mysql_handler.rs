@@ -27,11 +27,14 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use mysql_async::consts::ColumnFlags;
+use mysql_async::consts::ColumnType;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
 use mysql_async::Row;
 use mysql_async::SslOpts;
+use mysql_async::Value;
 use tokio::sync::Barrier;
 
 use crate::tests::tls_constants::*;
@@ -53,6 +56,60 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    // The rows of the prepared statements are encoded in the binary protocol.
+    let row: Option<Row> = connection
+        .exec_first(
+            "SELECT ?::INT8 + 1, ?, to_date('2024-01-02'), 1.5::DOUBLE, NULL, 2::UINT32",
+            (41, "it's"),
+        )
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    let row = row.unwrap();
+
+    let column_types = row
+        .columns_ref()
+        .iter()
+        .map(|c| c.column_type())
+        .collect::<Vec<_>>();
+    assert_eq!(column_types, vec![
+        ColumnType::MYSQL_TYPE_SHORT,
+        ColumnType::MYSQL_TYPE_VARCHAR,
+        ColumnType::MYSQL_TYPE_DATE,
+        ColumnType::MYSQL_TYPE_DOUBLE,
+        ColumnType::MYSQL_TYPE_NULL,
+        ColumnType::MYSQL_TYPE_LONG,
+    ]);
+    assert!(row.columns_ref()[5]
+        .flags()
+        .contains(ColumnFlags::UNSIGNED_FLAG | ColumnFlags::NOT_NULL_FLAG));
+
+    assert_eq!(row.as_ref(0), Some(&Value::Int(42)));
+    assert_eq!(row.as_ref(1), Some(&Value::Bytes(b"it's".to_vec())));
+    assert_eq!(row.as_ref(2), Some(&Value::Date(2024, 1, 2, 0, 0, 0, 0)));
+    assert_eq!(row.as_ref(3), Some(&Value::Double(1.5)));
+    assert_eq!(row.as_ref(4), Some(&Value::NULL));
+    assert_eq!(row.as_ref(5), Some(&Value::UInt(2)));
+
+    // The hours of the time parameter overflow.
+    let result = connection
+        .exec_first::<Row, _, _>("SELECT ?", (Value::Time(false, u32::MAX, 0, 0, 0, 0),))
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_connect_with_tls() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -72,6 +129,25 @@ async fn test_connect_with_tls() -> Result<()> {
     Ok(())
 }
 
+// client certificate is required once the root CA is configured
+#[tokio::test(flavor = "current_thread")]
+async fn test_connect_with_tls_client_auth_required() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let tls_config = MySQLTlsConfig::new(TEST_SERVER_CERT.to_string(), TEST_SERVER_KEY.to_string())
+        .with_root_ca(TEST_CA_CERT.to_string());
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, tls_config)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+
+    let connection = create_connection(runnable_server.port(), true).await;
+    assert!(connection.is_err());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     // TestFixture will create a default session, so we should limit the max_active_sessions to 2.
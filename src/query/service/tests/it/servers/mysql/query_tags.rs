@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_query::servers::QueryTags;
+
+#[test]
+fn test_parse_query_tags() {
+    assert_eq!(QueryTags::parse("SELECT 1"), QueryTags::default());
+
+    let tags = QueryTags::parse(
+        "SELECT 1 /*query_id='q-1_a',traceparent='00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01'*/",
+    );
+    assert_eq!(tags, QueryTags {
+        query_id: Some("q-1_a".to_string()),
+        traceparent: Some("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01".to_string()),
+    });
+
+    // Hints and unknown tags are ignored.
+    let tags = QueryTags::parse(
+        "SELECT /*+ SET_VAR(timezone='Asia/Shanghai') */ 1 /* app='x', query_id = 'q2' */",
+    );
+    assert_eq!(tags.query_id, Some("q2".to_string()));
+    assert_eq!(tags.traceparent, None);
+
+    // Invalid query ids are ignored.
+    let tags = QueryTags::parse("SELECT 1 /*query_id='a b'*/");
+    assert_eq!(tags.query_id, None);
+    let tags = QueryTags::parse(&format!("SELECT 1 /*query_id='{}'*/", "a".repeat(129)));
+    assert_eq!(tags.query_id, None);
+
+    // Unclosed comment.
+    assert_eq!(
+        QueryTags::parse("SELECT 1 /*query_id='q3'"),
+        QueryTags::default()
+    );
+}
@@ -12,9 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::UserQuotaManager;
+use databend_query::interpreters::interpreter_plan_sql;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 
@@ -64,3 +71,70 @@ async fn test_session_in_management_mode() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_max_running_queries_per_user() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .max_running_queries_per_user(1)
+        .build();
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    let session1 = fixture.new_session_with_type(SessionType::MySQL).await?;
+    let session2 = fixture.new_session_with_type(SessionType::MySQL).await?;
+
+    let ctx1 = session1.create_query_context().await?;
+
+    // The same user can not run another query in other session.
+    let res = session2.create_query_context().await;
+    assert!(res.is_err());
+    assert_eq!(
+        res.err().unwrap().code(),
+        ErrorCode::TOO_MANY_RUNNING_QUERIES_PER_USER
+    );
+
+    // The slot is released once the running query finished.
+    drop(ctx1);
+    let _ctx2 = session2.create_query_context().await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_user_query_quota() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let session = fixture.new_session_with_type(SessionType::MySQL).await?;
+    let user = session.get_current_user()?.identity();
+    let quota = UserQuotaManager::instance();
+
+    // A query context is also created for a login or an upload, it is not counted.
+    let ctx = session.create_query_context().await?;
+    assert_eq!(quota.queries_in_current_hour(&user), 0);
+
+    // The query of the client is counted.
+    let _ = interpreter_plan_sql(ctx, "SELECT 1", true).await?;
+    assert_eq!(quota.queries_in_current_hour(&user), 1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_idle_timeout() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let idle_session = fixture.new_session_with_type(SessionType::MySQL).await?;
+    idle_session
+        .get_settings()
+        .set_setting("idle_session_timeout_secs".to_string(), "1".to_string())?;
+    let active_session = fixture.new_session_with_type(SessionType::MySQL).await?;
+
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+
+    // Only the session with idle timeout enabled is closed.
+    let reaped = SessionManager::instance().reap_idle_sessions();
+    assert_eq!(reaped, 1);
+    assert!(idle_session.is_aborting());
+    assert!(!active_session.is_aborting());
+
+    Ok(())
+}
@@ -21,6 +21,7 @@ use dashmap::DashMap;
 use databend_common_base::base::tokio;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
+use databend_common_base::runtime::MemStat;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::cluster_info::Cluster;
 use databend_common_catalog::database::Database;
@@ -786,6 +787,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_query_mem_stat(&self) -> Arc<MemStat> {
+        todo!()
+    }
+
     fn add_mutation_status(&self, _mutation_status: MutationStatus) {
         todo!()
     }
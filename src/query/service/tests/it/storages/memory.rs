@@ -0,0 +1,95 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sql::executor::table_read_plan::ToReadDataSourcePlan;
+use databend_common_storages_memory::MemoryTable;
+use databend_query::sessions::QueryContext;
+use databend_query::stream::ReadDataBlockStream;
+use databend_query::test_kits::TestFixture;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_memory_table_snapshot() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let table = MemoryTable::try_create(TableInfo {
+        desc: "'default'.'a'".into(),
+        name: "a".into(),
+        ident: TableIdent::new(u64::MAX, 0),
+        meta: TableMeta {
+            schema: TableSchemaRefExt::create(vec![TableField::new(
+                "a",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]),
+            engine: "MEMORY".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+    let memory_table = table.as_any().downcast_ref::<MemoryTable>().unwrap();
+
+    let block = |values: Vec<u64>| DataBlock::new_from_columns(vec![UInt64Type::from_data(values)]);
+    memory_table.update(vec![block(vec![1, 2])]);
+
+    // blocks appended after the plan is built are not visible to it.
+    let plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    memory_table.update(vec![block(vec![1, 2]), block(vec![3])]);
+    assert_eq!(read_rows(table.as_ref(), ctx.clone(), &plan).await?, 2);
+
+    let plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    assert_eq!(read_rows(table.as_ref(), ctx.clone(), &plan).await?, 3);
+
+    // blocks replaced after the plan is built are not visible to it either.
+    memory_table.update(vec![block(vec![4])]);
+    assert_eq!(read_rows(table.as_ref(), ctx.clone(), &plan).await?, 3);
+
+    memory_table.truncate();
+    let plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    assert_eq!(read_rows(table.as_ref(), ctx.clone(), &plan).await?, 0);
+
+    Ok(())
+}
+
+async fn read_rows(
+    table: &dyn Table,
+    ctx: Arc<QueryContext>,
+    plan: &DataSourcePlan,
+) -> Result<usize> {
+    let stream = table.read_data_block_stream(ctx, plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    Ok(blocks.iter().map(|b| b.num_rows()).sum())
+}
@@ -449,3 +449,54 @@ async fn test_caches_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_audit_log_table() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.log.audit.on = true;
+    config.log.audit.key = "audit_key".to_string();
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    fixture
+        .execute_command("CREATE USER audit_user IDENTIFIED BY 'password'")
+        .await?;
+    fixture
+        .execute_command("GRANT SELECT ON *.* TO audit_user")
+        .await?;
+    fixture.execute_command("SET max_threads = 4").await?;
+    // Not a security-relevant query.
+    fixture.execute_command("SELECT 1").await?;
+
+    let stream = fixture
+        .execute_query("SELECT seq, event_type, success FROM system.audit_log ORDER BY seq")
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----------+-----------+----------+",
+        "| Column 0 | Column 1  | Column 2 |",
+        "+----------+-----------+----------+",
+        "| 1        | 'Account' | true     |",
+        "| 2        | 'Grant'   | true     |",
+        "| 3        | 'Set'     | true     |",
+        "+----------+-----------+----------+",
+    ];
+    databend_common_expression::block_debug::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // Each event is chained to the previous one.
+    let stream = fixture
+        .execute_query(
+            "SELECT count(DISTINCT checksum) FROM system.audit_log WHERE length(checksum) = 64",
+        )
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 3        |",
+        "+----------+",
+    ];
+    databend_common_expression::block_debug::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
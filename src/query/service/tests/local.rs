@@ -0,0 +1,45 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// `init_local` initializes the global services of the process,
+// so the local mode is tested in its own test binary instead of `it`.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_query::local::init_local;
+use databend_query::local::LocalSession;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_local_session() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    init_local(dir.path(), false).await?;
+
+    let session = LocalSession::try_create().await?;
+    session.query("CREATE TABLE t(a INT)").await?;
+    session.query("INSERT INTO t VALUES (1), (2), (3)").await?;
+
+    let blocks = session.query("SELECT count(*) FROM t WHERE a > 1").await?;
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(block.num_rows(), 1);
+    assert_eq!(
+        block.get_by_offset(0).value.as_ref().index(0),
+        Some(ScalarRef::Number(NumberScalar::UInt64(2)))
+    );
+
+    // The tables are kept under the given path.
+    assert!(dir.path().join("_data").exists());
+    Ok(())
+}
@@ -155,6 +155,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("max_query_memory_usage", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the hard limit of the memory in bytes tracked for a query, the query fails if it's exceeded, 0 for unlimited.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=i64::MAX as u64)),
+                }),
                 ("data_retention_time_in_days", DefaultSettingValue {
                     // unit of retention_period is day
                     value: UserSettingValue::UInt64(1),
@@ -353,6 +359,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("slow_query_threshold_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Logs the queries running longer than this threshold in milliseconds as slow queries, with their plan and operator statistics. Setting it to 0 disables the slow query log.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("collation", DefaultSettingValue {
                     value: UserSettingValue::String("utf8".to_owned()),
                     desc: "Sets the character collation. Available values include \"utf8\".",
@@ -765,12 +777,24 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_streaming_aggregate", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables streaming aggregation if the input is sorted by the GROUP BY keys, which emits the groups one by one instead of building a hash table.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("numeric_cast_option", DefaultSettingValue {
                     value: UserSettingValue::String("rounding".to_string()),
                     desc: "Set numeric cast mode as \"rounding\" or \"truncating\".",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::String(vec!["rounding".into(), "truncating".into()])),
                 }),
+                ("numeric_overflow_mode", DefaultSettingValue {
+                    value: UserSettingValue::String("wrap".to_string()),
+                    desc: "Set the behavior of integer arithmetic on overflow as \"wrap\", \"saturate\" or \"error\".",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["wrap".into(), "saturate".into(), "error".into()])),
+                }),
                 ("enable_experimental_rbac_check", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "experiment setting disables stage and udf privilege check(enable by default).",
@@ -856,6 +880,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(1..=u64::MAX)),
                 }),
+                ("idle_session_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Set the timeout in seconds to close a MySQL session that has not run any query, 0 means never close (default: 0)",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_experimental_queries_executor", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables experimental new executor",
@@ -911,6 +941,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_shuffle_sort", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables shuffling the input of a distributed sort across the cluster before sorting, by the ranges of the first sort key if it has a histogram, otherwise round-robin.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_last_snapshot_location_hint", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables writing last_snapshot_location_hint object",
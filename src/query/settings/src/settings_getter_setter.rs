@@ -212,6 +212,10 @@ impl Settings {
         self.try_set_u64("max_memory_usage", val)
     }
 
+    pub fn get_max_query_memory_usage(&self) -> Result<u64> {
+        self.try_get_u64("max_query_memory_usage")
+    }
+
     pub fn set_data_retention_time_in_days(&self, days: u64) -> Result<()> {
         self.try_set_u64("data_retention_time_in_days", days)
     }
@@ -247,6 +251,10 @@ impl Settings {
         self.try_get_u64("max_execute_time_in_seconds")
     }
 
+    pub fn get_slow_query_threshold_ms(&self) -> Result<u64> {
+        self.try_get_u64("slow_query_threshold_ms")
+    }
+
     // Get flight client timeout.
     pub fn get_flight_client_timeout(&self) -> Result<u64> {
         self.try_get_u64("flight_client_timeout")
@@ -488,6 +496,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_experimental_aggregate_hashtable")? == 1)
     }
 
+    pub fn get_enable_streaming_aggregate(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_streaming_aggregate")? == 1)
+    }
+
     pub fn get_lazy_read_threshold(&self) -> Result<u64> {
         self.try_get_u64("lazy_read_threshold")
     }
@@ -654,6 +666,10 @@ impl Settings {
         self.try_get_string("numeric_cast_option")
     }
 
+    pub fn get_numeric_overflow_mode(&self) -> Result<String> {
+        self.try_get_string("numeric_overflow_mode")
+    }
+
     pub fn get_nulls_first(&self) -> impl Fn(bool) -> bool {
         match self
             .try_get_string("default_order_by_null")
@@ -745,6 +761,10 @@ impl Settings {
         self.try_get_u64("idle_transaction_timeout_secs")
     }
 
+    pub fn get_idle_session_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("idle_session_timeout_secs")
+    }
+
     pub fn get_enable_experimental_queries_executor(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_queries_executor")? == 1)
     }
@@ -778,6 +798,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_parallel_multi_merge_sort")? == 1)
     }
 
+    pub fn get_enable_shuffle_sort(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_shuffle_sort")? == 1)
+    }
+
     pub fn get_format_null_as_str(&self) -> Result<bool> {
         Ok(self.try_get_u64("format_null_as_str")? == 1)
     }
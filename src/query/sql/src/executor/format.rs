@@ -1093,6 +1093,10 @@ fn aggregate_partial_to_format_tree(
         children.push(FormatTreeNode::new(format!("rank limit: {r}")));
     }
 
+    if plan.input_sorted {
+        children.push(FormatTreeNode::new("input sorted: true".to_string()));
+    }
+
     append_profile_info(&mut children, profs, plan.plan_id);
 
     children.push(to_format_tree(&plan.input, metadata, profs)?);
@@ -1489,7 +1493,7 @@ fn exchange_to_format_tree(
             "output columns: [{}]",
             format_output_columns(plan.output_schema()?, metadata, true)
         )),
-        FormatTreeNode::new(format!("exchange type: {}", match plan.kind {
+        FormatTreeNode::new(format!("exchange type: {}", match &plan.kind {
             FragmentKind::Init => "Init-Partition".to_string(),
             FragmentKind::Normal => format!(
                 "Hash({})",
@@ -1501,6 +1505,20 @@ fn exchange_to_format_tree(
             ),
             FragmentKind::Expansive => "Broadcast".to_string(),
             FragmentKind::Merge => "Merge".to_string(),
+            FragmentKind::Range(bounds) => format!(
+                "Range({}, bounds: [{}])",
+                plan.keys
+                    .iter()
+                    .map(|key| { key.as_expr(&BUILTIN_FUNCTIONS).sql_display() })
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                bounds
+                    .iter()
+                    .map(|bound| bound.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            FragmentKind::RoundRobin => "RoundRobin".to_string(),
         })),
         to_format_tree(&plan.input, metadata, profs)?,
     ]))
@@ -201,6 +201,7 @@ pub trait PhysicalPlanReplacer {
             agg_funcs: plan.agg_funcs.clone(),
             stat_info: plan.stat_info.clone(),
             rank_limit: plan.rank_limit.clone(),
+            input_sorted: plan.input_sorted,
         }))
     }
 
@@ -19,6 +19,7 @@ use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::Scalar;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_storage::Datum;
 
 use crate::IndexType;
 
@@ -69,6 +70,9 @@ pub enum FragmentKind {
     // Broadcast
     Expansive,
     Merge,
+    // Partitioned by the ranges of the key split at the bounds
+    Range(Vec<Datum>),
+    RoundRobin,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Copy)]
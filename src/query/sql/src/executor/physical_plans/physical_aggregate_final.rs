@@ -31,6 +31,7 @@ use crate::executor::physical_plans::AggregatePartial;
 use crate::executor::physical_plans::Exchange;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::AggregateMode;
 use crate::plans::DummyTableScan;
@@ -176,6 +177,23 @@ impl PhysicalPlanBuilder {
                     }
                 }
 
+                // The groups can be aggregated one by one if the input is sorted by the group by keys,
+                // in any order of the keys and in any direction.
+                let input_sorted = !group_items.is_empty()
+                    && agg.grouping_sets.is_none()
+                    && agg.rank_limit.is_none()
+                    && settings.get_enable_streaming_aggregate()?
+                    && {
+                        let orderings = RelExpr::with_s_expr(s_expr.child(0)?)
+                            .derive_relational_prop()?
+                            .orderings
+                            .clone();
+                        orderings.len() >= group_items.len()
+                            && orderings[..group_items.len()]
+                                .iter()
+                                .all(|item| group_items.contains(&item.index))
+                    };
+
                 let rank_limit = agg.rank_limit.map(|(item, limit)| {
                     let desc = item
                         .iter()
@@ -210,6 +228,7 @@ impl PhysicalPlanBuilder {
                                 group_by: group_items,
                                 stat_info: Some(stat_info),
                                 rank_limit: None,
+                                input_sorted: false,
                             }
                         } else {
                             AggregatePartial {
@@ -221,6 +240,7 @@ impl PhysicalPlanBuilder {
                                 group_by: group_items,
                                 stat_info: Some(stat_info),
                                 rank_limit,
+                                input_sorted: false,
                             }
                         };
 
@@ -290,6 +310,7 @@ impl PhysicalPlanBuilder {
                                 input: Box::new(PhysicalPlan::AggregateExpand(expand)),
                                 stat_info: Some(stat_info),
                                 rank_limit: None,
+                                input_sorted: false,
                             })
                         } else {
                             PhysicalPlan::AggregatePartial(AggregatePartial {
@@ -301,6 +322,7 @@ impl PhysicalPlanBuilder {
                                 input: Box::new(input),
                                 stat_info: Some(stat_info),
                                 rank_limit,
+                                input_sorted,
                             })
                         }
                     }
@@ -38,6 +38,9 @@ pub struct AggregatePartial {
 
     // Order by keys if keys are subset of group by key, then we can use rank to filter data in previous
     pub rank_limit: Option<(Vec<SortDesc>, usize)>,
+    // The input is sorted by the group by keys, the groups can be aggregated one by one
+    // without a hash table if the aggregation is not distributed.
+    pub input_sorted: bool,
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
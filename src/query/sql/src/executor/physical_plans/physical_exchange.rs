@@ -51,10 +51,16 @@ impl PhysicalPlanBuilder {
         mut required: ColumnSet,
     ) -> Result<PhysicalPlan> {
         // 1. Prune unused Columns.
-        if let crate::plans::Exchange::Hash(exprs) = exchange {
-            for expr in exprs {
-                required.extend(expr.used_columns());
+        match exchange {
+            crate::plans::Exchange::Hash(exprs) => {
+                for expr in exprs {
+                    required.extend(expr.used_columns());
+                }
+            }
+            crate::plans::Exchange::Range { key, .. } => {
+                required.insert(*key);
             }
+            _ => {}
         }
 
         // 2. Build physical plan.
@@ -75,6 +81,17 @@ impl PhysicalPlanBuilder {
                 }
                 FragmentKind::Normal
             }
+            crate::plans::Exchange::Range { key, bounds } => {
+                let offset = input_schema.index_of(&key.to_string())?;
+                keys.push(RemoteExpr::ColumnRef {
+                    span: None,
+                    id: offset,
+                    data_type: input_schema.field(offset).data_type().clone(),
+                    display_name: self.metadata.read().column(*key).name(),
+                });
+                FragmentKind::Range(bounds.clone())
+            }
+            crate::plans::Exchange::RoundRobin => FragmentKind::RoundRobin,
             crate::plans::Exchange::Broadcast => FragmentKind::Expansive,
             crate::plans::Exchange::Merge => FragmentKind::Merge,
             crate::plans::Exchange::MergeSort => {
@@ -21,6 +21,7 @@ use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::UriLocation;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_storage::StageFilesInfo;
 
@@ -47,7 +48,8 @@ impl Binder {
                 _ => location.clone(),
             };
 
-            let (mut stage_info, path) =
+            let is_uri = matches!(location, FileLocation::Uri(_));
+            let (mut stage_info, mut path) =
                 resolve_file_location(self.ctx.as_ref(), &location).await?;
 
             if let Some(f) = &options.file_format {
@@ -55,8 +57,19 @@ impl Binder {
                     Ok(t) => FileFormatParams::default_by_type(t)?,
                     _ => databend_common_base::runtime::block_on(self.ctx.get_file_format(f))?,
                 }
+            } else if is_uri {
+                if let Some(params) = infer_file_format(&path)? {
+                    stage_info.file_format_params = params;
+                }
             }
             let pattern = match &options.pattern {
+                None if is_uri && options.files.is_none() => match split_glob(&path) {
+                    Some((dir, pattern)) => {
+                        path = dir;
+                        Some(pattern)
+                    }
+                    None => None,
+                },
                 None => None,
                 Some(pattern) => Some(Self::resolve_copy_pattern(self.ctx.clone(), pattern)?),
             };
@@ -72,3 +85,61 @@ impl Binder {
         })
     }
 }
+
+/// Infer the file format from the extension of the path, e.g. `data.csv.gz`,
+/// when the uri is selected without `FILE_FORMAT`.
+///
+/// Returns `None` if the extension is unknown, the default format of the stage is used then.
+fn infer_file_format(path: &str) -> Result<Option<FileFormatParams>> {
+    let name = path.rsplit('/').next().unwrap_or_default().to_lowercase();
+    let mut exts = name.rsplit('.');
+    let mut ext = exts.next().unwrap_or_default();
+    let compression = match ext {
+        "gz" => StageFileCompression::Gzip,
+        "zst" => StageFileCompression::Zstd,
+        "bz2" => StageFileCompression::Bz2,
+        "xz" => StageFileCompression::Xz,
+        _ => StageFileCompression::None,
+    };
+    if compression != StageFileCompression::None {
+        ext = exts.next().unwrap_or_default();
+    }
+    let format_type = match ext {
+        "csv" => StageFileFormatType::Csv,
+        "tsv" => StageFileFormatType::Tsv,
+        "ndjson" | "jsonl" => StageFileFormatType::NdJson,
+        "parquet" => StageFileFormatType::Parquet,
+        "orc" => StageFileFormatType::Orc,
+        _ => return Ok(None),
+    };
+    let mut params = FileFormatParams::default_by_type(format_type)?;
+    match &mut params {
+        FileFormatParams::Csv(p) => p.compression = compression,
+        FileFormatParams::Tsv(p) => p.compression = compression,
+        FileFormatParams::NdJson(p) => p.compression = compression,
+        _ => {}
+    }
+    Ok(Some(params))
+}
+
+/// Split a path whose file name contains the glob wildcards `*` or `?`,
+/// e.g. `/data/2024-*.parquet`, into the directory to list and the pattern to match
+/// the files in it.
+fn split_glob(path: &str) -> Option<(String, String)> {
+    let (dir, name) = match path.rfind('/') {
+        Some(pos) => (&path[..=pos], &path[pos + 1..]),
+        None => ("/", path),
+    };
+    if !name.contains(['*', '?']) {
+        return None;
+    }
+    let mut pattern = String::new();
+    for c in name.chars() {
+        match c {
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    Some((dir.to_string(), pattern))
+}
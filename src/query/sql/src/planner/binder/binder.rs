@@ -192,8 +192,22 @@ impl<'a> Binder {
 
                 // Remove unused cache columns and join conditions and construct ExpressionScan's child.
                 (s_expr, _) = self.construct_expression_scan(&s_expr, self.metadata.clone())?;
-                let formatted_ast = if self.ctx.get_settings().get_enable_query_result_cache()? {
-                    Some(stmt.to_string())
+                let settings = self.ctx.get_settings();
+                let formatted_ast = if settings.get_enable_query_result_cache()? {
+                    // The result cache is keyed by the formatted ast, the changed settings
+                    // are part of it since they may change the result, e.g. `timezone`,
+                    // except the ones of the result cache itself.
+                    let mut changes = settings
+                        .changes()
+                        .iter()
+                        .filter(|change| {
+                            change.key() != "enable_query_result_cache"
+                                && !change.key().starts_with("query_result_cache_")
+                        })
+                        .map(|change| format!("{}={}", change.key(), change.value().value))
+                        .collect::<Vec<_>>();
+                    changes.sort();
+                    Some(format!("{stmt} SETTINGS ({})", changes.join(", ")))
                 } else {
                     None
                 };
@@ -36,6 +36,7 @@ use databend_common_ast::ast::TypeName;
 use databend_common_ast::parser::parse_values_with_placeholder;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_catalog::plan::list_stage_files;
+use databend_common_catalog::plan::RemoteDefaultExpr;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
@@ -47,7 +48,6 @@ use databend_common_expression::shrink_scalar;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
-use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
 use databend_common_meta_app::principal::EmptyFieldAs;
 use databend_common_meta_app::principal::FileFormatOptionsReader;
@@ -67,6 +67,7 @@ use parking_lot::RwLock;
 use crate::binder::bind_query::MaxColumnPosition;
 use crate::binder::location::parse_uri_location;
 use crate::binder::Binder;
+use crate::parse_sequence_default_expr;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::Plan;
@@ -565,7 +566,7 @@ impl<'a> Binder {
         &mut self,
         bind_context: &mut BindContext,
         data_schema: &DataSchemaRef,
-    ) -> Result<Vec<RemoteExpr>> {
+    ) -> Result<Vec<RemoteDefaultExpr>> {
         let mut scalar_binder = ScalarBinder::new(
             bind_context,
             self.ctx.clone(),
@@ -577,8 +578,18 @@ impl<'a> Binder {
         );
         let mut values = Vec::with_capacity(data_schema.fields.len());
         for field in &data_schema.fields {
-            let expr = scalar_binder.get_default_value(field, data_schema).await?;
-            values.push(expr.as_remote_expr());
+            // The values of `nextval(seq)` can not be evaluated once for all the rows.
+            let sequence = match field.default_expr() {
+                Some(default_expr) => parse_sequence_default_expr(default_expr)?,
+                None => None,
+            };
+            match sequence {
+                Some(sequence) => values.push(RemoteDefaultExpr::Sequence(sequence)),
+                None => {
+                    let expr = scalar_binder.get_default_value(field, data_schema).await?;
+                    values.push(RemoteDefaultExpr::RemoteExpr(expr.as_remote_expr()));
+                }
+            }
         }
         Ok(values)
     }
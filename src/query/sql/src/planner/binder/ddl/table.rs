@@ -93,6 +93,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use derive_visitor::DriveMut;
+use futures::StreamExt;
 use log::debug;
 use opendal::Operator;
 
@@ -106,6 +107,7 @@ use crate::executor::cast_expr_to_non_null_boolean;
 use crate::optimizer::SExpr;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
+use crate::parse_sequence_default_expr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
@@ -520,6 +522,19 @@ impl Binder {
             }
         }
 
+        if matches!(engine, Engine::Csv | Engine::Tsv | Engine::NdJson) {
+            // The schema of text files can't be inferred, and the files are not written by us.
+            if !matches!(source, Some(CreateTableSource::Columns(..))) || as_query.is_some() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Incorrect CREATE query: {engine} table engine requires a list of column descriptions and does not support AS SELECT"
+                )));
+            }
+            let sp = get_storage_params_from_options(self.ctx.as_ref(), &options).await?;
+            let data_operator = DataOperator::try_create(&sp).await?;
+            verify_external_location_read_privileges(data_operator.operator()).await?;
+            storage_params = Some(sp);
+        }
+
         // Build table schema
         let (schema, field_comments, inverted_indexes) = match (&source, &as_query) {
             (Some(source), None) => {
@@ -591,8 +606,16 @@ impl Binder {
                         engine_options.insert(OPT_KEY_ENGINE_META.to_lowercase().to_string(), meta);
                         (Arc::new(table_schema), vec![], None)
                     }
+                    Engine::Parquet => {
+                        let sp =
+                            get_storage_params_from_options(self.ctx.as_ref(), &options).await?;
+                        let (table_schema, _) =
+                            self.ctx.load_datalake_schema("parquet", &sp).await?;
+                        storage_params = Some(sp);
+                        (Arc::new(table_schema), vec![], None)
+                    }
                     _ => Err(ErrorCode::BadArguments(
-                        "Incorrect CREATE query: required list of column descriptions or AS section or SELECT or ICEBERG/DELTA table engine",
+                        "Incorrect CREATE query: required list of column descriptions or AS section or SELECT or ICEBERG/DELTA/PARQUET table engine",
                     ))?,
                 }
             }
@@ -870,6 +893,26 @@ impl Binder {
                     catalog,
                     database,
                     table,
+                    is_swap: false,
+                })))
+            }
+            AlterTableAction::SwapWith { target_table } => {
+                let target = normalize_identifier(target_table, &self.name_resolution_ctx).name;
+                if target == table {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Cannot swap table {table} with itself"
+                    ))
+                    .set_span(target_table.span));
+                }
+                Ok(Plan::RenameTable(Box::new(RenameTablePlan {
+                    tenant,
+                    if_exists: *if_exists,
+                    new_database: database.clone(),
+                    new_table: target,
+                    catalog,
+                    database,
+                    table,
+                    is_swap: true,
                 })))
             }
             AlterTableAction::ModifyTableComment { new_comment } => {
@@ -1134,6 +1177,7 @@ impl Binder {
             table,
             new_database,
             new_table,
+            is_swap: false,
         })))
     }
 
@@ -1407,6 +1451,12 @@ impl Binder {
                 ColumnExpr::Default(default_expr) => {
                     let (expr, expr_is_deterministic) =
                         parse_default_expr_to_string(self.ctx.clone(), &field, default_expr)?;
+                    if parse_sequence_default_expr(&expr)?.is_some() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "can't add column `{}` with default expr {}, the existing rows can't be filled",
+                            name, expr
+                        )));
+                    }
                     field = field.with_default_expr(Some(expr));
                     is_deterministic = expr_is_deterministic;
                 }
@@ -1687,6 +1737,19 @@ impl Binder {
                 )));
             }
 
+            // Virtual computed columns are not written into the blocks,
+            // the derived values must be stored to be clustered.
+            let column = cluster_key.used_columns().into_iter().next().unwrap();
+            if matches!(
+                schema.field(column).computed_expr(),
+                Some(ComputedExpr::Virtual(_))
+            ) {
+                return Err(ErrorCode::InvalidClusterKeys(format!(
+                    "Cluster by expression `{:#}` is invalid, virtual computed column can not be a cluster key, use a stored computed column instead",
+                    cluster_expr
+                )));
+            }
+
             let expr = cluster_key.as_expr()?;
             if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
                 return Err(ErrorCode::InvalidClusterKeys(format!(
@@ -1786,3 +1849,24 @@ async fn verify_external_location_privileges(dal: Operator) -> Result<()> {
         .await
         .expect("join must succeed")
 }
+
+/// The files of the external tables like CSV are only read, so only check the privilege to list them.
+async fn verify_external_location_read_privileges(dal: Operator) -> Result<()> {
+    let verification_task = async move {
+        let entry = match dal.lister("/").await {
+            Ok(mut lister) => lister.next().await.transpose(),
+            Err(e) => Err(e),
+        };
+        entry.map(|_| ()).map_err(|e| {
+            ErrorCode::StorageOther(
+                "Checking essential permissions for the external location failed.",
+            )
+            .add_message(format!("Permission check for [List] failed: {}", e))
+        })
+    };
+
+    GlobalIORuntime::instance()
+        .spawn(verification_task)
+        .await
+        .expect("join must succeed")
+}
@@ -20,6 +20,7 @@ use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
@@ -106,6 +107,13 @@ impl<'a> ScalarBinder<'a> {
             let tokens = tokenize_sql(default_expr)?;
             let ast = parse_expr(&tokens, self.dialect)?;
             let (mut scalar, _) = self.bind(&ast)?;
+            if let ScalarExpr::AsyncFunctionCall(func) = &scalar {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The default value `{}` of column `{}` must be generated for each row",
+                    func.display_name,
+                    field.name()
+                )));
+            }
             scalar = wrap_cast(&scalar, field.data_type());
 
             let expr = scalar
@@ -14,9 +14,13 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::quote::display_ident;
+use databend_common_ast::ast::ColumnID;
 use databend_common_ast::ast::Expr as AExpr;
 use databend_common_ast::parser::parse_comma_separated_exprs;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::table::Table;
@@ -52,6 +56,8 @@ use crate::binder::ExprContext;
 use crate::planner::binder::BindContext;
 use crate::planner::semantic::NameResolutionContext;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::AsyncFunctionArgument;
+use crate::plans::AsyncFunctionCall;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::IdentifierNormalizer;
@@ -258,6 +264,27 @@ pub fn parse_default_expr_to_string(
 
     let (mut scalar, data_type) = *type_checker.resolve(ast)?;
     let schema_data_type = DataType::from(field.data_type());
+    // `nextval(seq)` is not evaluated as an expression,
+    // the values are allocated from the sequence when the rows are inserted.
+    if let ScalarExpr::AsyncFunctionCall(AsyncFunctionCall {
+        func_arg: AsyncFunctionArgument::SequenceFunction(sequence_name),
+        ..
+    }) = &scalar
+    {
+        if !schema_data_type.remove_nullable().is_numeric() {
+            return Err(ErrorCode::SemanticError(format!(
+                "default expr nextval({}) of column `{}` requires a numeric type, but got {}",
+                sequence_name,
+                field.name(),
+                schema_data_type
+            )));
+        }
+        let expr = format!(
+            "nextval({})",
+            display_ident(sequence_name, true, Dialect::PostgreSQL)
+        );
+        return Ok((expr, false));
+    }
     if data_type != schema_data_type {
         scalar = wrap_cast(&scalar, &schema_data_type);
     }
@@ -273,6 +300,23 @@ pub fn parse_default_expr_to_string(
     Ok((expr.sql_display(), is_deterministic))
 }
 
+/// Returns the name of the sequence if the default expr of a column is `nextval(seq)`,
+/// as displayed by [`parse_default_expr_to_string`].
+pub fn parse_sequence_default_expr(default_expr: &str) -> Result<Option<String>> {
+    let tokens = tokenize_sql(default_expr)?;
+    let ast = parse_expr(&tokens, Dialect::PostgreSQL)?;
+    if let AExpr::FunctionCall { func, .. } = &ast {
+        if func.name.name.eq_ignore_ascii_case("nextval") {
+            if let [AExpr::ColumnRef { column, .. }] = func.args.as_slice() {
+                if let ColumnID::Name(ident) = &column.column {
+                    return Ok(Some(ident.name.clone()));
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
 pub fn parse_computed_expr_to_string(
     ctx: Arc<dyn TableContext>,
     table_schema: TableSchemaRef,
@@ -690,6 +734,11 @@ pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Re
 
     match field.default_expr() {
         Some(default_expr) => {
+            // The values of `nextval(seq)` are allocated when the rows are inserted,
+            // there is no constant to fill.
+            if parse_sequence_default_expr(default_expr)?.is_some() {
+                return Ok(Scalar::default_value(&data_type));
+            }
             let table: Arc<dyn Table> = Arc::new(DummyTable::default());
             let mut exprs = parse_exprs(ctx.clone(), table.clone(), default_expr)?;
             if exprs.len() != 1 {
@@ -131,6 +131,8 @@ pub fn format_exchange(op: &Exchange) -> String {
         Exchange::Broadcast => "Exchange(Broadcast)".to_string(),
         Exchange::Merge => "Exchange(Merge)".to_string(),
         Exchange::MergeSort => "Exchange(MergeSort)".to_string(),
+        Exchange::Range { .. } => "Exchange(Range)".to_string(),
+        Exchange::RoundRobin => "Exchange(RoundRobin)".to_string(),
     }
 }
 
@@ -423,7 +425,7 @@ fn limit_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType
 }
 
 fn exchange_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>>(
-    _id_humanizer: &I,
+    id_humanizer: &I,
     op: &Exchange,
 ) -> FormatTreeNode {
     match op {
@@ -436,6 +438,18 @@ fn exchange_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexT
                     .join(", ")
             ))])
         }
+        Exchange::Range { key, bounds } => {
+            FormatTreeNode::with_children(format_exchange(op), vec![FormatTreeNode::new(format!(
+                "Exchange(Range): key: [{} (#{})], bounds: [{}]",
+                id_humanizer.humanize_column_id(*key),
+                key,
+                bounds
+                    .iter()
+                    .map(|bound| bound.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ))])
+        }
         _ => FormatTreeNode::with_children(format_exchange(op), vec![]),
     }
 }
@@ -30,9 +30,9 @@ pub fn optimize_distributed_query(ctx: Arc<dyn TableContext>, s_expr: &SExpr) ->
     let required = RequiredProperty {
         distribution: Distribution::Any,
     };
-    let result = require_property(ctx, &required, s_expr)?;
+    let result = require_property(ctx.clone(), &required, s_expr)?;
 
-    let sort_and_limit_optimizer = SortAndLimitPushDownOptimizer::create();
+    let sort_and_limit_optimizer = SortAndLimitPushDownOptimizer::create(&ctx)?;
     let mut result = sort_and_limit_optimizer.optimize(&result)?;
 
     let rel_expr = RelExpr::with_s_expr(&result);
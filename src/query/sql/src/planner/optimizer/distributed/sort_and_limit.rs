@@ -14,9 +14,12 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_storage::Datum;
 
 use crate::optimizer::extract::Matcher;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::Exchange;
 use crate::plans::Limit;
@@ -27,14 +30,21 @@ use crate::plans::Sort;
 pub struct SortAndLimitPushDownOptimizer {
     sort_matcher: Matcher,
     limit_matcher: Matcher,
+    // The number of partitions to shuffle the input of a sort into, 0 if disabled.
+    shuffle_partitions: usize,
 }
 
 impl SortAndLimitPushDownOptimizer {
-    pub fn create() -> Self {
-        Self {
+    pub fn create(ctx: &Arc<dyn TableContext>) -> Result<Self> {
+        let shuffle_partitions = match ctx.get_settings().get_enable_shuffle_sort()? {
+            true => ctx.get_cluster().nodes.len(),
+            false => 0,
+        };
+        Ok(Self {
             sort_matcher: Self::sort_matcher(),
             limit_matcher: Self::limit_matcher(),
-        }
+            shuffle_partitions,
+        })
     }
 
     /// `limit` is already pushed down to `Sort`,
@@ -53,7 +63,9 @@ impl SortAndLimitPushDownOptimizer {
         //      \
         //       Sort (after_exchange = false)
         //        \
-        //         *
+        //         Exchange (Range or RoundRobin, if `enable_shuffle_sort` and no limit)
+        //          \
+        //           *
         Matcher::MatchOp {
             op_type: RelOp::Sort,
             children: vec![Matcher::MatchOp {
@@ -124,7 +136,11 @@ impl SortAndLimitPushDownOptimizer {
         debug_assert!(exchange_sexpr.children.len() == 1);
         let exchange_sexpr = exchange_sexpr.replace_plan(Arc::new(Exchange::MergeSort.into()));
 
-        let child = exchange_sexpr.child(0)?.clone();
+        let mut child = exchange_sexpr.child(0)?.clone();
+        if self.shuffle_partitions > 1 && sort.limit.is_none() {
+            let shuffle = self.shuffle_exchange(&sort, &child)?;
+            child = SExpr::create_unary(Arc::new(shuffle.into()), Arc::new(child));
+        }
         let before_exchange_sort =
             SExpr::create_unary(Arc::new(sort.clone().into()), Arc::new(child));
         let new_exchange = exchange_sexpr.replace_children(vec![Arc::new(before_exchange_sort)]);
@@ -133,6 +149,46 @@ impl SortAndLimitPushDownOptimizer {
         Ok(new_plan)
     }
 
+    /// Shuffle the input of the sort, so that every node sorts a part of it. Range partitioning
+    /// by the quantiles of the first sort key balances the parts and makes them disjoint, it
+    /// requires the histogram of the key, otherwise fall back to round-robin.
+    fn shuffle_exchange(&self, sort: &Sort, input: &SExpr) -> Result<Exchange> {
+        let key = sort.items[0].index;
+        let stat_info = RelExpr::with_s_expr(input).derive_cardinality()?;
+        let bounds = match stat_info
+            .statistics
+            .column_stats
+            .get(&key)
+            .and_then(|stat| stat.histogram.as_ref())
+        {
+            Some(histogram) => {
+                let total = histogram.num_values();
+                let mut bounds: Vec<Datum> = Vec::with_capacity(self.shuffle_partitions - 1);
+                let mut values = 0.0;
+                let mut partition = 1;
+                for bucket in histogram.buckets_iter() {
+                    if partition >= self.shuffle_partitions {
+                        break;
+                    }
+                    values += bucket.num_values();
+                    if values >= total * partition as f64 / self.shuffle_partitions as f64 {
+                        if bounds.last() != Some(bucket.upper_bound()) {
+                            bounds.push(bucket.upper_bound().clone());
+                        }
+                        partition += 1;
+                    }
+                }
+                bounds
+            }
+            None => vec![],
+        };
+
+        match bounds.is_empty() {
+            true => Ok(Exchange::RoundRobin),
+            false => Ok(Exchange::Range { key, bounds }),
+        }
+    }
+
     fn apply_limit(&self, s_expr: &SExpr) -> Result<SExpr> {
         if !self.limit_matcher.matches(s_expr) {
             return Ok(s_expr.clone());
@@ -60,6 +60,8 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
                 Exchange::Broadcast => "Broadcast".to_string(),
                 Exchange::Merge => "Merge".to_string(),
                 Exchange::MergeSort => "MergeSort".to_string(),
+                Exchange::Range { key, .. } => format!("Range(#{})", key),
+                Exchange::RoundRobin => "RoundRobin".to_string(),
             })
         }
         RelOperator::DummyTableScan(_) => "DummyTableScan".to_string(),
@@ -440,7 +440,8 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
             // Push down sort and limit
             // TODO(leiysky): do this optimization in cascades optimizer
             if opt_ctx.enable_distributed_optimization {
-                let sort_and_limit_optimizer = SortAndLimitPushDownOptimizer::create();
+                let sort_and_limit_optimizer =
+                    SortAndLimitPushDownOptimizer::create(&opt_ctx.table_ctx)?;
                 s_expr = sort_and_limit_optimizer.optimize(&s_expr)?;
             }
             s_expr
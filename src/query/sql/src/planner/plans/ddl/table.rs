@@ -219,6 +219,8 @@ pub struct RenameTablePlan {
     pub table: String,
     pub new_database: String,
     pub new_table: String,
+    /// Exchange the names of `table` and `new_table`.
+    pub is_swap: bool,
 }
 
 impl RenameTablePlan {
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_exception::Result;
+use databend_common_storage::Datum;
 
 use crate::optimizer::Distribution;
 use crate::optimizer::PhysicalProperty;
@@ -24,6 +25,7 @@ use crate::optimizer::StatInfo;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
+use crate::IndexType;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Exchange {
@@ -31,6 +33,9 @@ pub enum Exchange {
     Broadcast,
     Merge,
     MergeSort, // For distributed sort
+    // Partitioned by the ranges of the column `key` split at `bounds`, e.g. the quantiles of the column.
+    Range { key: IndexType, bounds: Vec<Datum> },
+    RoundRobin,
 }
 
 impl Operator for Exchange {
@@ -49,6 +54,7 @@ impl Operator for Exchange {
                 Exchange::Broadcast => Distribution::Broadcast,
                 Exchange::Merge => Distribution::Serial,
                 Exchange::MergeSort => Distribution::Serial,
+                Exchange::Range { .. } | Exchange::RoundRobin => Distribution::Random,
             },
         })
     }
@@ -1732,6 +1732,17 @@ impl<'a> TypeChecker<'a> {
             params
         };
 
+        // Pass the overflow mode of integer `sum` in params, so it is kept in the plan
+        let params = if func_name.eq_ignore_ascii_case("sum") && params.is_empty() {
+            let mode = self.ctx.get_settings().get_numeric_overflow_mode()?;
+            match mode.as_str() {
+                "wrap" => params,
+                _ => vec![Scalar::String(mode)],
+            }
+        } else {
+            params
+        };
+
         // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
         let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
             ("count_distinct", false)
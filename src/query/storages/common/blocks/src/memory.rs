@@ -20,8 +20,9 @@ use databend_common_expression::DataBlock;
 use parking_lot::RwLock;
 /// Shared store to support memory tables.
 ///
-/// Indexed by table id etc.
-pub type InMemoryData<K> = HashMap<K, Arc<RwLock<Vec<DataBlock>>>>;
+/// Indexed by table id etc. The blocks of a table are replaced as a whole on write,
+/// a reader keeps a consistent snapshot by cloning the inner `Arc`.
+pub type InMemoryData<K> = HashMap<K, Arc<RwLock<Arc<Vec<DataBlock>>>>>;
 
 pub static IN_MEMORY_DATA: LazyLock<Arc<RwLock<InMemoryData<InMemoryDataKey>>>> =
     LazyLock::new(|| Arc::new(Default::default()));
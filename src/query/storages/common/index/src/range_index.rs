@@ -17,6 +17,7 @@ use std::collections::HashMap;
 use databend_common_exception::Result;
 use databend_common_expression::is_internal_column;
 use databend_common_expression::is_stream_column;
+use databend_common_expression::types::boolean::BooleanDomain;
 use databend_common_expression::types::decimal::Decimal128Type;
 use databend_common_expression::types::decimal::Decimal256Type;
 use databend_common_expression::types::decimal::DecimalDataType;
@@ -24,6 +25,7 @@ use databend_common_expression::types::decimal::DecimalDomain;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::SimpleDomain;
 use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::NumberDataType;
@@ -212,6 +214,10 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                         max: NumberType::<NUM_TYPE>::try_downcast_scalar(&max.as_ref()).unwrap(),
                     })
                 }
+                DataType::Boolean => Domain::Boolean(BooleanDomain {
+                    has_false: !BooleanType::try_downcast_scalar(&min.as_ref()).unwrap(),
+                    has_true: BooleanType::try_downcast_scalar(&max.as_ref()).unwrap(),
+                }),
                 DataType::String => Domain::String(StringDomain {
                     min: min.clone().into_string().unwrap(),
                     max: Some(max.clone().into_string().unwrap()),
@@ -247,4 +253,17 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
     }
 }
 
-impl Index for RangeIndex {}
+impl Index for RangeIndex {
+    fn supported_type(data_type: &DataType) -> bool {
+        let inner_type = data_type.remove_nullable();
+        matches!(
+            inner_type,
+            DataType::Number(_)
+                | DataType::Date
+                | DataType::Timestamp
+                | DataType::String
+                | DataType::Decimal(_)
+                | DataType::Boolean
+        )
+    }
+}
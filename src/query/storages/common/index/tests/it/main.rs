@@ -16,3 +16,4 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod filters;
+mod range_index;
@@ -0,0 +1,64 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::boolean::BooleanDomain;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Domain;
+use databend_common_expression::Scalar;
+use databend_storages_common_index::statistics_to_domain;
+use databend_storages_common_index::Index;
+use databend_storages_common_index::RangeIndex;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+
+#[test]
+fn test_boolean_statistics_to_domain() {
+    assert!(RangeIndex::supported_type(&DataType::Boolean));
+    assert!(RangeIndex::supported_type(
+        &DataType::Boolean.wrap_nullable()
+    ));
+
+    let cases = [
+        (false, false, BooleanDomain {
+            has_false: true,
+            has_true: false,
+        }),
+        (false, true, BooleanDomain {
+            has_false: true,
+            has_true: true,
+        }),
+        (true, true, BooleanDomain {
+            has_false: false,
+            has_true: true,
+        }),
+    ];
+    for (min, max, expected) in cases {
+        let stat = ColumnStatistics::new(Scalar::Boolean(min), Scalar::Boolean(max), 0, 0, None);
+        let domain = statistics_to_domain(vec![&stat], &DataType::Boolean);
+        assert_eq!(domain, Domain::Boolean(expected));
+    }
+
+    let stat = ColumnStatistics::new(Scalar::Boolean(true), Scalar::Boolean(true), 1, 0, None);
+    let domain = statistics_to_domain(vec![&stat], &DataType::Boolean.wrap_nullable());
+    assert_eq!(
+        domain,
+        Domain::Nullable(NullableDomain {
+            has_null: true,
+            value: Some(Box::new(Domain::Boolean(BooleanDomain {
+                has_false: false,
+                has_true: true,
+            }))),
+        })
+    );
+}
@@ -177,8 +177,32 @@ impl TempTblMgr {
             name_ident,
             new_db_name,
             new_table_name,
+            swap,
         } = req;
         let desc = format!("{}.{}", name_ident.db_name, name_ident.table_name);
+        if *swap {
+            let new_desc = format!("{}.{}", new_db_name, new_table_name);
+            let ids = (
+                self.name_to_id.get(&desc).copied(),
+                self.name_to_id.get(&new_desc).copied(),
+            );
+            return match ids {
+                (Some(id), Some(new_id)) => {
+                    self.name_to_id.insert(desc, new_id);
+                    self.name_to_id.insert(new_desc, id);
+                    let table = self.id_to_table.get_mut(&id).unwrap();
+                    table.table_name = new_table_name.clone();
+                    let new_table = self.id_to_table.get_mut(&new_id).unwrap();
+                    new_table.table_name = name_ident.table_name.clone();
+                    Ok(Some(RenameTableReply { table_id: 0 }))
+                }
+                (None, None) => Ok(None),
+                _ => Err(ErrorCode::BadArguments(format!(
+                    "Cannot swap temporary table with non-temporary table: {}.{} and {}.{}",
+                    name_ident.db_name, name_ident.table_name, new_db_name, new_table_name
+                ))),
+            };
+        }
         match self.name_to_id.remove(&desc) {
             Some(id) => {
                 let new_desc = format!("{}.{}", new_db_name, new_table_name);
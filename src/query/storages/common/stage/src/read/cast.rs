@@ -14,6 +14,10 @@
 
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
 
 /// limits automatic type casting when loading data with a specified schema, applicable to formats like Parquet, ORC, Iceberg, and Delta.
 ///
@@ -145,3 +149,14 @@ pub fn load_can_auto_cast_to(from_type: &DataType, to_type: &DataType) -> bool {
         (_, Geography) => false,
     }
 }
+
+/// The first row in the block of a NULL value, counting from zero.
+///
+/// Used to point at the row of a load that violates a NOT NULL column.
+pub fn first_null_row(entry: &BlockEntry) -> Option<usize> {
+    match &entry.value {
+        Value::Scalar(Scalar::Null) => Some(0),
+        Value::Column(Column::Nullable(col)) => col.validity.iter().position(|valid| !valid),
+        _ => None,
+    }
+}
@@ -12,10 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_catalog::plan::RemoteDefaultExpr;
 use databend_common_exception::ErrorCode;
 use databend_common_expression::type_check::check_cast;
 use databend_common_expression::Expr;
-use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -31,7 +31,7 @@ pub fn project_columnar(
     input_schema: &TableSchemaRef,
     output_schema: &TableSchemaRef,
     null_as: &NullAs,
-    default_values: &Option<Vec<RemoteExpr>>,
+    default_values: &Option<Vec<RemoteDefaultExpr>>,
     location: &str,
 ) -> databend_common_exception::Result<(Vec<Expr>, Vec<usize>)> {
     let mut pushdown_columns = vec![];
@@ -107,7 +107,15 @@ pub fn project_columnar(
                         let default_values = &default_values.as_deref().expect(
                             "default_values should not be none when miss_field_as=FIELD_DEFAULT",
                         );
-                        default_values[i].as_expr(&BUILTIN_FUNCTIONS)
+                        match &default_values[i] {
+                            RemoteDefaultExpr::RemoteExpr(expr) => expr.as_expr(&BUILTIN_FUNCTIONS),
+                            RemoteDefaultExpr::Sequence(sequence) => {
+                                return Err(ErrorCode::BadDataValueType(format!(
+                                    "file {} missing column `{}` with default nextval({}), leave the column out of the column list to generate it",
+                                    location, field_name, sequence
+                                )));
+                            }
+                        }
                     }
                 }
             }
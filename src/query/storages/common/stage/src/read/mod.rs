@@ -14,9 +14,12 @@
 
 mod columnar;
 mod single_file_partition;
+mod source_file_meta;
 
 mod cast;
 
+pub use cast::first_null_row;
 pub use cast::load_can_auto_cast_to;
 pub use columnar::*;
 pub use single_file_partition::SingleFilePartition;
+pub use source_file_meta::SourceFileMeta;
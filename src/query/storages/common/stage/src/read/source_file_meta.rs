@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::BlockMetaInfoPtr;
+
+/// The stage files the rows of a block are loaded from, attached by the sources of COPY
+/// so that errors raised later in the pipeline can tell the file and the row in the file.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SourceFileMeta {
+    /// `(location, first row in the file, number of rows)`, in the order of the rows of the block.
+    pub ranges: Vec<(String, u64, usize)>,
+}
+
+impl SourceFileMeta {
+    pub fn create(ranges: Vec<(String, u64, usize)>) -> BlockMetaInfoPtr {
+        Box::new(SourceFileMeta { ranges })
+    }
+
+    /// The file of the given row of the block and the row in that file, counting from zero.
+    pub fn locate(&self, row: usize) -> Option<(&str, u64)> {
+        let mut start = 0;
+        for (location, first_row, num_rows) in &self.ranges {
+            if row < start + num_rows {
+                return Some((location, first_row + (row - start) as u64));
+            }
+            start += num_rows;
+        }
+        None
+    }
+}
+
+#[typetag::serde(name = "source_file_meta")]
+impl BlockMetaInfo for SourceFileMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        SourceFileMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
@@ -24,7 +24,13 @@ use databend_common_storages_delta::DeltaTable;
 use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_memory::MemoryTable;
 use databend_common_storages_null::NullTable;
+use databend_common_storages_parquet::ParquetExternalTable;
+use databend_common_storages_parquet::PARQUET_ENGINE;
 use databend_common_storages_random::RandomTable;
+use databend_common_storages_stage::TextExternalTable;
+use databend_common_storages_stage::CSV_ENGINE;
+use databend_common_storages_stage::NDJSON_ENGINE;
+use databend_common_storages_stage::TSV_ENGINE;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_view::view_table::ViewTable;
 
@@ -161,6 +167,30 @@ impl StorageFactory {
             table_info_refresher: None,
         });
 
+        // Register PARQUET table engine
+        creators.insert(PARQUET_ENGINE.to_string(), Storage {
+            creator: Arc::new(ParquetExternalTable::try_create),
+            descriptor: Arc::new(ParquetExternalTable::description),
+            table_info_refresher: None,
+        });
+
+        // Register CSV, TSV and NDJSON table engines
+        creators.insert(CSV_ENGINE.to_string(), Storage {
+            creator: Arc::new(TextExternalTable::try_create),
+            descriptor: Arc::new(TextExternalTable::csv_description),
+            table_info_refresher: None,
+        });
+        creators.insert(TSV_ENGINE.to_string(), Storage {
+            creator: Arc::new(TextExternalTable::try_create),
+            descriptor: Arc::new(TextExternalTable::tsv_description),
+            table_info_refresher: None,
+        });
+        creators.insert(NDJSON_ENGINE.to_string(), Storage {
+            creator: Arc::new(TextExternalTable::try_create),
+            descriptor: Arc::new(TextExternalTable::ndjson_description),
+            table_info_refresher: None,
+        });
+
         StorageFactory {
             storages: creators,
             schema_refreshing_timeout: DEFAULT_SCHEMA_REFRESHING_TIMEOUT_MS,
@@ -33,6 +33,7 @@ use databend_common_pipeline_transforms::processors::TransformSortPartial;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::executor::physical_plans::MutationKind;
+use log::warn;
 
 use crate::operations::common::TransformSerializeBlock;
 use crate::statistics::ClusterStatsGenerator;
@@ -47,7 +48,7 @@ impl FuseTable {
         let block_thresholds = self.get_block_thresholds();
         build_compact_block_pipeline(pipeline, block_thresholds)?;
 
-        let schema = DataSchema::from(self.schema()).into();
+        let schema = DataSchema::from(&self.schema().remove_virtual_computed_fields()).into();
         let cluster_stats_gen =
             self.cluster_gen_for_append(ctx.clone(), pipeline, block_thresholds, Some(schema))?;
         pipeline.add_transform(|input, output| {
@@ -78,7 +79,11 @@ impl FuseTable {
 
         let operators = cluster_stats_gen.operators.clone();
         if !operators.is_empty() {
-            let num_input_columns = self.table_info.schema().fields().len();
+            let num_input_columns = self
+                .table_info
+                .schema()
+                .remove_virtual_computed_fields()
+                .num_fields();
             let func_ctx2 = cluster_stats_gen.func_ctx.clone();
             let mut builder = pipeline.try_create_transform_pipeline_builder_with_len(
                 move || {
@@ -137,7 +142,11 @@ impl FuseTable {
 
         let operators = cluster_stats_gen.operators.clone();
         if !operators.is_empty() {
-            let num_input_columns = self.table_info.schema().fields().len();
+            let num_input_columns = self
+                .table_info
+                .schema()
+                .remove_virtual_computed_fields()
+                .num_fields();
             let func_ctx2 = cluster_stats_gen.func_ctx.clone();
 
             pipeline.add_transformer(move || {
@@ -174,8 +183,10 @@ impl FuseTable {
             return Ok(ClusterStatsGenerator::default());
         }
 
-        let input_schema =
-            modified_schema.unwrap_or(DataSchema::from(self.schema_with_stream()).into());
+        // The virtual computed columns are not in the written blocks.
+        let input_schema = modified_schema.unwrap_or(
+            DataSchema::from(&self.schema_with_stream().remove_virtual_computed_fields()).into(),
+        );
         let mut merged = input_schema.fields().clone();
 
         let mut cluster_key_index = Vec::with_capacity(cluster_keys.len());
@@ -184,9 +195,21 @@ impl FuseTable {
         let mut exprs = Vec::with_capacity(cluster_keys.len());
 
         for remote_expr in &cluster_keys {
-            let expr = remote_expr
-                .as_expr(&BUILTIN_FUNCTIONS)
-                .project_column_ref(|name| input_schema.index_of(name).unwrap());
+            let expr = remote_expr.as_expr(&BUILTIN_FUNCTIONS);
+            // Tables created before virtual computed columns were rejected as cluster keys
+            // may still have one, which can not be evaluated on the written blocks.
+            if let Some(name) = expr
+                .column_refs()
+                .into_keys()
+                .find(|name| input_schema.index_of(name).is_err())
+            {
+                warn!(
+                    "skip the cluster statistics of table {}, cluster key column {} is not written into the blocks",
+                    self.table_info.desc, name
+                );
+                return Ok(ClusterStatsGenerator::default());
+            }
+            let expr = expr.project_column_ref(|name| input_schema.index_of(name).unwrap());
             let index = match &expr {
                 Expr::ColumnRef { id, .. } => *id,
                 _ => {
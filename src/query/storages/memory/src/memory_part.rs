@@ -16,12 +16,17 @@ use std::any::Any;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::PartInfo;
+use databend_common_expression::DataBlock;
 
-#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+/// The parts of a memory table share the blocks seen by `read_partitions`,
+/// the blocks written after it are not visible to the plan.
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct MemoryPartInfo {
     pub total: usize,
     pub part_start: usize,
     pub part_end: usize,
+    #[serde(skip)]
+    pub blocks: Arc<Vec<DataBlock>>,
 }
 
 #[typetag::serde(name = "memory")]
@@ -33,7 +38,12 @@ impl PartInfo for MemoryPartInfo {
     fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
         info.as_any()
             .downcast_ref::<MemoryPartInfo>()
-            .is_some_and(|other| self == other)
+            .is_some_and(|other| {
+                self.total == other.total
+                    && self.part_start == other.part_start
+                    && self.part_end == other.part_end
+                    && Arc::ptr_eq(&self.blocks, &other.blocks)
+            })
     }
 
     fn hash(&self) -> u64 {
@@ -42,11 +52,12 @@ impl PartInfo for MemoryPartInfo {
 }
 
 impl MemoryPartInfo {
-    pub fn create(start: usize, end: usize, total: usize) -> Arc<Box<dyn PartInfo>> {
+    pub fn create(start: usize, end: usize, blocks: Arc<Vec<DataBlock>>) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(MemoryPartInfo {
-            total,
+            total: blocks.len(),
             part_start: start,
             part_end: end,
+            blocks,
         }))
     }
 }
@@ -33,6 +33,8 @@ use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::BlockEntry;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Value;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
@@ -59,7 +61,7 @@ use crate::memory_part::MemoryPartInfo;
 #[derive(Clone)]
 pub struct MemoryTable {
     table_info: TableInfo,
-    blocks: Arc<RwLock<Vec<DataBlock>>>,
+    blocks: Arc<RwLock<Arc<Vec<DataBlock>>>>,
 
     data_metrics: Arc<StorageMetrics>,
 }
@@ -76,7 +78,7 @@ impl MemoryTable {
             };
             let x = in_mem_data.get(&key);
             x.cloned().unwrap_or_else(|| {
-                let blocks = Arc::new(RwLock::new(vec![]));
+                let blocks = Arc::new(RwLock::new(Arc::new(vec![])));
                 in_mem_data.insert(key, blocks.clone());
                 blocks
             })
@@ -105,32 +107,26 @@ impl MemoryTable {
 
     pub fn truncate(&self) {
         let mut blocks = self.blocks.write();
-        blocks.clear();
+        *blocks = Arc::new(vec![]);
     }
 
     pub fn update(&self, new_blocks: Vec<DataBlock>) {
         let mut blocks = self.blocks.write();
-        *blocks = new_blocks;
+        *blocks = Arc::new(new_blocks);
     }
 
-    fn get_read_data_blocks(&self) -> Arc<Mutex<VecDeque<DataBlock>>> {
-        let data_blocks = self.blocks.read();
-        let mut read_data_blocks = VecDeque::with_capacity(data_blocks.len());
-
-        for data_block in data_blocks.iter() {
-            read_data_blocks.push_back(data_block.clone());
-        }
-
-        Arc::new(Mutex::new(read_data_blocks))
-    }
-
-    pub fn generate_memory_parts(start: usize, workers: usize, total: usize) -> Partitions {
+    pub fn generate_memory_parts(
+        start: usize,
+        workers: usize,
+        blocks: Arc<Vec<DataBlock>>,
+    ) -> Partitions {
+        let total = blocks.len();
         let part_size = total / workers;
         let part_remain = total % workers;
 
         let mut partitions = Vec::with_capacity(workers);
         if part_size == 0 {
-            partitions.push(MemoryPartInfo::create(start, total, total));
+            partitions.push(MemoryPartInfo::create(start, total, blocks));
         } else {
             for part in 0..workers {
                 let mut part_begin = part * part_size;
@@ -142,7 +138,7 @@ impl MemoryTable {
                     part_end += part_remain;
                 }
 
-                partitions.push(MemoryPartInfo::create(part_begin, part_end, total));
+                partitions.push(MemoryPartInfo::create(part_begin, part_end, blocks.clone()));
             }
         }
 
@@ -175,7 +171,8 @@ impl Table for MemoryTable {
         push_downs: Option<PushDownInfo>,
         _dry_run: bool,
     ) -> Result<(PartStatistics, Partitions)> {
-        let blocks = self.blocks.read();
+        // Take a snapshot of the blocks, it is read by `read_data` through the parts.
+        let blocks = self.blocks.read().clone();
 
         let statistics = match push_downs {
             Some(push_downs) => {
@@ -214,11 +211,8 @@ impl Table for MemoryTable {
             }
         };
 
-        let parts = Self::generate_memory_parts(
-            0,
-            ctx.get_settings().get_max_threads()? as usize,
-            blocks.len(),
-        );
+        let parts =
+            Self::generate_memory_parts(0, ctx.get_settings().get_max_threads()? as usize, blocks);
         Ok((statistics, parts))
     }
 
@@ -230,7 +224,15 @@ impl Table for MemoryTable {
         _put_cache: bool,
     ) -> Result<()> {
         let numbers = ctx.get_settings().get_max_threads()? as usize;
-        let read_data_blocks = self.get_read_data_blocks();
+        // All the parts share the snapshot taken by `read_partitions`.
+        let read_data_blocks = plan
+            .parts
+            .partitions
+            .iter()
+            .find_map(|part| part.as_any().downcast_ref::<MemoryPartInfo>())
+            .map(|part| part.blocks.iter().cloned().collect::<VecDeque<_>>())
+            .unwrap_or_default();
+        let read_data_blocks = Arc::new(Mutex::new(read_data_blocks));
 
         // Add source pipe.
         pipeline.add_source(
@@ -367,6 +369,7 @@ impl SyncSource for MemoryTableSource {
 
 struct MemoryTableSink {
     table: Arc<MemoryTable>,
+    schema: DataSchemaRef,
     write_progress: Arc<Progress>,
     operations: Vec<DataBlock>,
     overwrite: bool,
@@ -379,8 +382,12 @@ impl MemoryTableSink {
         table: Arc<MemoryTable>,
         overwrite: bool,
     ) -> Box<dyn Processor> {
+        let schema = Arc::new(DataSchema::from(
+            &table.schema().remove_virtual_computed_fields(),
+        ));
         Sinker::create(input, MemoryTableSink {
             table,
+            schema,
             write_progress: ctx.get_write_progress(),
             operations: vec![],
             overwrite,
@@ -392,6 +399,25 @@ impl Sink for MemoryTableSink {
     const NAME: &'static str = "MemoryTableSink";
 
     fn consume(&mut self, block: DataBlock) -> Result<()> {
+        // The blocks are read as they are, check them before they become visible.
+        let fields = self.schema.fields();
+        if block.num_columns() != fields.len() {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "Data schema mismatched, expect {} columns, but got {}",
+                fields.len(),
+                block.num_columns()
+            )));
+        }
+        for (entry, field) in block.columns().iter().zip(fields.iter()) {
+            if &entry.data_type != field.data_type() {
+                return Err(ErrorCode::TableSchemaMismatch(format!(
+                    "Data schema mismatched, expect {} of column {}, but got {}",
+                    field.data_type(),
+                    field.name(),
+                    entry.data_type
+                )));
+            }
+        }
         self.operations.push(block);
         Ok(())
     }
@@ -405,7 +431,9 @@ impl Sink for MemoryTableSink {
         self.write_progress.incr(&progress_values);
         self.table.data_metrics.inc_write_bytes(bytes);
 
+        // Copy on write, the snapshots held by the running reads are not changed.
         let mut blocks = self.table.blocks.write();
+        let blocks = Arc::make_mut(&mut blocks);
         if self.overwrite {
             blocks.clear();
         }
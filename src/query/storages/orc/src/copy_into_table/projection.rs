@@ -14,9 +14,9 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::plan::RemoteDefaultExpr;
 use databend_common_exception::Result;
 use databend_common_expression::Expr;
-use databend_common_expression::RemoteExpr;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::principal::NullAs;
 use databend_storages_common_stage::project_columnar;
@@ -26,7 +26,7 @@ use crate::hashable_schema::HashableSchema;
 #[derive(Clone)]
 pub struct ProjectionFactory {
     pub output_schema: TableSchemaRef,
-    default_values: Option<Vec<RemoteExpr>>,
+    default_values: Option<Vec<RemoteDefaultExpr>>,
     null_as: NullAs,
 
     projections: Arc<dashmap::DashMap<HashableSchema, Vec<Expr>>>,
@@ -35,7 +35,7 @@ pub struct ProjectionFactory {
 impl ProjectionFactory {
     pub fn try_create(
         output_schema: TableSchemaRef,
-        default_values: Option<Vec<RemoteExpr>>,
+        default_values: Option<Vec<RemoteDefaultExpr>>,
         null_as: NullAs,
     ) -> Result<Self> {
         Ok(Self {
@@ -17,10 +17,10 @@ use std::sync::Arc;
 
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::RemoteDefaultExpr;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::Expr;
-use databend_common_expression::RemoteExpr;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::principal::NullAs;
 use databend_common_storage::parquet_rs::infer_schema_with_extension;
@@ -73,7 +73,7 @@ impl RowGroupReaderForCopy {
         op: Operator,
         file_metadata: &FileMetaData,
         output_schema: TableSchemaRef,
-        default_values: Option<Vec<RemoteExpr>>,
+        default_values: Option<Vec<RemoteDefaultExpr>>,
         missing_as: &NullAs,
     ) -> Result<RowGroupReaderForCopy> {
         let arrow_schema = infer_schema_with_extension(file_metadata)?;
@@ -32,6 +32,7 @@ use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_storages_common_stage::first_null_row;
 use opendal::Operator;
 
 use crate::parquet_rs::copy_into_table::reader::RowGroupReaderForCopy;
@@ -40,10 +41,12 @@ use crate::ParquetPart;
 use crate::ReadSettings;
 
 type SchemaIndex = usize;
+type Location = String;
+type NextRow = u64;
 
 enum State {
     Init,
-    ReadRowGroup((SchemaIndex, ReadPolicyImpl)),
+    ReadRowGroup((SchemaIndex, Location, NextRow, ReadPolicyImpl)),
     // ReadFiles((SchemaIndex, Vec<(String, Vec<u8>)>)),
 }
 
@@ -142,7 +145,7 @@ impl Processor for ParquetCopySource {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Init) {
-            State::ReadRowGroup((schema_index, mut reader)) => {
+            State::ReadRowGroup((schema_index, location, next_row, mut reader)) => {
                 if let Some(block) = reader.as_mut().read_block()? {
                     let projection = self
                         .row_group_readers
@@ -152,13 +155,39 @@ impl Processor for ParquetCopySource {
                     let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
                     let mut columns = Vec::with_capacity(projection.len());
                     for (field, expr) in self.schema.fields().iter().zip(projection.iter()) {
-                        let value = evaluator.run(expr)?;
+                        let value = evaluator.run(expr).map_err(|err| {
+                            let mut msg = format!(
+                                "fail to load column {} from file {}",
+                                field.name(),
+                                location
+                            );
+                            let null_row = match field.is_nullable() {
+                                true => None,
+                                false => expr
+                                    .column_refs()
+                                    .keys()
+                                    .filter_map(|i| first_null_row(block.get_by_offset(*i)))
+                                    .min(),
+                            };
+                            if let Some(row) = null_row {
+                                msg.push_str(&format!(
+                                    ", NULL value at row {} of the file violates the NOT NULL constraint",
+                                    next_row + row as u64 + 1
+                                ));
+                            }
+                            err.add_message(msg)
+                        })?;
                         let column = BlockEntry::new(field.data_type().clone(), value);
                         columns.push(column);
                     }
-                    let block = DataBlock::new(columns, block.num_rows());
-                    self.generated_data = Some(block);
-                    self.state = State::ReadRowGroup((schema_index, reader));
+                    let num_rows = block.num_rows();
+                    self.generated_data = Some(DataBlock::new(columns, num_rows));
+                    self.state = State::ReadRowGroup((
+                        schema_index,
+                        location,
+                        next_row + num_rows as u64,
+                        reader,
+                    ));
                 }
                 // Else: The reader is finished. We should try to build another reader.
             }
@@ -189,7 +218,12 @@ impl Processor for ParquetCopySource {
                                 .await?
                                 .expect("reader must exist");
                             {
-                                self.state = State::ReadRowGroup((schema_index, reader));
+                                self.state = State::ReadRowGroup((
+                                    schema_index,
+                                    part.location.clone(),
+                                    part.start_row,
+                                    reader,
+                                ));
                             }
                             // Else: keep in init state.
                         }
@@ -84,9 +84,11 @@ impl ParquetTableForCopy {
                 num_rows_loaded: num_rows,
                 error: None,
             });
+            let mut start_row = 0;
             for rg in meta.meta.row_groups() {
                 let part = ParquetRSRowGroupPart {
                     location: meta.location.clone(),
+                    start_row,
                     meta: rg.clone(),
                     schema_index,
                     uncompressed_size: rg.total_byte_size() as u64,
@@ -96,6 +98,7 @@ impl ParquetTableForCopy {
                     page_locations: None,
                     selectors: None,
                 };
+                start_row += rg.num_rows() as u64;
                 parts.push(part);
             }
         }
@@ -34,7 +34,9 @@ pub use parquet_reader::ParquetFileReader;
 pub use parquet_reader::ParquetRSFullReader;
 pub use parquet_reader::ParquetRSReaderBuilder;
 pub use parquet_reader::ParquetRSRowGroupReader;
+pub use parquet_table::ParquetExternalTable;
 pub use parquet_table::ParquetRSTable;
+pub use parquet_table::PARQUET_ENGINE;
 pub use partition::ParquetRSRowGroupPart;
 pub use pruning::ParquetRSPruner;
 pub use source::ParquetSource;
@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::StorageDescription;
+use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::query_kind::QueryKind;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_settings::Settings;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFilesInfo;
+
+use super::ParquetRSTable;
+use crate::parquet_rs::schema::arrow_to_table_schema;
+
+pub const PARQUET_ENGINE: &str = "PARQUET";
+
+// Skip the files like `_SUCCESS` or `.crc` written by the other engines.
+const DATA_FILE_PATTERN: &str = "^(.*/)?[^_./][^/]*$";
+
+/// Table created by `CREATE TABLE ... ENGINE = PARQUET LOCATION = '...'`.
+///
+/// Only the schema is kept in the catalog, the files under the location are listed
+/// and read by a [`ParquetRSTable`] built in [`ParquetExternalTable::load`] each time
+/// the table is resolved by a query, so the row group pruning, prewhere and the
+/// parallel row group scanning of the stage reading are all reused.
+pub struct ParquetExternalTable {
+    info: TableInfo,
+}
+
+impl ParquetExternalTable {
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        Ok(Box::new(Self { info }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: PARQUET_ENGINE.to_string(),
+            comment: "PARQUET Storage Engine".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    fn stage(sp: StorageParams) -> (StageInfo, StageFilesInfo) {
+        let stage_info = StageInfo::new_external_stage(sp, true);
+        let files_info = StageFilesInfo {
+            path: "/".to_string(),
+            files: None,
+            pattern: Some(DATA_FILE_PATTERN.to_string()),
+        };
+        (stage_info, files_info)
+    }
+
+    /// Infer the table schema from the first parquet file under the location.
+    #[async_backtrace::framed]
+    pub async fn infer_schema(sp: &StorageParams) -> Result<TableSchema> {
+        let (stage_info, files_info) = Self::stage(sp.clone());
+        let operator = init_stage_operator(&stage_info)?;
+        let first_file = files_info.first_file(&operator).await.map_err(|e| {
+            ErrorCode::BadArguments(format!("Cannot infer the parquet schema from {sp}: {e}"))
+        })?;
+        let (arrow_schema, _, _) =
+            ParquetRSTable::prepare_metas(&first_file.path, operator).await?;
+        arrow_to_table_schema(&arrow_schema)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn load(
+        info: &TableInfo,
+        sp: StorageParams,
+        read_options: ParquetReadOptions,
+        settings: Arc<Settings>,
+        query_kind: QueryKind,
+    ) -> Result<Arc<dyn Table>> {
+        let (stage_info, files_info) = Self::stage(sp);
+        let mut table = ParquetRSTable::try_create(
+            stage_info,
+            files_info,
+            read_options,
+            None,
+            settings,
+            query_kind,
+        )
+        .await?;
+
+        // Keep the identity of the catalog table, but read with the schema of the files,
+        // which may have been changed since the table was created.
+        table.table_info = TableInfo {
+            meta: TableMeta {
+                schema: table.table_info.meta.schema.clone(),
+                ..info.meta.clone()
+            },
+            ..info.clone()
+        };
+        Ok(Arc::new(table))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ParquetExternalTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+}
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod external;
 mod partition;
 mod read;
 mod stats;
 mod table;
 
+pub use external::ParquetExternalTable;
+pub use external::PARQUET_ENGINE;
 pub use table::ParquetRSTable;
@@ -365,6 +365,15 @@ fn prune_and_generate_partitions(
         };
 
         let mut rows_read = 0; // Rows read in current file.
+        let row_group_starts = meta
+            .row_groups()
+            .iter()
+            .scan(0, |start, rg| {
+                let rg_start = *start;
+                *start += rg.num_rows() as u64;
+                Some(rg_start)
+            })
+            .collect::<Vec<_>>();
 
         for (rg, omit) in rgs.into_iter().zip(omits.into_iter()) {
             let rg_meta = meta.row_group(rg);
@@ -417,6 +426,7 @@ fn prune_and_generate_partitions(
 
             parts.push(ParquetRSRowGroupPart {
                 location: location.clone(),
+                start_row: row_group_starts[rg],
                 selectors: serde_selection,
                 meta: rg_meta.clone(),
                 page_locations,
@@ -122,6 +122,27 @@ impl ParquetRSTable {
         settings: Arc<Settings>,
         query_kind: QueryKind,
     ) -> Result<Arc<dyn Table>> {
+        let table = Self::try_create(
+            stage_info,
+            files_info,
+            read_options,
+            files_to_read,
+            settings,
+            query_kind,
+        )
+        .await?;
+        Ok(Arc::new(table))
+    }
+
+    #[async_backtrace::framed]
+    pub(super) async fn try_create(
+        stage_info: StageInfo,
+        files_info: StageFilesInfo,
+        read_options: ParquetReadOptions,
+        files_to_read: Option<Vec<StageFileInfo>>,
+        settings: Arc<Settings>,
+        query_kind: QueryKind,
+    ) -> Result<ParquetRSTable> {
         let operator = init_stage_operator(&stage_info)?;
         let first_file = match &files_to_read {
             Some(files) => files[0].path.clone(),
@@ -143,7 +164,7 @@ impl ParquetRSTable {
         let max_threads = settings.get_max_threads()? as usize;
         let max_memory_usage = settings.get_max_memory_usage()?;
 
-        Ok(Arc::new(ParquetRSTable {
+        Ok(ParquetRSTable {
             table_info,
             arrow_schema,
             operator,
@@ -159,11 +180,11 @@ impl ParquetRSTable {
             need_stats_provider,
             max_threads,
             max_memory_usage,
-        }))
+        })
     }
 
     #[async_backtrace::framed]
-    async fn prepare_metas(
+    pub(super) async fn prepare_metas(
         path: &str,
         operator: Operator,
     ) -> Result<(ArrowSchema, SchemaDescPtr, f64)> {
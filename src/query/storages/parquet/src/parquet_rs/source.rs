@@ -32,6 +32,7 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::FileStatus;
+use databend_storages_common_stage::SourceFileMeta;
 
 use super::parquet_reader::policy::ReadPolicyImpl;
 use crate::ParquetPart;
@@ -39,9 +40,12 @@ use crate::ParquetRSFullReader;
 use crate::ParquetRSRowGroupReader;
 use crate::ReadSettings;
 
+type Location = String;
+type NextRow = u64;
+
 enum State {
     Init,
-    ReadRowGroup(ReadPolicyImpl),
+    ReadRowGroup((Location, NextRow, ReadPolicyImpl)),
     ReadFiles(Vec<(String, Vec<u8>)>),
 }
 
@@ -150,15 +154,26 @@ impl Processor for ParquetSource {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Init) {
-            State::ReadRowGroup(mut reader) => {
-                if let Some(block) = reader.as_mut().read_block()? {
+            State::ReadRowGroup((location, next_row, mut reader)) => {
+                if let Some(mut block) = reader.as_mut().read_block()? {
+                    let num_rows = block.num_rows();
+                    if self.is_copy {
+                        block = block.add_meta(Some(SourceFileMeta::create(vec![(
+                            location.clone(),
+                            next_row,
+                            num_rows,
+                        )])))?;
+                    }
                     self.generated_data = Some(block);
-                    self.state = State::ReadRowGroup(reader);
+                    self.state =
+                        State::ReadRowGroup((location, next_row + num_rows as u64, reader));
                 }
                 // Else: The reader is finished. We should try to build another reader.
             }
             State::ReadFiles(buffers) => {
                 let mut blocks = Vec::with_capacity(buffers.len());
+                // The files of the rows, only tracked for copy.
+                let mut ranges = vec![];
                 // Write `if` outside to reduce branches.
                 if self.is_copy {
                     for (path, buffer) in buffers {
@@ -172,6 +187,7 @@ impl Processor for ParquetSource {
                             num_rows_loaded: num_rows,
                             error: None,
                         });
+                        ranges.push((path, 0, num_rows));
                         blocks.extend(bs);
                     }
                 } else {
@@ -186,7 +202,11 @@ impl Processor for ParquetSource {
                 }
 
                 if !blocks.is_empty() {
-                    self.generated_data = Some(DataBlock::concat(&blocks)?);
+                    let mut block = DataBlock::concat(&blocks)?;
+                    if self.is_copy {
+                        block = block.add_meta(Some(SourceFileMeta::create(ranges)))?;
+                    }
+                    self.generated_data = Some(block);
                 }
                 // Else: no output data is generated.
             }
@@ -211,7 +231,11 @@ impl Processor for ParquetSource {
                                 )
                                 .await?
                             {
-                                self.state = State::ReadRowGroup(reader);
+                                self.state = State::ReadRowGroup((
+                                    part.location.clone(),
+                                    part.start_row,
+                                    reader,
+                                ));
                             }
                             // Else: keep in init state.
                         }
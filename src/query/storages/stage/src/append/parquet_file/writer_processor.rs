@@ -21,18 +21,22 @@ use arrow_schema::Schema as ArrowSchema;
 use async_trait::async_trait;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_config::QUERY_SEMVER;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::converts::arrow::table_schema_to_arrow_schema;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::ParquetFileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use databend_storages_common_table_meta::table::TableCompression;
 use opendal::Operator;
 use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
 use parquet::basic::Encoding;
 use parquet::file::properties::EnabledStatistics;
 use parquet::file::properties::WriterProperties;
@@ -67,22 +71,43 @@ pub struct ParquetFileWriter {
     batch_id: usize,
 
     targe_file_size: Option<usize>,
+    params: ParquetFileFormatParams,
 }
 
 const MAX_BUFFER_SIZE: usize = 64 * 1024 * 1024;
-// this is number of rows, not size
-const MAX_ROW_GROUP_SIZE: usize = 1024 * 1024;
+
+fn parquet_compression(compression: StageFileCompression) -> Result<Compression> {
+    Ok(match compression {
+        StageFileCompression::None => Compression::UNCOMPRESSED,
+        StageFileCompression::Snappy => Compression::SNAPPY,
+        StageFileCompression::Gzip => Compression::GZIP(Default::default()),
+        StageFileCompression::Lzo => Compression::LZO,
+        StageFileCompression::Brotli => Compression::BROTLI(Default::default()),
+        StageFileCompression::Zstd => Compression::ZSTD(Default::default()),
+        other => {
+            return Err(ErrorCode::IllegalFileFormat(format!(
+                "Unsupported compression {:?} for PARQUET",
+                other
+            )));
+        }
+    })
+}
 
 fn create_writer(
     arrow_schema: Arc<ArrowSchema>,
     targe_file_size: Option<usize>,
+    params: &ParquetFileFormatParams,
 ) -> Result<ArrowWriter<Vec<u8>>> {
+    let statistics = match params.enable_statistics {
+        true => EnabledStatistics::Chunk,
+        false => EnabledStatistics::None,
+    };
     let props = WriterProperties::builder()
-        .set_compression(TableCompression::Zstd.into())
-        .set_max_row_group_size(MAX_ROW_GROUP_SIZE)
+        .set_compression(parquet_compression(params.compression)?)
+        .set_max_row_group_size(params.row_group_size as usize)
         .set_encoding(Encoding::PLAIN)
         .set_dictionary_enabled(false)
-        .set_statistics_enabled(EnabledStatistics::None)
+        .set_statistics_enabled(statistics)
         .set_bloom_filter_enabled(false)
         .set_created_by(format!("Databend {}", *QUERY_SEMVER))
         .build();
@@ -108,7 +133,11 @@ impl ParquetFileWriter {
             UnloadOutput::create(table_info.copy_into_location_options.detailed_output);
 
         let arrow_schema = Arc::new(table_schema_to_arrow_schema(&table_info.schema));
-        let writer = create_writer(arrow_schema.clone(), targe_file_size)?;
+        let params = match &table_info.stage_info.file_format_params {
+            FileFormatParams::Parquet(params) => params.clone(),
+            _ => unreachable!("ParquetFileWriter only support parquet file format"),
+        };
+        let writer = create_writer(arrow_schema.clone(), targe_file_size, &params)?;
 
         Ok(ProcessorPtr::create(Box::new(ParquetFileWriter {
             input,
@@ -126,11 +155,16 @@ impl ParquetFileWriter {
             group_id,
             batch_id: 0,
             targe_file_size,
+            params,
             row_counts: 0,
         })))
     }
     pub fn reinit_writer(&mut self) -> Result<()> {
-        self.writer = create_writer(self.arrow_schema.clone(), self.targe_file_size)?;
+        self.writer = create_writer(
+            self.arrow_schema.clone(),
+            self.targe_file_size,
+            &self.params,
+        )?;
         self.row_counts = 0;
         self.input_bytes = 0;
         Ok(())
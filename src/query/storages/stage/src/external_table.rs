@@ -0,0 +1,181 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::StorageDescription;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::FileFormatOptionsReader;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::OnErrorMode;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_storage::StageFilesInfo;
+
+use crate::StageTable;
+
+pub const CSV_ENGINE: &str = "CSV";
+pub const TSV_ENGINE: &str = "TSV";
+pub const NDJSON_ENGINE: &str = "NDJSON";
+
+const OPT_KEY_ON_ERROR: &str = "on_error";
+
+/// Table options of the CSV, TSV and NDJSON engines, besides `location` and `connection_name`.
+///
+/// They are the same as the options of the file format with the same name, and
+/// `on_error` which accepts `abort`, `continue` and `abort_<num>`.
+pub const TEXT_TABLE_OPTIONS: &[&str] = &[
+    "compression",
+    "skip_header",
+    "field_delimiter",
+    "record_delimiter",
+    "quote",
+    "escape",
+    "null_display",
+    "nan_display",
+    "empty_field_as",
+    "binary_format",
+    "error_on_column_count_mismatch",
+    "missing_field_as",
+    "null_field_as",
+    "null_if",
+    OPT_KEY_ON_ERROR,
+];
+
+// Skip the files like `_SUCCESS` or `.crc` written by the other engines.
+const DATA_FILE_PATTERN: &str = "^(.*/)?[^_./][^/]*$";
+
+/// Table created by `CREATE TABLE ... ENGINE = CSV|TSV|NDJSON LOCATION = '...'`.
+///
+/// The columns and the format options are kept in the catalog, the files under the
+/// location are read by a [`StageTable`] built in [`TextExternalTable::load`] each time
+/// the table is resolved by a query, in the same way as `COPY INTO` reads them.
+pub struct TextExternalTable {
+    info: TableInfo,
+}
+
+impl TextExternalTable {
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        Ok(Box::new(Self { info }))
+    }
+
+    pub fn csv_description() -> StorageDescription {
+        StorageDescription {
+            engine_name: CSV_ENGINE.to_string(),
+            comment: "CSV Storage Engine".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    pub fn tsv_description() -> StorageDescription {
+        StorageDescription {
+            engine_name: TSV_ENGINE.to_string(),
+            comment: "TSV Storage Engine".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    pub fn ndjson_description() -> StorageDescription {
+        StorageDescription {
+            engine_name: NDJSON_ENGINE.to_string(),
+            comment: "NDJSON Storage Engine".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    pub fn is_text_engine(engine: &str) -> bool {
+        [CSV_ENGINE, TSV_ENGINE, NDJSON_ENGINE]
+            .iter()
+            .any(|e| engine.eq_ignore_ascii_case(e))
+    }
+
+    /// Parse the file format and the malformed row policy from the table options.
+    pub fn read_params(
+        engine: &str,
+        options: &BTreeMap<String, String>,
+    ) -> Result<(FileFormatParams, OnErrorMode)> {
+        let mut format_options = BTreeMap::new();
+        format_options.insert("type".to_string(), engine.to_string());
+        let mut on_error = OnErrorMode::default();
+        for (k, v) in options {
+            let k = k.to_lowercase();
+            if k == OPT_KEY_ON_ERROR {
+                on_error = OnErrorMode::from_str(v).map_err(ErrorCode::TableOptionInvalid)?;
+                if matches!(on_error, OnErrorMode::SkipFileNum(_)) {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "on_error = '{v}' is not supported by {engine} table, must be one of {{ CONTINUE | ABORT | ABORT_<num> }}"
+                    )));
+                }
+            } else if TEXT_TABLE_OPTIONS.contains(&k.as_str()) {
+                format_options.insert(k, v.clone());
+            }
+        }
+
+        let params = FileFormatParams::try_from_reader(
+            FileFormatOptionsReader::from_map(format_options),
+            false,
+        )
+        .map_err(|e| ErrorCode::TableOptionInvalid(e.message()))?;
+        Ok((params, on_error))
+    }
+
+    pub fn load(info: &TableInfo, sp: StorageParams) -> Result<Arc<dyn Table>> {
+        let (file_format_params, on_error) =
+            Self::read_params(&info.meta.engine, &info.meta.options)?;
+        let mut stage_info = StageInfo::new_external_stage(sp, true);
+        stage_info.file_format_params = file_format_params;
+        stage_info.copy_options.on_error = on_error;
+
+        let stage_table_info = StageTableInfo {
+            schema: info.schema(),
+            stage_info,
+            files_info: StageFilesInfo {
+                path: "/".to_string(),
+                files: None,
+                pattern: Some(DATA_FILE_PATTERN.to_string()),
+            },
+            files_to_copy: None,
+            duplicated_files_detected: vec![],
+            is_select: false,
+            default_values: None,
+            copy_into_location_options: Default::default(),
+        };
+        Ok(StageTable::create_with_table_info(
+            stage_table_info,
+            info.clone(),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for TextExternalTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+}
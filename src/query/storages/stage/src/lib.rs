@@ -20,7 +20,13 @@
 
 mod append;
 mod compression;
+mod external_table;
 mod read;
 mod stage_table;
 
+pub use external_table::TextExternalTable;
+pub use external_table::CSV_ENGINE;
+pub use external_table::NDJSON_ENGINE;
+pub use external_table::TEXT_TABLE_OPTIONS;
+pub use external_table::TSV_ENGINE;
 pub use stage_table::StageTable;
@@ -15,6 +15,7 @@
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::RemoteDefaultExpr;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
@@ -25,7 +26,6 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::Evaluator;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
-use databend_common_expression::RemoteExpr;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_expression::Value;
@@ -40,7 +40,7 @@ pub struct LoadContext {
     pub func_ctx: FunctionContext,
 
     pub schema: TableSchemaRef,
-    pub default_values: Option<Vec<RemoteExpr>>,
+    pub default_values: Option<Vec<RemoteDefaultExpr>>,
     pub pos_projection: Option<Vec<usize>>,
     pub is_copy: bool,
 
@@ -105,7 +105,19 @@ impl LoadContext {
                 }
             }
             Some(values) => {
-                if let Some(remote_expr) = &values.get(column_index) {
+                if let Some(default_value) = &values.get(column_index) {
+                    let remote_expr = match default_value {
+                        RemoteDefaultExpr::RemoteExpr(remote_expr) => remote_expr,
+                        RemoteDefaultExpr::Sequence(sequence) => {
+                            return Err(FileParseError::Unexpected {
+                                message: format!(
+                                    "missing value of column `{}` with default nextval({}), leave the column out of the column list to generate it",
+                                    self.schema.field(column_index).name(),
+                                    sequence
+                                ),
+                            });
+                        }
+                    };
                     let expr = remote_expr.as_expr(&BUILTIN_FUNCTIONS);
                     if let Expr::Constant { scalar, .. } = expr {
                         column_builder.push(scalar.as_ref());
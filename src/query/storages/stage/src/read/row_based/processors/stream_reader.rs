@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::ProgressValues;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_sources::AsyncSource;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use log::debug;
+
+use crate::read::row_based::batch::BytesBatch;
+
+/// Read the bytes of a single file from a stream, e.g. the body of an HTTP request.
+///
+/// Unlike [`super::BytesReader`], the size is unknown in advance,
+/// so the end of the file is only known when the stream is exhausted.
+pub struct BytesStreamReader {
+    table_ctx: Arc<dyn TableContext>,
+    stream: BoxStream<'static, Result<Vec<u8>>>,
+    path: String,
+    read_batch_size: usize,
+    buffer: Vec<u8>,
+    offset: usize,
+    finished: bool,
+}
+
+impl BytesStreamReader {
+    pub fn create(
+        table_ctx: Arc<dyn TableContext>,
+        stream: BoxStream<'static, Result<Vec<u8>>>,
+        path: String,
+        read_batch_size: usize,
+    ) -> Self {
+        Self {
+            table_ctx,
+            stream,
+            path,
+            read_batch_size,
+            buffer: Vec::with_capacity(read_batch_size),
+            offset: 0,
+            finished: false,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for BytesStreamReader {
+    const NAME: &'static str = "BytesStreamReader";
+
+    const SKIP_EMPTY_DATA_BLOCK: bool = false;
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let mut is_eof = false;
+        while self.buffer.len() < self.read_batch_size {
+            match self.stream.next().await {
+                Some(chunk) => self.buffer.extend_from_slice(&chunk?),
+                None => {
+                    is_eof = true;
+                    break;
+                }
+            }
+        }
+
+        let data = std::mem::replace(&mut self.buffer, Vec::with_capacity(self.read_batch_size));
+        let n = data.len();
+        Profile::record_usize_profile(ProfileStatisticsName::ScanBytes, n);
+        self.table_ctx
+            .get_scan_progress()
+            .incr(&ProgressValues { rows: 0, bytes: n });
+        debug!("read {} bytes from {}", n, self.path);
+
+        // An empty batch still has to be sent at the end, so the rows
+        // buffered by the following processors are flushed.
+        let offset = self.offset;
+        self.offset += n;
+        self.finished = is_eof;
+        let batch = Box::new(BytesBatch {
+            data,
+            path: self.path.clone(),
+            offset,
+            is_eof,
+        });
+        Ok(Some(DataBlock::empty_with_meta(batch)))
+    }
+}
@@ -22,18 +22,23 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
 use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_core::SourcePipeBuilder;
+use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_pipeline_sources::EmptySource;
 use databend_common_pipeline_sources::PrefetchAsyncSourcer;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_settings::Settings;
 use databend_common_storage::init_stage_operator;
+use futures::stream::BoxStream;
 
 use crate::compression::get_compression_alg_copy;
 use crate::read::load_context::LoadContext;
 use crate::read::row_based::format::create_row_based_file_format;
 use crate::read::row_based::processors::BlockBuilder;
 use crate::read::row_based::processors::BytesReader;
+use crate::read::row_based::processors::BytesStreamReader;
 use crate::read::row_based::processors::Decompressor;
 use crate::read::row_based::processors::Separator;
 
@@ -97,15 +102,55 @@ impl RowBasedReadPipelineBuilder<'_> {
         let num_sources = std::cmp::min(max_threads, plan.parts.len());
         self.build_read_stage_source(ctx.clone(), pipeline, &settings, num_sources)?;
 
-        let format =
-            create_row_based_file_format(&self.stage_table_info.stage_info.file_format_params);
-
         let load_ctx = Arc::new(LoadContext::try_create(
             ctx.clone(),
             self.stage_table_info,
             pos_projection,
             self.compact_threshold,
         )?);
+        self.build_decode_pipeline(load_ctx, pipeline, max_threads)
+    }
+
+    /// Read a single file of unknown size from a stream of bytes,
+    /// such as the body of a streaming load request.
+    pub fn read_stream(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        stream: BoxStream<'static, Result<Vec<u8>>>,
+        path: String,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let settings = ctx.get_settings();
+        let max_threads = settings.get_max_threads()? as usize;
+        let batch_size = settings.get_input_read_buffer_size()? as usize;
+        let output = OutputPort::create();
+        let reader = BytesStreamReader::create(ctx.clone(), stream, path, batch_size);
+        let mut source_builder = SourcePipeBuilder::create();
+        source_builder.add_source(
+            output.clone(),
+            AsyncSourcer::create(ctx.clone(), output, reader)?,
+        );
+        pipeline.add_pipe(source_builder.finalize());
+
+        let mut load_ctx = LoadContext::try_create(
+            ctx.clone(),
+            self.stage_table_info,
+            None,
+            self.compact_threshold,
+        )?;
+        // The data is loaded into a table like COPY does, e.g. empty fields take the default values.
+        load_ctx.is_copy = true;
+        self.build_decode_pipeline(Arc::new(load_ctx), pipeline, max_threads)
+    }
+
+    fn build_decode_pipeline(
+        &self,
+        load_ctx: Arc<LoadContext>,
+        pipeline: &mut Pipeline,
+        max_threads: usize,
+    ) -> Result<()> {
+        let format =
+            create_row_based_file_format(&self.stage_table_info.stage_info.file_format_params);
 
         match self
             .stage_table_info
@@ -36,6 +36,7 @@ use databend_common_storage::StageFileInfo;
 use databend_common_storages_orc::OrcTableForCopy;
 use databend_common_storages_parquet::ParquetTableForCopy;
 use databend_storages_common_stage::SingleFilePartition;
+use futures::stream::BoxStream;
 use opendal::Operator;
 
 use crate::read::row_based::RowBasedReadPipelineBuilder;
@@ -64,6 +65,17 @@ impl StageTable {
         }))
     }
 
+    /// Create a stage table that reports the given catalog table as its table info.
+    pub fn create_with_table_info(
+        table_info: StageTableInfo,
+        table_info_placeholder: TableInfo,
+    ) -> Arc<dyn Table> {
+        Arc::new(Self {
+            table_info,
+            table_info_placeholder,
+        })
+    }
+
     /// Get operator with correctly prefix.
     pub fn get_op(stage: &StageInfo) -> Result<Operator> {
         init_stage_operator(stage)
@@ -78,6 +90,32 @@ impl StageTable {
         stage_info.list_files(thread_num, max_files).await
     }
 
+    /// Read a single file given as a stream of bytes, e.g. the body of a streaming load request.
+    ///
+    /// Only the row based formats can be decoded before the whole file is received.
+    pub fn read_stream(
+        ctx: Arc<dyn TableContext>,
+        stage_table_info: &StageTableInfo,
+        stream: BoxStream<'static, Result<Vec<u8>>>,
+        path: String,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        match stage_table_info.stage_info.file_format_params {
+            FileFormatParams::Csv(_) | FileFormatParams::NdJson(_) | FileFormatParams::Tsv(_) => {
+                let compact_threshold = ctx.get_read_block_thresholds();
+                RowBasedReadPipelineBuilder {
+                    stage_table_info,
+                    compact_threshold,
+                }
+                .read_stream(ctx, stream, path, pipeline)
+            }
+            _ => Err(ErrorCode::Unimplemented(format!(
+                "can not read {} files from a stream",
+                stage_table_info.stage_info.file_format_params.get_type()
+            ))),
+        }
+    }
+
     pub async fn read_partitions_simple(
         &self,
         ctx: Arc<dyn TableContext>,
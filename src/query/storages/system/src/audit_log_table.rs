@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use serde::Serialize;
+
+use crate::query_log_table::datetime_str;
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// A security-relevant event: a login, a denied access, a DDL, a change of
+/// users, roles or grants, or a change of settings.
+///
+/// `checksum` chains the events of a node: it is the HMAC-SHA256 keyed by `log.audit.key`
+/// of the `checksum` of the previous event followed by the JSON of this event with an
+/// empty `checksum`, so that removing or editing an event of the audit log breaks the chain.
+/// `seq` and the chain continue after a restart of the node.
+#[derive(Clone, Serialize)]
+pub struct AuditLogElement {
+    pub seq: u64,
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+    pub event_type: String,
+
+    pub tenant_id: String,
+    pub node_id: String,
+    pub user: String,
+    pub client_address: String,
+
+    pub query_id: String,
+    pub query_text: String,
+
+    pub success: bool,
+    pub error_message: String,
+
+    pub checksum: String,
+}
+
+impl SystemLogElement for AuditLogElement {
+    const TABLE_NAME: &'static str = "audit_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("seq", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("event_type", TableDataType::String),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("user", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("query_text", TableDataType::String),
+            TableField::new("success", TableDataType::Boolean),
+            TableField::new("error_message", TableDataType::String),
+            TableField::new("checksum", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.seq)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.event_type.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.tenant_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.node_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.user.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.client_address.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_text.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.success).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.error_message.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.checksum.clone()).as_ref());
+        Ok(())
+    }
+}
+
+pub type AuditLogQueue = SystemLogQueue<AuditLogElement>;
+pub type AuditLogTable = SystemLogTable<AuditLogElement>;
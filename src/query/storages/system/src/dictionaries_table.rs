@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -75,8 +76,16 @@ impl AsyncSystemTable for DictionariesTable {
 
         let catalog = ctx.get_default_catalog().unwrap();
         let databases = catalog.list_databases(&tenant).await?;
+        let visibility_checker = ctx.get_visibility_checker().await?;
         for database in databases {
             let db_id = database.get_db_info().database_id.db_id;
+            if !visibility_checker.check_database_visibility(
+                CATALOG_DEFAULT,
+                database.get_db_name(),
+                db_id,
+            ) {
+                continue;
+            }
             let req = ListDictionaryReq {
                 tenant: tenant.clone(),
                 db_id,
@@ -20,6 +20,7 @@
 
 extern crate core;
 
+mod audit_log_table;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
@@ -51,6 +52,7 @@ mod processes_table;
 mod queries_profiling;
 mod query_cache_table;
 mod query_log_table;
+mod quotas_usage_table;
 mod roles_table;
 mod settings_table;
 mod stages_table;
@@ -67,6 +69,9 @@ mod users_table;
 mod util;
 mod virtual_columns_table;
 
+pub use audit_log_table::AuditLogElement;
+pub use audit_log_table::AuditLogQueue;
+pub use audit_log_table::AuditLogTable;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
@@ -108,6 +113,7 @@ pub use query_log_table::LogType;
 pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
+pub use quotas_usage_table::QuotasUsageTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
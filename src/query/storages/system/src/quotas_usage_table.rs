@@ -0,0 +1,133 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::ProcessInfoState;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+use databend_common_users::UserQuotaManager;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The query quotas of the users and their usage on the current node.
+pub struct QuotasUsageTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for QuotasUsageTable {
+    const NAME: &'static str = "system.quotas_usage";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let users = UserApiProvider::instance().get_users(&tenant).await?;
+        let processes = ctx.get_processes_info();
+        let quota_manager = UserQuotaManager::instance();
+
+        let mut names = Vec::with_capacity(users.len());
+        let mut hostnames = Vec::with_capacity(users.len());
+        let mut max_queries_per_hour = Vec::with_capacity(users.len());
+        let mut queries_in_current_hour = Vec::with_capacity(users.len());
+        let mut max_concurrent_queries = Vec::with_capacity(users.len());
+        let mut running_queries = Vec::with_capacity(users.len());
+        for user in users {
+            let identity = user.identity();
+            let running = processes
+                .iter()
+                .filter(|p| p.state == ProcessInfoState::Query)
+                .filter(|p| p.user.as_ref().is_some_and(|u| u.identity() == identity))
+                .count();
+
+            names.push(user.name.clone());
+            hostnames.push(user.hostname.clone());
+            max_queries_per_hour.push(user.option.max_queries_per_hour().cloned());
+            queries_in_current_hour.push(quota_manager.queries_in_current_hour(&identity));
+            max_concurrent_queries.push(user.option.max_concurrent_queries().cloned());
+            running_queries.push(running as u64);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(hostnames),
+            UInt64Type::from_opt_data(max_queries_per_hour),
+            UInt64Type::from_data(queries_in_current_hour),
+            UInt64Type::from_opt_data(max_concurrent_queries),
+            UInt64Type::from_data(running_queries),
+        ]))
+    }
+}
+
+impl QuotasUsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("hostname", TableDataType::String),
+            TableField::new(
+                "max_queries_per_hour",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "queries_in_current_hour",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "max_concurrent_queries",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "running_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'quotas_usage'".to_string(),
+            name: "quotas_usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQuotasUsage".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(QuotasUsageTable { table_info })
+    }
+}
@@ -24,6 +24,7 @@ mod role_mgr;
 mod user;
 mod user_api;
 mod user_mgr;
+mod user_quota_mgr;
 mod user_setting;
 mod user_stage;
 mod user_udf;
@@ -36,10 +37,12 @@ pub mod role_cache_mgr;
 pub mod role_util;
 
 pub use jwt::*;
+pub use network_policy::check_client_ip_allowed;
 pub use password_policy::*;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 pub use role_mgr::BUILTIN_ROLE_PUBLIC;
 pub use user::CertifiedInfo;
 pub use user_api::UserApiProvider;
+pub use user_quota_mgr::UserQuotaManager;
 pub use visibility_checker::GrantObjectVisibilityChecker;
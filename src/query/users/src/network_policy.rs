@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
+
 use chrono::Utc;
+use cidr::IpCidr;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_api::crud::CrudError;
@@ -165,3 +168,28 @@ impl UserApiProvider {
         Ok(network_policies)
     }
 }
+
+/// Check the client ip against the allowed ip list of a handler, such as
+/// `query.mysql_handler_allowed_ip_list`. An empty list allows all the clients.
+///
+/// The client ip must be the peer address of the connection, the forwarding
+/// headers of HTTP requests are set by the client and can't be trusted here.
+pub fn check_client_ip_allowed(
+    allowed_ip_list: &[IpCidr],
+    client_ip: Option<IpAddr>,
+) -> Result<()> {
+    if allowed_ip_list.is_empty() {
+        return Ok(());
+    }
+
+    let Some(ip_addr) = client_ip else {
+        return Err(ErrorCode::AuthenticateFailure("Unknown client ip"));
+    };
+    if allowed_ip_list.iter().any(|cidr| cidr.contains(&ip_addr)) {
+        return Ok(());
+    }
+    Err(ErrorCode::AuthenticateFailure(format!(
+        "client ip `{}` is not allowed to connect",
+        ip_addr
+    )))
+}
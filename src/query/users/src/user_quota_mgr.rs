@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::principal::UserInfo;
+use parking_lot::RwLock;
+
+const QUOTA_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+struct QueriesInWindow {
+    started_at: Instant,
+    count: u64,
+}
+
+/// Counts the queries started by each user on this node,
+/// to enforce the `MAX_QUERIES_PER_HOUR` user option.
+///
+/// The counter of a user is reset one hour after the first query of the window.
+pub struct UserQuotaManager {
+    queries: RwLock<HashMap<UserIdentity, QueriesInWindow>>,
+}
+
+impl UserQuotaManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(Self::create()));
+        Ok(())
+    }
+
+    pub fn create() -> UserQuotaManager {
+        UserQuotaManager {
+            queries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn instance() -> Arc<UserQuotaManager> {
+        GlobalInstance::get()
+    }
+
+    /// Count a new query of the user, returns `UserQuotaExceeded` if the user
+    /// has already started `MAX_QUERIES_PER_HOUR` queries in the current window.
+    pub fn try_start_query(&self, user: &UserInfo) -> Result<()> {
+        let limit = user
+            .option
+            .max_queries_per_hour()
+            .copied()
+            .unwrap_or_default();
+
+        let now = Instant::now();
+        let mut queries = self.queries.write();
+        let window = queries.entry(user.identity()).or_insert(QueriesInWindow {
+            started_at: now,
+            count: 0,
+        });
+        if now.duration_since(window.started_at) >= QUOTA_WINDOW {
+            window.started_at = now;
+            window.count = 0;
+        }
+
+        if limit != 0 && window.count >= limit {
+            return Err(ErrorCode::UserQuotaExceeded(format!(
+                "User {} has started {} queries in the last hour, which exceeds the max_queries_per_hour quota ({})",
+                user.identity().display(),
+                window.count,
+                limit
+            )));
+        }
+        window.count += 1;
+        Ok(())
+    }
+
+    /// The number of queries started by the user in the current window.
+    pub fn queries_in_current_hour(&self, user: &UserIdentity) -> u64 {
+        let queries = self.queries.read();
+        match queries.get(user) {
+            Some(window) if window.started_at.elapsed() < QUOTA_WINDOW => window.count,
+            _ => 0,
+        }
+    }
+}
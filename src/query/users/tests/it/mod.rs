@@ -19,4 +19,5 @@ mod role_cache_mgr;
 mod role_mgr;
 mod role_util;
 mod user_mgr;
+mod user_quota_mgr;
 mod user_udf;
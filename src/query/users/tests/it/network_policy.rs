@@ -14,6 +14,7 @@
 
 use chrono::TimeZone;
 use chrono::Utc;
+use cidr::IpCidr;
 use databend_common_base::base::tokio;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
@@ -27,6 +28,7 @@ use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::UserOption;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::check_client_ip_allowed;
 use databend_common_users::UserApiProvider;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -147,3 +149,26 @@ async fn test_network_policy() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_check_client_ip_allowed() -> Result<()> {
+    // Empty list allows all the clients.
+    check_client_ip_allowed(&[], None)?;
+    check_client_ip_allowed(&[], Some("10.0.0.1".parse().unwrap()))?;
+
+    let allowed_ip_list: Vec<IpCidr> = vec![
+        "192.168.1.0/24".parse().unwrap(),
+        "10.0.0.1".parse().unwrap(),
+    ];
+    check_client_ip_allowed(&allowed_ip_list, Some("192.168.1.10".parse().unwrap()))?;
+    check_client_ip_allowed(&allowed_ip_list, Some("10.0.0.1".parse().unwrap()))?;
+
+    let res = check_client_ip_allowed(&allowed_ip_list, Some("10.0.0.2".parse().unwrap()));
+    assert!(res.is_err());
+    let res = check_client_ip_allowed(&allowed_ip_list, Some("::1".parse().unwrap()));
+    assert!(res.is_err());
+    let res = check_client_ip_allowed(&allowed_ip_list, None);
+    assert!(res.is_err());
+
+    Ok(())
+}
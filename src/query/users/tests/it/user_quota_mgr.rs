@@ -0,0 +1,42 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserOption;
+use databend_common_users::UserQuotaManager;
+
+#[test]
+fn test_user_quota_max_queries_per_hour() -> Result<()> {
+    let manager = UserQuotaManager::create();
+
+    let mut user = UserInfo::new_no_auth("u1", "%");
+    user.option = UserOption::default().with_max_queries_per_hour(Some(2));
+    let other = UserInfo::new_no_auth("u2", "%");
+
+    manager.try_start_query(&user)?;
+    manager.try_start_query(&user)?;
+    let res = manager.try_start_query(&user);
+    assert_eq!(res.unwrap_err().code(), ErrorCode::USER_QUOTA_EXCEEDED);
+    assert_eq!(manager.queries_in_current_hour(&user.identity()), 2);
+
+    // The queries are counted per user, and users without quota are not limited.
+    for _ in 0..3 {
+        manager.try_start_query(&other)?;
+    }
+    assert_eq!(manager.queries_in_current_hour(&other.identity()), 3);
+
+    Ok(())
+}
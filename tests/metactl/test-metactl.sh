@@ -12,6 +12,8 @@ want_snapshot_v003="$SCRIPT_PATH/want_snapshot_v003"
 
 exported="$SCRIPT_PATH/exported"
 grpc_exported="$SCRIPT_PATH/grpc_exported"
+http_exported="$SCRIPT_PATH/http_exported"
+http_meta_dir="$SCRIPT_PATH/_http_meta_dir"
 
 chmod +x ./target/${BUILD_PROFILE}/databend-metactl
 chmod +x ./target/${BUILD_PROFILE}/databend-meta
@@ -66,7 +68,7 @@ metactl_import_export () {
     # Give it a very big heartbeat interval to prevent election.
     # Election will change the `vote` in storage and thus fail the following `diff`
     # in this test.
-    ./target/${BUILD_PROFILE}/databend-meta --single --heartbeat-interval 100000 --raft-dir "$meta_dir" --log-file-level=debug &
+    ./target/${BUILD_PROFILE}/databend-meta --single --heartbeat-interval 100000 --raft-dir "$meta_dir" --admin-api-token metactl-test-token --log-file-level=debug &
     METASRV_PID=$!
     echo " === pid: $METASRV_PID"
     sleep 10
@@ -79,8 +81,25 @@ metactl_import_export () {
     echo " === grpc_exported file data end"
     diff $want_exported $grpc_exported
 
+    echo " === "
+    echo " === ${title} 4. Test export from the admin http api of running meta-service to file $http_exported"
+    echo " === "
+
+    curl -sSf -H "Authorization: Bearer metactl-test-token" http://127.0.0.1:28002/v1/ctrl/export >$http_exported
+    diff $want_exported $http_exported
+
     kill $METASRV_PID
     sleep 1
+
+    echo " === "
+    echo " === ${title} 5. Test import $http_exported into dir: $http_meta_dir"
+    echo " === "
+
+    cat $http_exported |
+        ./target/${BUILD_PROFILE}/databend-metactl import --raft-dir "$http_meta_dir"
+
+    ./target/${BUILD_PROFILE}/databend-metactl export --raft-dir "$http_meta_dir" >$exported
+    diff $want_exported $exported
 }
 
 metactl_import_export 'V003' "$meta_json_v002" "$want_exported_v003" "$want_snapshot_v003"
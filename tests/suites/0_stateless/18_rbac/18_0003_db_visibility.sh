@@ -86,3 +86,9 @@ echo "select * from db1.t1" | $BENDSQL_CLIENT_CONNECT
 echo "select * from db2.t2" | $BENDSQL_CLIENT_CONNECT
 echo "select * from db_u3.t3" | $BENDSQL_CLIENT_CONNECT
 echo "select * from db_root.t1" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== test dictionaries visibility ==="
+echo "create dictionary db1.d1(c1 int not null, c2 varchar not null) primary key c1 source(mysql(host='localhost' port='3306' username='root' password='1234' db='db1' table='test_table'))" | $BENDSQL_CLIENT_CONNECT
+echo "create dictionary db_root.d2(c1 int not null, c2 varchar not null) primary key c1 source(mysql(host='localhost' port='3306' username='root' password='1234' db='db1' table='test_table'))" | $BENDSQL_CLIENT_CONNECT
+echo "select database, name from system.dictionaries where database in ('db1', 'db_root') order by database" | $TEST_U1_CONNECT
+echo "select database, name from system.dictionaries where database in ('db1', 'db_root') order by database" | $BENDSQL_CLIENT_CONNECT
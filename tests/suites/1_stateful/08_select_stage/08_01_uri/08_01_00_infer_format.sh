@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+echo "drop table if exists t1;" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE TABLE t1 (id INT, name VARCHAR);" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t1 (id,name) values(1,'a'), (2, 'b');" | $BENDSQL_CLIENT_CONNECT
+
+DATADIR_PATH="/tmp/08_01_00"
+rm -rf ${DATADIR_PATH}
+mkdir -p ${DATADIR_PATH}
+DATADIR="fs://$DATADIR_PATH"
+
+printf '1,a\n2,b\n' > ${DATADIR_PATH}/d1.csv
+printf '3,c\n' > ${DATADIR_PATH}/d2.csv
+printf '4,d\n' | gzip > ${DATADIR_PATH}/d3.csv.gz
+printf '5\te\n' > ${DATADIR_PATH}/d4.tsv
+printf '{"id":6,"name":"f"}\n' > ${DATADIR_PATH}/d5.ndjson
+echo "copy into '${DATADIR}/p/' from t1 FILE_FORMAT = (type = PARQUET);" | $BENDSQL_CLIENT_CONNECT > /dev/null
+mv ${DATADIR_PATH}/p/*.parquet ${DATADIR_PATH}/d6.parquet
+
+echo '--- csv glob'
+echo "select \$1, \$2 from '${DATADIR}/d?.csv' order by \$1;" | $BENDSQL_CLIENT_CONNECT
+echo '--- csv gzip'
+echo "select \$1, \$2 from '${DATADIR}/d3.csv.gz';" | $BENDSQL_CLIENT_CONNECT
+echo '--- tsv'
+echo "select \$1, \$2 from '${DATADIR}/*.tsv';" | $BENDSQL_CLIENT_CONNECT
+echo '--- ndjson'
+echo "select \$1 from '${DATADIR}/d5.ndjson';" | $BENDSQL_CLIENT_CONNECT
+echo '--- parquet with inferred schema'
+echo "select * from '${DATADIR}/*.parquet' order by id;" | $BENDSQL_CLIENT_CONNECT
+
+rm -rf ${DATADIR_PATH}
@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT="/tmp/13_0000_parquet_engine"
+rm -rf $ROOT
+mkdir $ROOT
+cp "$TESTS_DATA_DIR"/parquet/ii/f1.parquet "$TESTS_DATA_DIR"/parquet/ii/f2.parquet $ROOT
+# not a data file, should be skipped
+touch $ROOT/_SUCCESS
+
+stmt "drop table if exists test_parquet;"
+
+echo ">>>> create table test_parquet engine = parquet location = 'fs://\${ROOT}/';"
+echo "create table test_parquet engine = parquet location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+query "select count(*) from test_parquet;"
+
+# new files are visible without recreating the table
+cp "$TESTS_DATA_DIR"/parquet/ii/f3.parquet $ROOT
+query "select count(*) from test_parquet;"
+stmt "drop table test_parquet;"
+
+rm -rf $ROOT
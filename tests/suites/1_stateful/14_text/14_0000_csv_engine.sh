@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT="/tmp/14_0000_csv_engine"
+rm -rf $ROOT
+mkdir $ROOT
+printf 'id|name\n1|"a|b"\n2|NULL\nx|c\n3|d\n' | gzip > $ROOT/f1.csv.gz
+# not a data file, should be skipped
+touch $ROOT/_SUCCESS
+
+stmt "drop table if exists test_csv;"
+
+echo ">>>> create table test_csv(id int, name string null) engine = csv location = 'fs://\${ROOT}/' field_delimiter = '|' skip_header = '1' null_display = 'NULL' compression = 'gzip' on_error = 'continue';"
+echo "create table test_csv(id int, name string null) engine = csv location = 'fs://${ROOT}/' field_delimiter = '|' skip_header = '1' null_display = 'NULL' compression = 'gzip' on_error = 'continue';" | $BENDSQL_CLIENT_CONNECT
+query "select * from test_csv order by id;"
+
+# new files are visible without recreating the table
+printf 'id|name\n4|e\n' | gzip > $ROOT/f2.csv.gz
+query "select count(*) from test_csv;"
+stmt "drop table test_csv;"
+
+# the options are checked when creating the table
+stmt "create table test_csv(id int) engine = csv location = 'fs:///tmp/' on_error = 'skip_file';"
+stmt "create table test_csv(id int) engine = csv location = 'fs:///tmp/' row_tag = 'row';"
+stmt "create table test_csv engine = csv location = 'fs:///tmp/';"
+
+rm -rf $ROOT
@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT="/tmp/14_0001_ndjson_engine"
+rm -rf $ROOT
+mkdir $ROOT
+printf '{"id": 1, "name": "a", "extra": true}\n{"id": 2}\n{"id": 3, "name": null}\n{"id": \n' > $ROOT/f1.ndjson
+
+stmt "drop table if exists test_ndjson;"
+
+echo ">>>> create table test_ndjson(id int, name string null) engine = ndjson location = 'fs://\${ROOT}/' missing_field_as = 'null' on_error = 'continue';"
+echo "create table test_ndjson(id int, name string null) engine = ndjson location = 'fs://${ROOT}/' missing_field_as = 'null' on_error = 'continue';" | $BENDSQL_CLIENT_CONNECT
+query "select * from test_ndjson order by id;"
+stmt "drop table test_ndjson;"
+
+# csv options are not valid for ndjson
+stmt "create table test_ndjson(id int) engine = ndjson location = 'fs:///tmp/' field_delimiter = '|';"
+
+rm -rf $ROOT